@@ -1,31 +1,34 @@
-extern crate scan_fmt;
-
-use multimap::MultiMap;
+use aoc_tsp::Distances;
 use scan_fmt::scan_fmt;
-use std::collections::HashSet;
 use std::fs::read_to_string;
 
-fn parse(file: &str) -> MultiMap<String, (String, u32)> {
-    let mut cities: MultiMap<String, (String, u32)> = read_to_string(file)
+/// The cities, and the distances between them.
+fn parse(file: &str) -> (Vec<String>, Distances) {
+    let routes: Vec<(String, String, i64)> = read_to_string(file)
         .unwrap()
         .lines()
-        .map(|line| scan_fmt!(line, "{} to {} = {d}", String, String, u32).unwrap())
-        .flat_map(|(city1, city2, distance)| {
-            vec![
-                (city1.clone(), (city2.clone(), distance)),
-                (city2, (city1, distance)),
-            ]
-        })
+        .map(|line| scan_fmt!(line, "{} to {} = {d}", String, String, i64).unwrap())
         .collect();
 
-    let first = cities
-        .keys()
-        .map(String::from)
-        .map(|c| (c, 0))
-        .collect::<Vec<(String, u32)>>();
-    cities.insert_many(String::from(""), first);
+    let mut cities: Vec<String> = Vec::new();
+    let mut index = |city: &String| match cities.iter().position(|c| c == city) {
+        Some(i) => i,
+        None => {
+            cities.push(city.clone());
+            cities.len() - 1
+        }
+    };
+    let routes: Vec<(usize, usize, i64)> = routes
+        .iter()
+        .map(|(city1, city2, distance)| (index(city1), index(city2), *distance))
+        .collect();
 
-    cities
+    let mut distances = Distances::new(cities.len());
+    for (city1, city2, distance) in routes {
+        distances.set_both(city1, city2, distance);
+    }
+
+    (cities, distances)
 }
 
 fn main() {
@@ -35,58 +38,52 @@ fn main() {
     println!("{}", part2("input.txt"));
 }
 
-fn part1(file: &str) -> u32 {
-    let cities = parse(file);
+fn part1(file: &str) -> i64 {
+    let (_, distances) = parse(file);
 
-    find_shortest_path(&String::from(""), &cities, &mut HashSet::new())
+    distances.shortest_path().expect("no route").length
 }
 
-fn part2(file: &str) -> u32 {
-    let cities = parse(file);
+fn part2(file: &str) -> i64 {
+    let (_, distances) = parse(file);
 
-    find_longest_path(&String::from(""), &cities, &mut HashSet::new())
+    distances.longest_path().expect("no route").length
 }
 
-fn find_shortest_path<'a>(
-    cur: &'a String,
-    cities: &'a MultiMap<String, (String, u32)>,
-    visited: &mut HashSet<&'a String>,
-) -> u32 {
-    if visited.len() + 1 == cities.len() {
-        return 0;
+#[cfg(test)]
+mod test {
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test]
+    fn parsing() {
+        let (cities, distances) = parse("sample1.txt");
+
+        assert_eq!(vec!["London", "Dublin", "Belfast"], cities);
+        assert_eq!(Some(141), distances.get(2, 1));
     }
-    let mut min = u32::MAX;
-    visited.insert(cur);
-    for (city, dist) in cities.get_vec(cur).unwrap() {
-        if !visited.contains(city) {
-            let new_min = find_shortest_path(city, cities, visited);
-            if new_min < u32::MAX && new_min + dist < min {
-                min = new_min + dist;
-            }
-        }
+
+    #[test]
+    fn route() {
+        let (cities, distances) = parse("sample1.txt");
+        let route = distances.shortest_path().unwrap();
+
+        let stops: Vec<&str> = route.stops.iter().map(|&i| cities[i].as_str()).collect();
+        assert!(
+            stops == ["London", "Dublin", "Belfast"] || stops == ["Belfast", "Dublin", "London"]
+        );
     }
-    visited.remove(cur);
-    min
-}
 
-fn find_longest_path<'a>(
-    cur: &'a String,
-    cities: &'a MultiMap<String, (String, u32)>,
-    visited: &mut HashSet<&'a String>,
-) -> u32 {
-    if visited.len() + 1 == cities.len() {
-        return 0;
+    #[test_case("sample1.txt" => 605; "sample1")]
+    #[test_case("input.txt" => 207; "input")]
+    fn part1(input: &str) -> i64 {
+        super::part1(input)
     }
-    let mut max = 0;
-    visited.insert(cur);
-    for (city, dist) in cities.get_vec(cur).unwrap() {
-        if !visited.contains(city) {
-            let new_max = find_longest_path(city, cities, visited);
-            if new_max + dist > max {
-                max = new_max + dist;
-            }
-        }
+
+    #[test_case("sample1.txt" => 982; "sample1")]
+    #[test_case("input.txt" => 804; "input")]
+    fn part2(input: &str) -> i64 {
+        super::part2(input)
     }
-    visited.remove(cur);
-    max
 }
@@ -1,13 +1,11 @@
-use single::Single;
+use itertools::Itertools;
 use std::collections::HashMap;
 use std::fs::read_to_string;
 
 fn filter_part1(sue: &HashMap<String, i32>, evidence: &HashMap<String, i32>) -> bool {
     for (e, n) in evidence {
-        if sue.contains_key(e) {
-            if sue[e] != *n {
-                return false;
-            }
+        if sue.contains_key(e) && sue[e] != *n {
+            return false;
         }
     }
     true
@@ -15,21 +13,21 @@ fn filter_part1(sue: &HashMap<String, i32>, evidence: &HashMap<String, i32>) ->
 
 fn filter_part2(sue: &HashMap<String, i32>, evidence: &HashMap<String, i32>) -> bool {
     for (e, n) in evidence {
-        if sue.contains_key(e) {
-            if match e.as_str() {
+        if sue.contains_key(e)
+            && match e.as_str() {
                 "cats" | "trees" => sue[e] <= *n,
                 "pomeranians" | "goldfish" => sue[e] >= *n,
                 _ => sue[e] != *n,
-            } {
-                return false;
             }
+        {
+            return false;
         }
     }
     true
 }
 
 fn filter_sues(
-    input: &String,
+    input: &str,
     evidence: &HashMap<String, i32>,
     filter: fn(&HashMap<String, i32>, &HashMap<String, i32>) -> bool,
 ) -> (i32, HashMap<String, i32>) {
@@ -45,8 +43,8 @@ fn filter_sues(
                     .collect::<HashMap<String, i32>>(),
             )
         })
-        .filter(|(_, s)| filter(&s, &evidence))
-        .single()
+        .filter(|(_, s)| filter(s, evidence))
+        .exactly_one()
         .unwrap()
 }
 
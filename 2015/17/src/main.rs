@@ -8,14 +8,14 @@ fn main() {
         .unwrap()
         .lines()
         .map(|l| l.parse::<i32>().unwrap())
-        .collect();
+        .collect::<Vec<_>>();
 
     println!("Test - {} combinations", combinations(25, &test_containers));
     println!("Part1 - {} combinations", combinations(150, &containers));
     println!("Part2 - {} minimum ways", min_ways(150, &containers));
 }
 
-fn combinations(nog: i32, containers: &Vec<i32>) -> usize {
+fn combinations(nog: i32, containers: &[i32]) -> usize {
     (1..containers.len())
         .map(|size| {
             containers
@@ -27,7 +27,7 @@ fn combinations(nog: i32, containers: &Vec<i32>) -> usize {
         .sum()
 }
 
-fn min_ways(nog: i32, containers: &Vec<i32>) -> usize {
+fn min_ways(nog: i32, containers: &[i32]) -> usize {
     let mut ways = (1..containers.len())
         .flat_map(|size| {
             containers
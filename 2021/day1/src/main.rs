@@ -1,62 +1,5 @@
-use std::collections::LinkedList;
-use std::fs::File;
-use std::io::{self, BufRead};
-use std::path::Path;
+use y2021_d01::solution::Day;
 
 fn main() {
-    println!("Hello, world!");
-}
-
-// The output is wrapped in a Result to allow matching on errors
-// Returns an Iterator to the Reader of the lines of the file.
-fn read_lines<P>(filename: P) -> io::Result<io::Lines<io::BufReader<File>>>
-where
-    P: AsRef<Path>,
-{
-    let file = File::open(filename)?;
-    Ok(io::BufReader::new(file).lines())
-}
-
-#[cfg(test)]
-mod test {
-    use test_case::test_case;
-
-    use super::*;
-
-    #[test_case("sample1.txt" => is eq(7) ; "sample")]
-    #[test_case("input1.txt" => is eq(1655) ; "input")]
-    fn part1(input: &str) -> i32 {
-        let mut result = 0;
-        let mut prev = i32::MAX;
-        for line in read_lines(input).unwrap() {
-            //println!("{}", line.unwrap());
-            let cur = line.unwrap().parse::<i32>().unwrap();
-            if cur > prev {
-                result = result + 1;
-            }
-            prev = cur;
-        }
-        return result;
-    }
-
-    #[test_case("sample1.txt" => is eq(5) ; "sample")]
-    #[test_case("input1.txt" => is eq(1683) ; "input")]
-    fn part2(input: &str) -> i32 {
-        let mut result = 0;
-        let mut prev = i32::MAX;
-        let mut window = LinkedList::new();
-        for line in read_lines(input).unwrap() {
-            let l = line.unwrap().parse::<i32>().unwrap();
-            window.push_back(l);
-            if window.len() > 2 {
-                let cur = window.iter().sum();
-                if cur > prev {
-                    result = result + 1;
-                }
-                prev = cur;
-                window.pop_front();
-            }
-        }
-        return result;
-    }
+    aoc_core::solve_measure_and_print::<Day>(env!("CARGO_PKG_NAME"), "input1.txt");
 }
@@ -0,0 +1,48 @@
+use aoc_core::{Answer, Solution};
+
+/// How often a depth is deeper than the one before.
+fn increases(depths: &[i32]) -> usize {
+    depths.windows(2).filter(|w| w[1] > w[0]).count()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<i32>;
+
+    fn parse(input: &str) -> Self::Input {
+        input
+            .lines()
+            .map(|line| line.parse().expect("a depth"))
+            .collect()
+    }
+
+    fn part1(depths: &Self::Input) -> Answer {
+        increases(depths).into()
+    }
+
+    fn part2(depths: &Self::Input) -> Answer {
+        let sums: Vec<i32> = depths.windows(3).map(|w| w.iter().sum()).collect();
+        increases(&sums).into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use test_case::test_case;
+
+    use super::*;
+    use aoc_core::{read, Part};
+
+    #[test_case("sample1.txt" => 7 ; "sample")]
+    #[test_case("input1.txt" => 1655 ; "input")]
+    fn part1(input: &str) -> Answer {
+        Day::solve(Part::One, &read(input))
+    }
+
+    #[test_case("sample1.txt" => 5 ; "sample")]
+    #[test_case("input1.txt" => 1683 ; "input")]
+    fn part2(input: &str) -> Answer {
+        Day::solve(Part::Two, &read(input))
+    }
+}
@@ -0,0 +1,136 @@
+use aoc_core::{Answer, Solution};
+use std::ops::ControlFlow;
+
+fn find_closing(c: char) -> Option<char> {
+    match c {
+        '(' => Some(')'),
+        '[' => Some(']'),
+        '{' => Some('}'),
+        '<' => Some('>'),
+        _ => None,
+    }
+}
+
+fn validate(chunk: &str) -> Option<(char, String)> {
+    match chunk
+        .chars()
+        .try_fold(String::new(), |mut stack, c| match find_closing(c) {
+            Some(closing) => {
+                stack.insert(0, closing);
+                ControlFlow::Continue(stack)
+            }
+            None => {
+                if stack.remove(0) != c {
+                    ControlFlow::Break(c)
+                } else {
+                    ControlFlow::Continue(stack)
+                }
+            }
+        }) {
+        ControlFlow::Break(c) => Some((c, "".to_string())),
+        ControlFlow::Continue(stack) => match stack.as_str() {
+            "" => None,
+            stack => Some(('_', stack.to_string())),
+        },
+    }
+}
+
+fn score_syntax_check((invalid, _): (char, String)) -> Option<u32> {
+    match invalid {
+        ')' => Some(3),
+        ']' => Some(57),
+        '}' => Some(1197),
+        '>' => Some(25137),
+        _ => None,
+    }
+}
+
+fn score_autocompletion((_, completion): (char, String)) -> Option<u64> {
+    completion
+        .chars()
+        .map(|c| match c {
+            ')' => 1,
+            ']' => 2,
+            '}' => 3,
+            '>' => 4,
+            _ => panic!("Invalid character in autocompletion: {}", c),
+        })
+        .reduce(|sum, cur| sum * 5 + cur)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Self::Input {
+        input.lines().map(str::to_string).collect()
+    }
+
+    fn part1(lines: &Self::Input) -> Answer {
+        lines
+            .iter()
+            .filter_map(|l| validate(l))
+            .filter_map(score_syntax_check)
+            .sum::<u32>()
+            .into()
+    }
+
+    fn part2(lines: &Self::Input) -> Answer {
+        let mut scores: Vec<u64> = lines
+            .iter()
+            .filter_map(|l| validate(l))
+            .filter_map(score_autocompletion)
+            .collect();
+
+        scores.sort();
+
+        scores[scores.len() / 2].into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use test_case::test_case;
+
+    use super::*;
+    use aoc_core::{read, Part};
+
+    #[test_case("(]" => ']' ; "1")]
+    #[test_case("{()()()>" => '>' ; "2")]
+    #[test_case("(((()))}" => '}' ; "3")]
+    #[test_case("<([]){()}[{}])" => ')' ; "4")]
+    fn corrupt(chunk: &str) -> char {
+        validate(chunk).unwrap().0
+    }
+
+    #[test_case("([])" => None ; "1")]
+    #[test_case("{()()()}" => None ; "2")]
+    #[test_case("<([{}])>" => None ; "3")]
+    #[test_case("[<>({}){}[([])<>]]" => None ; "4")]
+    #[test_case("(((((((((())))))))))" => None ; "5")]
+    fn valid(chunk: &str) -> Option<(char, String)> {
+        validate(chunk)
+    }
+
+    #[test_case("[({(<(())[]>[[{[]{<()<>>" => "}}]])})]" ; "1")]
+    #[test_case("[(()[<>])]({[<{<<[]>>(" => ")}>]})" ; "2")]
+    #[test_case("(((({<>}<{<{<>}{[]{[]{}" => "}}>}>))))" ; "3")]
+    #[test_case("{<[[]]>}<{[{[{[]{()[[[]" => "]]}}]}]}>" ; "4")]
+    #[test_case("<{([{{}}[<[[[<>{}]]]>[]]" => "])}>" ; "5")]
+    fn complete(chunk: &str) -> String {
+        validate(chunk).unwrap().1
+    }
+
+    #[test_case("sample1.txt" => 26397 ; "sample")]
+    #[test_case("input.txt" => 367227 ; "input")]
+    fn part1(input: &str) -> Answer {
+        Day::solve(Part::One, &read(input))
+    }
+
+    #[test_case("sample1.txt" => 288957 ; "sample")]
+    #[test_case("input.txt" => 3583341858 ; "input")]
+    fn part2(input: &str) -> Answer {
+        Day::solve(Part::Two, &read(input))
+    }
+}
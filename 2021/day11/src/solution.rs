@@ -0,0 +1,116 @@
+use aoc_core::{Answer, Solution};
+use aoc_grid::{Grid, Point};
+
+fn step(octos: &mut Grid<u8>) {
+    for point in octos.points() {
+        octos[point] += 1;
+    }
+}
+
+fn flash(octos: &mut Grid<u8>) -> i32 {
+    let mut flashes = 0;
+    for point in octos.points() {
+        if octos[point] > 9 {
+            flashes += 1;
+            octos[point] = 0;
+            energize_neighbours(octos, point);
+        }
+    }
+    let mut more = flashes > 0;
+    while more {
+        let more_flashes = flash(octos);
+        flashes += more_flashes;
+        more = more_flashes > 0;
+    }
+
+    flashes
+}
+
+fn energize_neighbours(octos: &mut Grid<u8>, point: Point) {
+    for neighbour in octos.neighbours8(point).collect::<Vec<_>>() {
+        if octos[neighbour] > 0 {
+            octos[neighbour] += 1;
+        }
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Grid<u8>;
+
+    fn parse(input: &str) -> Self::Input {
+        Grid::parse_digits(input)
+    }
+
+    fn part1(octos: &Self::Input) -> Answer {
+        let mut octos = octos.clone();
+        let mut flashes = 0;
+        for _ in 0..100 {
+            step(&mut octos);
+            flashes += flash(&mut octos);
+        }
+        flashes.into()
+    }
+
+    fn part2(octos: &Self::Input) -> Answer {
+        let mut octos = octos.clone();
+        let mut flashes = 0;
+        let mut counter = 0;
+        while flashes < 100 {
+            counter += 1;
+            step(&mut octos);
+            flashes = flash(&mut octos);
+        }
+        counter.into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use test_case::test_case;
+
+    use super::*;
+    use aoc_core::{read, Part};
+
+    #[test_case("sample1.txt" => 1656 ; "sample")]
+    #[test_case("input.txt" => 1649 ; "input")]
+    fn part1(input: &str) -> Answer {
+        Day::solve(Part::One, &read(input))
+    }
+
+    #[test_case("sample1.txt" => 195 ; "sample")]
+    #[test_case("input.txt" => 256 ; "input")]
+    fn part2(input: &str) -> Answer {
+        Day::solve(Part::Two, &read(input))
+    }
+
+    #[test]
+    fn first_steps() {
+        let mut octos = Day::parse(&read("sample1.txt"));
+
+        step(&mut octos);
+        assert_eq!(0, flash(&mut octos));
+        println!("{octos}");
+
+        step(&mut octos);
+        assert_eq!(35, flash(&mut octos));
+        println!("{octos}");
+
+        step(&mut octos);
+        assert_eq!(45, flash(&mut octos));
+        println!("{octos}");
+
+        step(&mut octos);
+        flash(&mut octos);
+        println!("{octos}");
+
+        step(&mut octos);
+        flash(&mut octos);
+        println!("{octos}");
+
+        step(&mut octos);
+        assert_eq!(1, flash(&mut octos));
+        println!("{octos}");
+    }
+}
@@ -0,0 +1,133 @@
+use aoc_core::{Answer, Solution};
+use aoc_grid::read_dots;
+use itertools::Itertools;
+use std::collections::HashSet;
+
+type Dots = HashSet<(i32, i32)>;
+
+fn game_from_input(input: &str) -> (Dots, Vec<(i32, i32)>) {
+    let mut dots = HashSet::new();
+    let mut folds = vec![];
+    let mut folding = false;
+    for line in input.lines() {
+        if line.is_empty() {
+            folding = true;
+        } else {
+            if folding {
+                let (a, n) = line.split("=").collect_tuple().unwrap();
+                let num = n.parse().unwrap();
+                folds.push(if a.ends_with("x") { (0, num) } else { (1, num) });
+            } else {
+                dots.insert(
+                    line.split(",")
+                        .map(|n| n.parse().unwrap())
+                        .collect_tuple()
+                        .unwrap(),
+                );
+            }
+        }
+    }
+
+    (dots, folds)
+}
+
+fn render(dots: &Dots) -> String {
+    let minx = *dots.iter().map(|(x, _)| x).min().unwrap();
+    let maxx = *dots.iter().map(|(x, _)| x).max().unwrap();
+    let miny = *dots.iter().map(|(_, y)| y).min().unwrap();
+    let maxy = *dots.iter().map(|(_, y)| y).max().unwrap();
+
+    (miny..=maxy)
+        .map(|y| {
+            (minx..=maxx)
+                .map(|x| if dots.contains(&(x, y)) { '#' } else { ' ' })
+                .collect::<String>()
+        })
+        .join("\n")
+}
+
+fn fold(dots: Dots, (axis, num): (i32, i32)) -> Dots {
+    let mut folded = HashSet::new();
+
+    for (x, y) in dots {
+        if axis == 1 {
+            if y < num {
+                folded.insert((x, y));
+            } else {
+                folded.insert((x, num - (y - num)));
+            }
+        } else {
+            if x < num {
+                folded.insert((x, y));
+            } else {
+                folded.insert((num - (x - num), y));
+            }
+        }
+    }
+
+    folded
+}
+
+fn fold_all((dots, folds): &(Dots, Vec<(i32, i32)>)) -> Dots {
+    folds.iter().fold(dots.clone(), |dots, &f| fold(dots, f))
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = (Dots, Vec<(i32, i32)>);
+
+    fn parse(input: &str) -> Self::Input {
+        game_from_input(input)
+    }
+
+    fn part1((dots, folds): &Self::Input) -> Answer {
+        fold(dots.clone(), folds[0]).len().into()
+    }
+
+    fn part2(game: &Self::Input) -> Answer {
+        let dots = fold_all(game);
+        read_dots(&dots).map_or_else(|| Answer::Art(render(&dots)), Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use test_case::test_case;
+
+    use super::*;
+    use aoc_core::{read, Part};
+
+    #[test_case("sample1.txt" => 17 ; "sample1")]
+    #[test_case("input.txt" => 693 ; "input")]
+    fn part1(input: &str) -> Answer {
+        Day::solve(Part::One, &read(input))
+    }
+
+    #[test_case("sample1.txt" => 16 ; "sample1")]
+    #[test_case("input.txt" => 95 ; "input")]
+    #[test_case("inputplusplus.txt" => 358 ; "inputplusplus")]
+    #[test_case("input_day13_BONUS.txt" => 302 ; "bonus")]
+    fn part2(input: &str) -> usize {
+        fold_all(&Day::parse(&read(input))).len()
+    }
+
+    #[test]
+    fn reading_letters() {
+        assert_eq!("UCLZRAZU", Day::solve(Part::Two, &read("input.txt")));
+    }
+
+    #[test]
+    fn unreadable_letters() {
+        let square = "#####\n#   #\n#   #\n#   #\n#####";
+        assert_eq!(
+            Answer::Art(square.to_string()),
+            Day::solve(Part::Two, &read("sample1.txt"))
+        );
+        // These two are drawn in a taller font of their own.
+        for input in ["inputplusplus.txt", "input_day13_BONUS.txt"] {
+            let answer = Day::solve(Part::Two, &read(input));
+            assert!(matches!(answer, Answer::Art(_)), "{input}: {answer}");
+        }
+    }
+}
@@ -0,0 +1,135 @@
+use aoc_core::{Answer, Solution};
+use cached::{cached_key, UnboundCache};
+use itertools::Itertools;
+use std::collections::HashMap;
+
+fn game_from_input(input: &str) -> (String, HashMap<String, String>) {
+    let mut lines = input.lines();
+
+    (
+        lines.next().unwrap().to_string(),
+        lines
+            .skip(1)
+            .map(|r| {
+                r.split(" -> ")
+                    .map(|r| r.to_string())
+                    .collect_tuple()
+                    .unwrap()
+            })
+            .collect(),
+    )
+}
+
+fn count(polymer: String) -> HashMap<char, usize> {
+    let counts = polymer.chars().fold(HashMap::new(), |mut m, c| {
+        *m.entry(c).or_insert(0) += 1;
+        m
+    });
+    counts
+}
+
+fn count_most_least(counts: HashMap<char, usize>) -> (usize, usize) {
+    (
+        *counts.values().max().unwrap(),
+        *counts.values().min().unwrap(),
+    )
+}
+
+fn apply(template: String, rules: &HashMap<String, String>) -> String {
+    let mut polymer = template[..1].to_string();
+
+    for i in 0..template.len() - 1 {
+        let pair = &template[i..=i + 1];
+        let last = &pair[1..];
+        match rules.get(pair) {
+            Some(insert) => {
+                polymer += insert;
+                polymer += last
+            }
+            None => polymer += last,
+        }
+    }
+
+    polymer
+}
+
+fn merge(counts: &mut HashMap<char, usize>, more: &HashMap<char, usize>) {
+    for (c, n) in more {
+        *counts.entry(*c).or_insert(0) += *n;
+    }
+}
+
+cached_key! {
+    // name and type
+    NAME_OF_CACHE: UnboundCache<String, HashMap<char, usize>> = UnboundCache::new();
+    // construct key, cache is a shared one, so include rules in key
+    Key = { format!("{}{}{}", pair, rounds, rules.len())};
+    fn apply_rec(rules: &HashMap<String, String>, pair: &str, rounds: i32) -> HashMap<char, usize> = {
+        let mut counts = HashMap::new();
+        if rounds > 0 {
+            let mut first = pair[..1].to_string();
+            let mut last = pair[1..].to_string();
+            let new = rules.get(pair).unwrap().chars().next().unwrap();
+            first.push(new);
+            last.insert(0, new);
+            *counts.entry(new).or_insert(0) +=1;
+            merge(&mut counts, &apply_rec(rules, &first, rounds-1));
+            merge(&mut counts, &apply_rec(rules, &last, rounds-1));
+        }
+        counts
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = (String, HashMap<String, String>);
+
+    fn parse(input: &str) -> Self::Input {
+        game_from_input(input)
+    }
+
+    fn part1((template, rules): &Self::Input) -> Answer {
+        let mut polymer = template.clone();
+        for _ in 0..10 {
+            polymer = apply(polymer, rules);
+        }
+
+        let (most, least) = count_most_least(count(polymer));
+
+        (most - least).into()
+    }
+
+    fn part2((template, rules): &Self::Input) -> Answer {
+        let mut counts = count(template.clone());
+
+        for i in 0..template.len() - 1 {
+            let pair = &template[i..=i + 1];
+            merge(&mut counts, &apply_rec(rules, pair, 40));
+        }
+
+        let (most, least) = count_most_least(counts);
+
+        (most - least).into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use test_case::test_case;
+
+    use super::*;
+    use aoc_core::{read, Part};
+
+    #[test_case("sample1.txt" => 1588 ; "sample1")]
+    #[test_case("input.txt" => 3306 ; "input")]
+    fn part1(input: &str) -> Answer {
+        Day::solve(Part::One, &read(input))
+    }
+
+    #[test_case("sample1.txt" => 2188189693529 ; "sample1")]
+    #[test_case("input.txt" => 3760312702877 ; "input")]
+    fn part2(input: &str) -> Answer {
+        Day::solve(Part::Two, &read(input))
+    }
+}
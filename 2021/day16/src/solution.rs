@@ -0,0 +1,610 @@
+use aoc_core::{Answer, Solution};
+use std::fmt::{self, Display, Formatter};
+
+/// A BITS packet. Operators carry the packets they work on; the comparisons
+/// always have exactly two.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Packet {
+    Literal {
+        version: u8,
+        value: u64,
+    },
+    Sum {
+        version: u8,
+        packets: Vec<Packet>,
+    },
+    Product {
+        version: u8,
+        packets: Vec<Packet>,
+    },
+    Min {
+        version: u8,
+        packets: Vec<Packet>,
+    },
+    Max {
+        version: u8,
+        packets: Vec<Packet>,
+    },
+    Gt {
+        version: u8,
+        packets: Box<[Packet; 2]>,
+    },
+    Lt {
+        version: u8,
+        packets: Box<[Packet; 2]>,
+    },
+    Eq {
+        version: u8,
+        packets: Box<[Packet; 2]>,
+    },
+}
+
+/// How an operator says where its sub-packets end.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LengthType {
+    /// The total length of the sub-packets in bits, in 15 bits.
+    Bits,
+    /// The number of sub-packets, in 11 bits.
+    Count,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecodeError {
+    InvalidHex(char),
+    UnexpectedEnd,
+    /// A literal with more groups than fit in 64 bits.
+    LiteralOverflow,
+    /// A sub-packet ran past the number of bits its operator gave it.
+    Overrun,
+    /// An operator with the wrong number of sub-packets: none at all, or
+    /// anything but two for a comparison.
+    Operands {
+        type_id: u8,
+        count: usize,
+    },
+}
+
+impl Display for DecodeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::InvalidHex(c) => write!(f, "not a hex digit: {c:?}"),
+            DecodeError::UnexpectedEnd => write!(f, "transmission ends inside a packet"),
+            DecodeError::LiteralOverflow => write!(f, "literal does not fit in 64 bits"),
+            DecodeError::Overrun => write!(f, "sub-packets run past their length"),
+            DecodeError::Operands { type_id, count } => {
+                write!(f, "operator {type_id} cannot take {count} packets")
+            }
+        }
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EncodeError {
+    /// Versions only have three bits.
+    Version(u8),
+    /// The sub-packets do not fit the length field.
+    TooLong {
+        length_type: LengthType,
+        length: usize,
+    },
+    /// An operator without sub-packets, which decoding would reject.
+    /// Comparisons always have two.
+    Operands { type_id: u8, count: usize },
+}
+
+impl Display for EncodeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            EncodeError::Version(version) => write!(f, "version {version} needs more than 3 bits"),
+            EncodeError::TooLong {
+                length_type,
+                length,
+            } => write!(f, "{length_type:?} length {length} does not fit"),
+            EncodeError::Operands { type_id, count } => {
+                write!(f, "operator {type_id} cannot take {count} packets")
+            }
+        }
+    }
+}
+
+impl std::error::Error for EncodeError {}
+
+/// Reads the transmission bit by bit, most significant first.
+struct Bits {
+    bits: Vec<bool>,
+    position: usize,
+}
+
+impl Bits {
+    fn from_hex(hex: &str) -> Result<Bits, DecodeError> {
+        let mut bits = Vec::with_capacity(hex.len() * 4);
+        for c in hex.chars() {
+            let digit = c.to_digit(16).ok_or(DecodeError::InvalidHex(c))?;
+            bits.extend((0..4).rev().map(|i| digit >> i & 1 == 1));
+        }
+        Ok(Bits { bits, position: 0 })
+    }
+
+    fn read(&mut self, count: usize) -> Result<u64, DecodeError> {
+        let bits = self
+            .bits
+            .get(self.position..self.position + count)
+            .ok_or(DecodeError::UnexpectedEnd)?;
+        self.position += count;
+        Ok(bits.iter().fold(0, |n, &bit| n << 1 | bit as u64))
+    }
+}
+
+/// Collects the bits of an encoded packet.
+#[derive(Default)]
+struct Writer {
+    bits: Vec<bool>,
+}
+
+impl Writer {
+    fn write(&mut self, value: u64, count: usize) {
+        self.bits
+            .extend((0..count).rev().map(|i| value >> i & 1 == 1));
+    }
+
+    fn into_hex(mut self) -> String {
+        self.bits.resize(self.bits.len().div_ceil(8) * 8, false);
+        self.bits
+            .chunks(4)
+            .map(|nibble| {
+                let digit = nibble.iter().fold(0, |n, &bit| n << 1 | bit as u32);
+                char::from_digit(digit, 16).unwrap().to_ascii_uppercase()
+            })
+            .collect()
+    }
+}
+
+const LITERAL: u8 = 4;
+
+impl Packet {
+    /// Decodes the outermost packet of a hex transmission, ignoring the
+    /// padding after it.
+    pub fn decode(hex: &str) -> Result<Packet, DecodeError> {
+        Packet::read(&mut Bits::from_hex(hex.trim())?)
+    }
+
+    fn read(bits: &mut Bits) -> Result<Packet, DecodeError> {
+        let version = bits.read(3)? as u8;
+        let type_id = bits.read(3)? as u8;
+        if type_id == LITERAL {
+            let mut value = 0;
+            loop {
+                let group = bits.read(5)?;
+                if value >> 60 != 0 {
+                    return Err(DecodeError::LiteralOverflow);
+                }
+                value = value << 4 | group & 0b1111;
+                if group & 0b10000 == 0 {
+                    return Ok(Packet::Literal { version, value });
+                }
+            }
+        }
+
+        let mut packets = Vec::new();
+        if bits.read(1)? == 0 {
+            let length = bits.read(15)? as usize;
+            let end = bits.position + length;
+            while bits.position < end {
+                packets.push(Packet::read(bits)?);
+            }
+            if bits.position > end {
+                return Err(DecodeError::Overrun);
+            }
+        } else {
+            for _ in 0..bits.read(11)? {
+                packets.push(Packet::read(bits)?);
+            }
+        }
+        Packet::operator(version, type_id, packets)
+    }
+
+    fn operator(version: u8, type_id: u8, packets: Vec<Packet>) -> Result<Packet, DecodeError> {
+        let operands = DecodeError::Operands {
+            type_id,
+            count: packets.len(),
+        };
+        Ok(match type_id {
+            0..=3 if packets.is_empty() => return Err(operands),
+            0 => Packet::Sum { version, packets },
+            1 => Packet::Product { version, packets },
+            2 => Packet::Min { version, packets },
+            3 => Packet::Max { version, packets },
+            _ => {
+                let packets = Box::new(<[Packet; 2]>::try_from(packets).map_err(|_| operands)?);
+                match type_id {
+                    5 => Packet::Gt { version, packets },
+                    6 => Packet::Lt { version, packets },
+                    _ => Packet::Eq { version, packets },
+                }
+            }
+        })
+    }
+
+    /// Encodes the packet as hex, padded with zeros to whole bytes. All
+    /// operators use the same `length_type`.
+    pub fn encode(&self, length_type: LengthType) -> Result<String, EncodeError> {
+        let mut writer = Writer::default();
+        self.write(&mut writer, length_type)?;
+        Ok(writer.into_hex())
+    }
+
+    fn write(&self, writer: &mut Writer, length_type: LengthType) -> Result<(), EncodeError> {
+        let version = self.version();
+        if version > 7 {
+            return Err(EncodeError::Version(version));
+        }
+        writer.write(version as u64, 3);
+        writer.write(self.type_id() as u64, 3);
+
+        if let Packet::Literal { value, .. } = self {
+            let groups = (64 - value.leading_zeros() as usize).div_ceil(4).max(1);
+            for group in (0..groups).rev() {
+                let more = (group > 0) as u64;
+                writer.write(more << 4 | value >> (group * 4) & 0b1111, 5);
+            }
+            return Ok(());
+        }
+
+        if self.packets().is_empty() {
+            return Err(EncodeError::Operands {
+                type_id: self.type_id(),
+                count: 0,
+            });
+        }
+
+        let mut sub = Writer::default();
+        for packet in self.packets() {
+            packet.write(&mut sub, length_type)?;
+        }
+        let (flag, width, length) = match length_type {
+            LengthType::Bits => (0, 15, sub.bits.len()),
+            LengthType::Count => (1, 11, self.packets().len()),
+        };
+        if length >= 1 << width {
+            return Err(EncodeError::TooLong {
+                length_type,
+                length,
+            });
+        }
+        writer.write(flag, 1);
+        writer.write(length as u64, width);
+        writer.bits.extend(sub.bits);
+        Ok(())
+    }
+
+    pub fn version(&self) -> u8 {
+        match self {
+            Packet::Literal { version, .. }
+            | Packet::Sum { version, .. }
+            | Packet::Product { version, .. }
+            | Packet::Min { version, .. }
+            | Packet::Max { version, .. }
+            | Packet::Gt { version, .. }
+            | Packet::Lt { version, .. }
+            | Packet::Eq { version, .. } => *version,
+        }
+    }
+
+    fn type_id(&self) -> u8 {
+        match self {
+            Packet::Sum { .. } => 0,
+            Packet::Product { .. } => 1,
+            Packet::Min { .. } => 2,
+            Packet::Max { .. } => 3,
+            Packet::Literal { .. } => LITERAL,
+            Packet::Gt { .. } => 5,
+            Packet::Lt { .. } => 6,
+            Packet::Eq { .. } => 7,
+        }
+    }
+
+    /// The sub-packets, none for a literal.
+    pub fn packets(&self) -> &[Packet] {
+        match self {
+            Packet::Literal { .. } => &[],
+            Packet::Sum { packets, .. }
+            | Packet::Product { packets, .. }
+            | Packet::Min { packets, .. }
+            | Packet::Max { packets, .. } => packets,
+            Packet::Gt { packets, .. }
+            | Packet::Lt { packets, .. }
+            | Packet::Eq { packets, .. } => &packets[..],
+        }
+    }
+
+    pub fn version_sum(&self) -> u64 {
+        self.version() as u64 + self.packets().iter().map(Packet::version_sum).sum::<u64>()
+    }
+
+    pub fn value(&self) -> u64 {
+        let values = self.packets().iter().map(Packet::value);
+        match self {
+            Packet::Literal { value, .. } => *value,
+            Packet::Sum { .. } => values.sum(),
+            Packet::Product { .. } => values.product(),
+            Packet::Min { .. } => values.min().expect("min of no packets"),
+            Packet::Max { .. } => values.max().expect("max of no packets"),
+            Packet::Gt { packets, .. } => (packets[0].value() > packets[1].value()) as u64,
+            Packet::Lt { packets, .. } => (packets[0].value() < packets[1].value()) as u64,
+            Packet::Eq { packets, .. } => (packets[0].value() == packets[1].value()) as u64,
+        }
+    }
+
+    fn symbol(&self) -> &'static str {
+        match self {
+            Packet::Literal { .. } => "",
+            Packet::Sum { .. } => "+",
+            Packet::Product { .. } => "*",
+            Packet::Min { .. } => "min",
+            Packet::Max { .. } => "max",
+            Packet::Gt { .. } => ">",
+            Packet::Lt { .. } => "<",
+            Packet::Eq { .. } => "=",
+        }
+    }
+
+    /// Writes the packet indented by `depth`, keeping operators on one line
+    /// only when all their packets are literals.
+    fn pretty(&self, f: &mut Formatter<'_>, depth: usize) -> fmt::Result {
+        let packets = self.packets();
+        if packets
+            .iter()
+            .all(|packet| matches!(packet, Packet::Literal { .. }))
+        {
+            return write!(f, "{self}");
+        }
+        write!(f, "({}", self.symbol())?;
+        for packet in packets {
+            write!(f, "\n{:width$}", "", width = (depth + 1) * 2)?;
+            packet.pretty(f, depth + 1)?;
+        }
+        write!(f, ")")
+    }
+}
+
+/// Prints the packet as an S-expression such as `(+ 1 (* 2 3))`, leaving out
+/// the versions. The alternate form `{:#}` spreads it over indented lines.
+impl Display for Packet {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            return self.pretty(f, 0);
+        }
+        match self {
+            Packet::Literal { value, .. } => write!(f, "{value}"),
+            _ => {
+                write!(f, "({}", self.symbol())?;
+                for packet in self.packets() {
+                    write!(f, " {packet}")?;
+                }
+                write!(f, ")")
+            }
+        }
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Packet;
+
+    fn parse(input: &str) -> Self::Input {
+        Packet::decode(input).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    fn part1(packet: &Self::Input) -> Answer {
+        packet.version_sum().into()
+    }
+
+    fn part2(packet: &Self::Input) -> Answer {
+        packet.value().into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use test_case::test_case;
+
+    use super::*;
+    use aoc_core::{read, Part};
+
+    fn literal(version: u8, value: u64) -> Packet {
+        Packet::Literal { version, value }
+    }
+
+    #[test]
+    fn literal_packet() {
+        assert_eq!(Ok(literal(6, 2021)), Packet::decode("D2FE28"));
+        assert_eq!(
+            Ok(literal(6, u64::MAX)),
+            Packet::decode("D3FFFFFFFFFFFFFFFFFFBC")
+        );
+    }
+
+    #[test]
+    fn operator_length() {
+        assert_eq!(
+            Ok(Packet::Lt {
+                version: 1,
+                packets: Box::new([literal(6, 10), literal(2, 20)])
+            }),
+            Packet::decode("38006F45291200")
+        );
+    }
+
+    #[test]
+    fn operator_count() {
+        assert_eq!(
+            Ok(Packet::Max {
+                version: 7,
+                packets: vec![literal(2, 1), literal(4, 2), literal(1, 3)]
+            }),
+            Packet::decode("EE00D40C823060")
+        );
+    }
+
+    #[test_case("D2FE28", LengthType::Bits => "D2FE28"; "literal")]
+    #[test_case("38006F45291200", LengthType::Bits => "38006F45291200"; "lengths")]
+    #[test_case("EE00D40C823060", LengthType::Count => "EE00D40C823060"; "counts")]
+    fn encoding(hex: &str, length_type: LengthType) -> String {
+        Packet::decode(hex).unwrap().encode(length_type).unwrap()
+    }
+
+    #[test_case("sample1.txt"; "sample1")]
+    #[test_case("sample2.txt"; "sample2")]
+    #[test_case("sample3.txt"; "sample3")]
+    #[test_case("sample4.txt"; "sample4")]
+    #[test_case("input.txt"; "input")]
+    fn round_trip(input: &str) {
+        let packet = Day::parse(&read(input));
+        for length_type in [LengthType::Bits, LengthType::Count] {
+            let hex = packet.encode(length_type).unwrap();
+            assert_eq!(Ok(&packet), Packet::decode(&hex).as_ref());
+        }
+    }
+
+    #[test]
+    fn hand_crafted() {
+        // (2 + 3) * (4 == 4)
+        let packet = Packet::Product {
+            version: 0,
+            packets: vec![
+                Packet::Sum {
+                    version: 1,
+                    packets: vec![literal(2, 2), literal(3, 3)],
+                },
+                Packet::Eq {
+                    version: 4,
+                    packets: Box::new([literal(5, 4), literal(6, 4)]),
+                },
+            ],
+        };
+        assert_eq!(5, packet.value());
+        assert_eq!(21, packet.version_sum());
+        assert_eq!("(* (+ 2 3) (= 4 4))", packet.to_string());
+        assert_eq!("(*\n  (+ 2 3)\n  (= 4 4))", format!("{packet:#}"));
+
+        let hex = packet.encode(LengthType::Count).unwrap();
+        assert_eq!(Ok(5), Packet::decode(&hex).map(|p| p.value()));
+    }
+
+    #[test_case("C200B40A82" => "(+ 1 2)"; "sum")]
+    #[test_case("9C0141080250320F1802104A08" => "(= (+ 1 3) (* 2 2))"; "nested")]
+    fn printing(hex: &str) -> String {
+        Packet::decode(hex).unwrap().to_string()
+    }
+
+    #[test_case("D2FG28" => DecodeError::InvalidHex('G'); "bad digit")]
+    #[test_case("D2FE" => DecodeError::UnexpectedEnd; "truncated literal")]
+    #[test_case("38006F452912" => DecodeError::UnexpectedEnd; "truncated operator")]
+    #[test_case("EE000" => DecodeError::Operands { type_id: 3, count: 0 }; "empty max")]
+    #[test_case("3A004428" => DecodeError::Operands { type_id: 6, count: 1 }; "lonely comparison")]
+    #[test_case("D3FFFFFFFFFFFFFFFFFFFC20" => DecodeError::LiteralOverflow; "huge literal")]
+    fn decode_errors(hex: &str) -> DecodeError {
+        Packet::decode(hex).unwrap_err()
+    }
+
+    #[test_case("E20000", Packet::Sum { version: 7, packets: vec![] }; "sum")]
+    #[test_case("E60000", Packet::Product { version: 7, packets: vec![] }; "product")]
+    #[test_case("EA0000", Packet::Min { version: 7, packets: vec![] }; "min")]
+    #[test_case("EE0000", Packet::Max { version: 7, packets: vec![] }; "max")]
+    fn empty_operators(hex: &str, packet: Packet) {
+        let type_id = packet.type_id();
+        assert_eq!(
+            Err(DecodeError::Operands { type_id, count: 0 }),
+            Packet::decode(hex)
+        );
+        assert_eq!(
+            Err(EncodeError::Operands { type_id, count: 0 }),
+            packet.encode(LengthType::Count)
+        );
+
+        // with an operand, both ways work again
+        let packets = vec![literal(1, 5)];
+        let packet = match packet {
+            Packet::Sum { version, .. } => Packet::Sum { version, packets },
+            Packet::Product { version, .. } => Packet::Product { version, packets },
+            Packet::Min { version, .. } => Packet::Min { version, packets },
+            Packet::Max { version, .. } => Packet::Max { version, packets },
+            _ => unreachable!(),
+        };
+        let hex = packet.encode(LengthType::Count).unwrap();
+        let decoded = Packet::decode(&hex).unwrap();
+        assert_eq!(packet, decoded);
+        assert_eq!(Ok(hex), decoded.encode(LengthType::Count));
+    }
+
+    #[test]
+    fn encode_errors() {
+        assert_eq!(
+            Err(EncodeError::Version(8)),
+            literal(8, 1).encode(LengthType::Bits)
+        );
+        let wide = Packet::Sum {
+            version: 0,
+            packets: vec![literal(0, 1); 2048],
+        };
+        assert_eq!(
+            Err(EncodeError::TooLong {
+                length_type: LengthType::Count,
+                length: 2048
+            }),
+            wide.encode(LengthType::Count)
+        );
+        assert!(wide.encode(LengthType::Bits).is_ok());
+
+        let empty = Packet::Max {
+            version: 7,
+            packets: vec![],
+        };
+        assert_eq!(
+            Err(EncodeError::Operands {
+                type_id: 3,
+                count: 0
+            }),
+            empty.encode(LengthType::Count)
+        );
+        let nothing = Packet::Sum {
+            version: 0,
+            packets: vec![],
+        };
+        assert!(nothing.encode(LengthType::Bits).is_err());
+    }
+
+    #[test_case("sample1.txt" => 16; "sample1")]
+    #[test_case("sample2.txt" => 12; "sample2")]
+    #[test_case("sample3.txt" => 23; "sample3")]
+    #[test_case("sample4.txt" => 31; "sample4")]
+    #[test_case("input.txt" => 936; "input")]
+    fn part1(input: &str) -> Answer {
+        Day::solve(Part::One, &read(input))
+    }
+
+    #[test_case("D2FE28" => 2021; "sample0")]
+    #[test_case("C200B40A82" => 3; "sample1")]
+    #[test_case("04005AC33890" => 54; "sample2")]
+    #[test_case("880086C3E88112" => 7; "sample3")]
+    #[test_case("CE00C43D881120" => 9; "sample4")]
+    #[test_case("D8005AC2A8F0" => 1; "sample5")]
+    #[test_case("F600BC2D8F" => 0; "sample6")]
+    #[test_case("9C005AC2F8F0" => 0; "sample7")]
+    #[test_case("9C0141080250320F1802104A08" => 1; "sample8")]
+    fn part2_samples(input: &str) -> u64 {
+        Packet::decode(input).unwrap().value()
+    }
+
+    #[test_case("sample1.txt" => 15; "sample1")]
+    #[test_case("sample2.txt" => 46; "sample2")]
+    #[test_case("sample3.txt" => 46; "sample3")]
+    #[test_case("sample4.txt" => 54; "sample4")]
+    #[test_case("input.txt" => 6802496672062; "input")]
+    fn part2(input: &str) -> Answer {
+        Day::solve(Part::Two, &read(input))
+    }
+}
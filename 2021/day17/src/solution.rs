@@ -0,0 +1,127 @@
+use aoc_core::{Answer, Solution};
+use scan_fmt::scan_fmt;
+use std::collections::HashSet;
+
+type Area = ((i32, i32), (i32, i32));
+
+fn area_from_string(input: &str) -> Area {
+    let (x1, x2, y1, y2) = scan_fmt!(
+        input,
+        "target area: x={d}..{d}, y={d}..{d}",
+        i32,
+        i32,
+        i32,
+        i32
+    )
+    .unwrap();
+
+    ((x1, y1), (x2, y2))
+}
+
+fn highest(&((x1, y1), (x2, y2)): &Area) -> i32 {
+    // vx>0
+    // vy>0
+    // horiz speed stop: vx-n+1/2=0 == n=vx+1/2
+    // vert speed stop: vy-n+1/2=0 == n=vy+1/2
+    // x=n*vx+n-n(n+1)/2
+    // y=n*vy+n-n(n+1)/2
+
+    // max height:
+    // y=vy*vy+vy-vy(vy+1)/2
+    // y=vy*(vy+1-(vy+1)/2)
+    // y=vy*(vy+1)/2
+
+    // y=vy²/2+vy/2
+
+    // x^2/+x/2-t
+
+    // x = -1/2 +- sqrt(1/4+2t)
+
+    let minvx = f32::ceil(f32::sqrt(1.0 / 4.0 + 2.0 * x1 as f32) - 1.0 / 2.0) as i32;
+    let maxvx = f32::floor(f32::sqrt(1.0 / 4.0 + 2.0 * x2 as f32) - 1.0 / 2.0) as i32;
+
+    let mut max_height = 0;
+
+    const LIMIT: i32 = 300;
+    for vx in minvx..=maxvx {
+        for vy in 1..=LIMIT {
+            let y = vx * vy + vx - vx * (vx + 1) / 2;
+            if y >= y1 {
+                for n in vx..=LIMIT {
+                    let nx = if n <= vx { n } else { vx };
+                    let x = nx * vx + nx - nx * (nx + 1) / 2;
+                    let y = n * vy + n - n * (n + 1) / 2;
+                    if x1 <= x && x <= x2 && y1 <= y && y <= y2 {
+                        let max = vy * (vy + 1) / 2;
+                        if max > max_height {
+                            max_height = max;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    max_height
+}
+
+fn hits(&((x1, y1), (x2, y2)): &Area) -> usize {
+    let minvx = f32::ceil(f32::sqrt(1.0 / 4.0 + 2.0 * x1 as f32) - 1.0 / 2.0) as i32;
+    let maxvx = x2;
+
+    let mut hits: HashSet<(i32, i32)> = HashSet::new();
+
+    const LIMIT: i32 = 300;
+    for vx in minvx..=maxvx {
+        for vy in y1..=LIMIT {
+            for n in 1..=LIMIT {
+                let nx = if n <= vx { n } else { vx };
+                let x = nx * vx + nx - nx * (nx + 1) / 2;
+                let y = n * vy + n - n * (n + 1) / 2;
+                if x1 <= x && x <= x2 && y1 <= y && y <= y2 {
+                    hits.insert((vx, vy));
+                }
+            }
+        }
+    }
+
+    hits.len()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Area;
+
+    fn parse(input: &str) -> Self::Input {
+        area_from_string(input)
+    }
+
+    fn part1(area: &Self::Input) -> Answer {
+        highest(area).into()
+    }
+
+    fn part2(area: &Self::Input) -> Answer {
+        hits(area).into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use test_case::test_case;
+
+    use super::*;
+    use aoc_core::{read, Part};
+
+    #[test_case("sample1.txt" => 45 ; "sample1")]
+    #[test_case("input.txt" => 7750 ; "input")]
+    fn part1(input: &str) -> Answer {
+        Day::solve(Part::One, &read(input))
+    }
+
+    #[test_case("sample1.txt" => 112 ; "sample1")]
+    #[test_case("input.txt" => 4120 ; "input")]
+    fn part2(input: &str) -> Answer {
+        Day::solve(Part::Two, &read(input))
+    }
+}
@@ -0,0 +1,421 @@
+use aoc_core::{Answer, Solution};
+use rayon::prelude::*;
+use std::fmt::{self, Display, Formatter};
+use std::iter::Sum;
+use std::ops::Add;
+use std::str::FromStr;
+
+/// A regular number together with the number of pairs it sits in.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+struct Element {
+    value: u32,
+    depth: u8,
+}
+
+/// A snailfish number stored as its regular numbers from left to right,
+/// each tagged with its depth. The pairs are implied: a pair is two
+/// neighbouring halves at one depth more than the pair itself.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SnailfishNumber {
+    elements: Vec<Element>,
+}
+
+/// One step of a reduction.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Action {
+    Explode,
+    Split,
+}
+
+impl Display for Action {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Action::Explode => write!(f, "explode"),
+            Action::Split => write!(f, "split"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseSnailfishError {
+    position: usize,
+}
+
+impl Display for ParseSnailfishError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "malformed snailfish number at byte {}", self.position)
+    }
+}
+
+impl std::error::Error for ParseSnailfishError {}
+
+impl FromStr for SnailfishNumber {
+    type Err = ParseSnailfishError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim().as_bytes();
+        let mut elements = Vec::new();
+        let mut position = 0;
+        parse_element(s, &mut position, 0, &mut elements)?;
+        if position != s.len() {
+            return Err(ParseSnailfishError { position });
+        }
+        Ok(SnailfishNumber { elements })
+    }
+}
+
+fn parse_element(
+    s: &[u8],
+    position: &mut usize,
+    depth: u8,
+    elements: &mut Vec<Element>,
+) -> Result<(), ParseSnailfishError> {
+    let expect = |position: &mut usize, c: u8| {
+        if s.get(*position) == Some(&c) {
+            *position += 1;
+            Ok(())
+        } else {
+            Err(ParseSnailfishError {
+                position: *position,
+            })
+        }
+    };
+    if s.get(*position) == Some(&b'[') {
+        expect(position, b'[')?;
+        parse_element(s, position, depth + 1, elements)?;
+        expect(position, b',')?;
+        parse_element(s, position, depth + 1, elements)?;
+        return expect(position, b']');
+    }
+
+    let start = *position;
+    while s.get(*position).is_some_and(u8::is_ascii_digit) {
+        *position += 1;
+    }
+    let value = std::str::from_utf8(&s[start..*position])
+        .unwrap()
+        .parse()
+        .map_err(|_| ParseSnailfishError { position: start })?;
+    elements.push(Element { value, depth });
+    Ok(())
+}
+
+impl Display for SnailfishNumber {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.write(f, &mut 0, 0)
+    }
+}
+
+impl SnailfishNumber {
+    fn write(&self, f: &mut Formatter<'_>, next: &mut usize, depth: u8) -> fmt::Result {
+        let element = self.elements[*next];
+        if element.depth == depth {
+            *next += 1;
+            return write!(f, "{}", element.value);
+        }
+        write!(f, "[")?;
+        self.write(f, next, depth + 1)?;
+        write!(f, ",")?;
+        self.write(f, next, depth + 1)?;
+        write!(f, "]")
+    }
+
+    /// The pair of both numbers, before reducing it.
+    fn pair(&self, other: &SnailfishNumber) -> SnailfishNumber {
+        let elements = self
+            .elements
+            .iter()
+            .chain(&other.elements)
+            .map(|element| Element {
+                depth: element.depth + 1,
+                ..*element
+            })
+            .collect();
+        SnailfishNumber { elements }
+    }
+
+    /// Explodes the leftmost pair nested inside four pairs, if there is one.
+    pub fn explode(&mut self) -> bool {
+        // The first two neighbours this deep at the same depth always form
+        // a pair: a left half that is a pair itself would have been found
+        // first.
+        let Some(i) = self
+            .elements
+            .windows(2)
+            .position(|pair| pair[0].depth > 4 && pair[0].depth == pair[1].depth)
+        else {
+            return false;
+        };
+        let (left, right) = (self.elements[i], self.elements[i + 1]);
+        if i > 0 {
+            self.elements[i - 1].value += left.value;
+        }
+        if let Some(next) = self.elements.get_mut(i + 2) {
+            next.value += right.value;
+        }
+        self.elements.splice(
+            i..i + 2,
+            [Element {
+                value: 0,
+                depth: left.depth - 1,
+            }],
+        );
+        true
+    }
+
+    /// Splits the leftmost regular number of 10 or more, if there is one.
+    pub fn split(&mut self) -> bool {
+        let Some(i) = self.elements.iter().position(|element| element.value >= 10) else {
+            return false;
+        };
+        let Element { value, depth } = self.elements[i];
+        self.elements.splice(
+            i..=i,
+            [
+                Element {
+                    value: value / 2,
+                    depth: depth + 1,
+                },
+                Element {
+                    value: value.div_ceil(2),
+                    depth: depth + 1,
+                },
+            ],
+        );
+        true
+    }
+
+    pub fn reduce(&mut self) {
+        self.reduce_traced(|_, _| ());
+    }
+
+    /// Reduces the number, calling `trace` with the number after every step.
+    pub fn reduce_traced(&mut self, mut trace: impl FnMut(Action, &SnailfishNumber)) {
+        loop {
+            let action = if self.explode() {
+                Action::Explode
+            } else if self.split() {
+                Action::Split
+            } else {
+                return;
+            };
+            trace(action, self);
+        }
+    }
+
+    /// Like `self + other`, calling `trace` with the unreduced pair and then
+    /// after every reduction step.
+    pub fn add_traced(
+        &self,
+        other: &SnailfishNumber,
+        mut trace: impl FnMut(Option<Action>, &SnailfishNumber),
+    ) -> SnailfishNumber {
+        let mut sum = self.pair(other);
+        trace(None, &sum);
+        sum.reduce_traced(|action, number| trace(Some(action), number));
+        sum
+    }
+
+    pub fn magnitude(&self) -> u64 {
+        // Collapses each pair as soon as both of its halves are known.
+        let mut stack: Vec<(u64, u8)> = Vec::new();
+        for element in &self.elements {
+            let mut top = (element.value as u64, element.depth);
+            while let Some(&(left, depth)) = stack.last() {
+                if depth != top.1 {
+                    break;
+                }
+                stack.pop();
+                top = (3 * left + 2 * top.0, depth - 1);
+            }
+            stack.push(top);
+        }
+        stack[0].0
+    }
+}
+
+impl Add<&SnailfishNumber> for &SnailfishNumber {
+    type Output = SnailfishNumber;
+
+    fn add(self, other: &SnailfishNumber) -> SnailfishNumber {
+        let mut sum = self.pair(other);
+        sum.reduce();
+        sum
+    }
+}
+
+impl Add for SnailfishNumber {
+    type Output = SnailfishNumber;
+
+    fn add(self, other: SnailfishNumber) -> SnailfishNumber {
+        &self + &other
+    }
+}
+
+/// Adds up the numbers from the first to the last. There is no zero, so the
+/// sum of no numbers panics.
+impl Sum for SnailfishNumber {
+    fn sum<I: Iterator<Item = SnailfishNumber>>(iter: I) -> SnailfishNumber {
+        iter.reduce(|sum, number| sum + number)
+            .expect("sum of no snailfish numbers")
+    }
+}
+
+impl<'a> Sum<&'a SnailfishNumber> for SnailfishNumber {
+    fn sum<I: Iterator<Item = &'a SnailfishNumber>>(iter: I) -> SnailfishNumber {
+        iter.cloned().sum()
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<SnailfishNumber>;
+
+    fn parse(input: &str) -> Self::Input {
+        input
+            .lines()
+            .map(|line| line.parse().unwrap_or_else(|e| panic!("{e}")))
+            .collect()
+    }
+
+    fn part1(nums: &Self::Input) -> Answer {
+        nums.iter().sum::<SnailfishNumber>().magnitude().into()
+    }
+
+    fn part2(nums: &Self::Input) -> Answer {
+        nums.par_iter()
+            .enumerate()
+            .flat_map_iter(|(i, first)| {
+                nums.iter()
+                    .enumerate()
+                    .filter(move |&(j, _)| i != j)
+                    .map(move |(_, second)| (first + second).magnitude())
+            })
+            .max()
+            .unwrap()
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use test_case::test_case;
+
+    use super::*;
+    use aoc_core::{read, Part};
+
+    fn num(s: &str) -> SnailfishNumber {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_to_string() {
+        let s = "[[[[5,0],[7,4]],[5,5]],[6,6]]";
+        assert_eq!(s, num(s).to_string());
+        assert_eq!("[[15,[0,13]],1]", num("[[15,[0,13]],1]").to_string());
+    }
+
+    #[test_case("[1,2" => 4; "unclosed")]
+    #[test_case("[1;2]" => 2; "separator")]
+    #[test_case("[1,2]]" => 5; "trailing")]
+    #[test_case("[,2]" => 1; "missing number")]
+    fn parse_error(input: &str) -> usize {
+        input.parse::<SnailfishNumber>().unwrap_err().position
+    }
+
+    #[test_case("9" => 9; "num")]
+    #[test_case("[9,1]" => 29; "0")]
+    #[test_case("[[1,2],[[3,4],5]]" => 143; "1")]
+    #[test_case("[[[[0,7],4],[[7,8],[6,0]]],[8,1]]" => 1384; "2")]
+    #[test_case("[[[[1,1],[2,2]],[3,3]],[4,4]]" => 445; "3")]
+    #[test_case("[[[[3,0],[5,3]],[4,4]],[5,5]]" => 791; "4")]
+    #[test_case("[[[[5,0],[7,4]],[5,5]],[6,6]]" => 1137; "5")]
+    #[test_case("[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]" => 3488; "6")]
+    fn magnitude(input: &str) -> u64 {
+        num(input).magnitude()
+    }
+
+    #[test_case("[1,2]","[[3,4],5]" => "[[1,2],[[3,4],5]]"; "simple add")]
+    #[test_case("[[[[4,3],4],4],[7,[[8,4],9]]]","[1,1]" => "[[[[0,7],4],[[7,8],[6,0]]],[8,1]]"; "reducing add")]
+    fn adding(first: &str, second: &str) -> String {
+        let sum = num(first) + num(second);
+        assert_eq!(sum, &num(first) + &num(second));
+        sum.to_string()
+    }
+
+    #[test_case("[1,1]\n[2,2]\n[3,3]\n[4,4]" => "[[[[1,1],[2,2]],[3,3]],[4,4]]"; "no reduction")]
+    #[test_case("[1,1]\n[2,2]\n[3,3]\n[4,4]\n[5,5]\n[6,6]" => "[[[[5,0],[7,4]],[5,5]],[6,6]]"; "reduction")]
+    fn summing(input: &str) -> String {
+        Day::parse(input)
+            .iter()
+            .sum::<SnailfishNumber>()
+            .to_string()
+    }
+
+    #[test_case("[[[[[9,8],1],2],3],4]","[[[[0,9],2],3],4]"; "first")]
+    #[test_case("[7,[6,[5,[4,[3,2]]]]]","[7,[6,[5,[7,0]]]]"; "last")]
+    #[test_case("[[6,[5,[4,[3,2]]]],1]","[[6,[5,[7,0]]],3]"; "in middle")]
+    #[test_case("[[3,[2,[1,[7,3]]]],[6,[5,[4,[3,2]]]]]","[[3,[2,[8,0]]],[9,[5,[4,[3,2]]]]]"; "one at a time")]
+    #[test_case("[[3,[2,[8,0]]],[9,[5,[4,[3,2]]]]]","[[3,[2,[8,0]]],[9,[5,[7,0]]]]"; "last2")]
+    fn exploding(input: &str, out: &str) {
+        let mut number = num(input);
+        assert!(number.explode());
+        assert_eq!(num(out), number);
+    }
+
+    #[test_case("10","[5,5]"; "simple")]
+    #[test_case("[[[[0,7],4],[15,[0,13]]],[1,1]]","[[[[0,7],4],[[7,8],[0,13]]],[1,1]]"; "first")]
+    #[test_case("[[[[0,7],4],[[7,8],[0,13]]],[1,1]]","[[[[0,7],4],[[7,8],[0,[6,7]]]],[1,1]]"; "second")]
+    fn split(input: &str, out: &str) {
+        let mut number = num(input);
+        assert!(number.split());
+        assert_eq!(num(out), number);
+    }
+
+    #[test]
+    fn tracing() {
+        let mut steps = Vec::new();
+        num("[[[[4,3],4],4],[7,[[8,4],9]]]").add_traced(&num("[1,1]"), |action, number| {
+            steps.push((action, number.to_string()))
+        });
+        assert_eq!(
+            vec![
+                (None, "[[[[[4,3],4],4],[7,[[8,4],9]]],[1,1]]".to_string()),
+                (
+                    Some(Action::Explode),
+                    "[[[[0,7],4],[7,[[8,4],9]]],[1,1]]".to_string()
+                ),
+                (
+                    Some(Action::Explode),
+                    "[[[[0,7],4],[15,[0,13]]],[1,1]]".to_string()
+                ),
+                (
+                    Some(Action::Split),
+                    "[[[[0,7],4],[[7,8],[0,13]]],[1,1]]".to_string()
+                ),
+                (
+                    Some(Action::Split),
+                    "[[[[0,7],4],[[7,8],[0,[6,7]]]],[1,1]]".to_string()
+                ),
+                (
+                    Some(Action::Explode),
+                    "[[[[0,7],4],[[7,8],[6,0]]],[8,1]]".to_string()
+                ),
+            ],
+            steps
+        );
+    }
+
+    #[test_case("sample1.txt" => 4140 ; "sample1")]
+    #[test_case("input.txt" => 3763 ; "input")]
+    fn part1(input: &str) -> Answer {
+        Day::solve(Part::One, &read(input))
+    }
+
+    #[test_case("sample1.txt" => 3993 ; "sample1")]
+    #[test_case("input.txt" => 4664 ; "input")]
+    fn part2(input: &str) -> Answer {
+        Day::solve(Part::Two, &read(input))
+    }
+}
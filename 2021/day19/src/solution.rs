@@ -0,0 +1,616 @@
+use aoc_core::{Answer, Solution};
+use enum_iterator::IntoEnumIterator;
+use itertools::Itertools;
+use multimap::MultiMap;
+use scan_fmt::scan_fmt;
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt::{self, Display, Formatter};
+
+type Point = (i32, i32, i32);
+
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, IntoEnumIterator, PartialEq, Clone, Eq, Hash)]
+enum Rotation {
+    XYZ,
+    YZX,
+    ZXY,
+    XnYnZ,
+    YnZnX,
+    ZnXnY,
+    XYnZn,
+    YZnXn,
+    ZXnYn,
+    XnYZn,
+    YnZXn,
+    ZnXYn,
+    XnZnYn,
+    ZnYnXn,
+    YnXnZn,
+    XZYn,
+    ZYXn,
+    YXZn,
+    XnZY,
+    ZnYX,
+    YnXZ,
+    XZnY,
+    ZYnX,
+    YXnZ,
+}
+
+impl Rotation {
+    fn rotate(&self, p: Point) -> Point {
+        match self {
+            Self::XYZ => p,
+            Self::YZX => (p.1, p.2, p.0),
+            Self::ZXY => (p.2, p.0, p.1),
+            Self::XnYnZ => (-p.0, -p.1, p.2),
+            Self::YnZnX => (-p.1, -p.2, p.0),
+            Self::ZnXnY => (-p.2, -p.0, p.1),
+            Self::XYnZn => (p.0, -p.1, -p.2),
+            Self::YZnXn => (p.1, -p.2, -p.0),
+            Self::ZXnYn => (p.2, -p.0, -p.1),
+            Self::XnYZn => (-p.0, p.1, -p.2),
+            Self::YnZXn => (-p.1, p.2, -p.0),
+            Self::ZnXYn => (-p.2, p.0, -p.1),
+            Self::XnZnYn => (-p.0, -p.2, -p.1),
+            Self::ZnYnXn => (-p.2, -p.1, -p.0),
+            Self::YnXnZn => (-p.1, -p.0, -p.2),
+            Self::XZYn => (p.0, p.2, -p.1),
+            Self::ZYXn => (p.2, p.1, -p.0),
+            Self::YXZn => (p.1, p.0, -p.2),
+            Self::XnZY => (-p.0, p.2, p.1),
+            Self::ZnYX => (-p.2, p.1, p.0),
+            Self::YnXZ => (-p.1, p.0, p.2),
+            Self::XZnY => (p.0, -p.2, p.1),
+            Self::ZYnX => (p.2, -p.1, p.0),
+            Self::YXnZ => (p.1, -p.0, p.2),
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Hash)]
+struct Op {
+    rotation: Rotation,
+    translation: Point,
+}
+
+fn total_distance_squared(beacons: Vec<&Point>) -> i32 {
+    let mut d = 0;
+    let length = beacons.len();
+    for i in 0..length - 1 {
+        for j in (i + 1)..length {
+            d += distance_squared(*beacons[i], *beacons[j])
+        }
+    }
+    d
+}
+
+fn distance_squared(b1: Point, b2: Point) -> i32 {
+    (b1.0 - b2.0).pow(2) + (b1.1 - b2.1).pow(2) + (b1.2 - b2.2).pow(2)
+}
+
+fn parse_sensors(input: &str) -> Vec<(i32, Vec<Point>)> {
+    input.split("\n\n").map(parse_sensor).collect()
+}
+
+fn parse_sensor(input: &str) -> (i32, Vec<Point>) {
+    let (s, c) = input.split_once("\n").unwrap();
+
+    (
+        scan_fmt!(s, "--- scanner {d} ---", i32).unwrap(),
+        parse_coordinates(c),
+    )
+}
+
+fn parse_coordinates(beacons: &str) -> Vec<Point> {
+    beacons
+        .lines()
+        .filter_map(|l| l.split(",").map(|c| c.parse().unwrap()).collect_tuple())
+        .collect()
+}
+
+fn triplet_distances(coords: &[Point]) -> MultiMap<i32, [Point; 3]> {
+    let mut dists = MultiMap::new();
+
+    for nlet in coords.iter().combinations(3) {
+        if !collinear(*nlet[0], *nlet[1], *nlet[2]) {
+            let triplet = [*nlet[0], *nlet[1], *nlet[2]];
+            let dist = total_distance_squared(nlet);
+            dists.insert(dist, triplet);
+        }
+    }
+
+    dists
+}
+
+/// Beacons along with their triplets, keyed by total distance.
+struct Fingerprint {
+    beacons: Vec<Point>,
+    triplets: MultiMap<i32, [Point; 3]>,
+}
+
+impl Fingerprint {
+    fn new(beacons: Vec<Point>) -> Fingerprint {
+        let triplets = triplet_distances(&beacons);
+        Fingerprint { beacons, triplets }
+    }
+}
+
+fn collinear(p1: Point, p2: Point, p3: Point) -> bool {
+    let u = (p1.0 - p2.0, p1.1 - p2.1, p1.2 - p2.2);
+    let v = (p1.0 - p3.0, p1.1 - p3.1, p1.2 - p3.2);
+    assert!(u.0 != 0 || u.1 != 0 || u.2 != 0);
+    assert!(v.0 != 0 || v.1 != 0 || v.2 != 0);
+
+    let x = u.1 * v.2 - u.2 * v.1;
+    let y = u.2 * v.0 - u.0 * v.2;
+    let z = u.0 * v.1 - u.1 * v.0;
+
+    x == 0 && y == 0 && z == 0
+}
+
+fn apply_rotation(triplet1: [&Point; 3], rotation: &Rotation) -> [Point; 3] {
+    [
+        rotation.rotate(*triplet1[0]),
+        rotation.rotate(*triplet1[1]),
+        rotation.rotate(*triplet1[2]),
+    ]
+}
+
+#[cfg(test)]
+fn apply_translation(triplet1: [&Point; 3], translation: &Point) -> [Point; 3] {
+    [
+        (
+            triplet1[0].0 + translation.0,
+            triplet1[0].1 + translation.1,
+            triplet1[0].2 + translation.2,
+        ),
+        (
+            triplet1[1].0 + translation.0,
+            triplet1[1].1 + translation.1,
+            triplet1[1].2 + translation.2,
+        ),
+        (
+            triplet1[2].0 + translation.0,
+            triplet1[2].1 + translation.1,
+            triplet1[2].2 + translation.2,
+        ),
+    ]
+}
+
+impl Op {
+    fn apply(&self, p: Point) -> Point {
+        let rotated = self.rotation.rotate(p);
+        (
+            rotated.0 + self.translation.0,
+            rotated.1 + self.translation.1,
+            rotated.2 + self.translation.2,
+        )
+    }
+}
+
+#[cfg(test)]
+fn find_op(triplet0: [&Point; 3], triplet1: [&Point; 3]) -> Option<Op> {
+    find_ops(triplet0, triplet1).next()
+}
+
+/// Every op that takes `triplet1` onto `triplet0`, in any order. A
+/// symmetric triplet has more than one.
+fn find_ops<'a>(
+    triplet0: [&'a Point; 3],
+    triplet1: [&'a Point; 3],
+) -> impl Iterator<Item = Op> + 'a {
+    Rotation::into_enum_iter()
+        .cartesian_product((0..3).permutations(3).collect_vec())
+        .filter_map(move |(rotation, permutation)| {
+            let candidate = [
+                triplet1[permutation[0]],
+                triplet1[permutation[1]],
+                triplet1[permutation[2]],
+            ];
+            let rotated = apply_rotation(candidate, &rotation);
+            let translation = diff(triplet0[0], &rotated[0]);
+            (translation == diff(triplet0[1], &rotated[1])
+                && translation == diff(triplet0[2], &rotated[2]))
+            .then_some(Op {
+                rotation,
+                translation,
+            })
+        })
+}
+
+fn diff(p1: &Point, p2: &Point) -> Point {
+    (p1.0 - p2.0, p1.1 - p2.1, p1.2 - p2.2)
+}
+
+/// Finds the op that puts at least 12 beacons of `second` onto beacons of
+/// `first`, along with those beacons as seen from each side. Every pairing
+/// of triplets with the same total distance votes for the ops that match
+/// them up, and the ops are checked against all beacons in order of votes.
+fn find_common_12(
+    first: &Fingerprint,
+    second: &Fingerprint,
+) -> Option<(HashSet<Point>, HashSet<Point>, Op)> {
+    let mut votes: HashMap<Op, usize> = HashMap::new();
+    for (dist, from_first) in first.triplets.iter_all() {
+        let Some(from_second) = second.triplets.get_vec(dist) else {
+            continue;
+        };
+        for (triplet0, triplet1) in from_first.iter().cartesian_product(from_second) {
+            for op in find_ops(triplet0.each_ref(), triplet1.each_ref()) {
+                *votes.entry(op).or_default() += 1;
+            }
+        }
+    }
+
+    let first_beacons: HashSet<Point> = first.beacons.iter().copied().collect();
+    votes
+        .into_iter()
+        .sorted_by_key(|(_, count)| Reverse(*count))
+        .find_map(|(op, _)| {
+            let second_common: HashSet<Point> = second
+                .beacons
+                .iter()
+                .copied()
+                .filter(|beacon| first_beacons.contains(&op.apply(*beacon)))
+                .collect();
+            if second_common.len() < 12 {
+                return None;
+            }
+            let first_common = second_common
+                .iter()
+                .map(|beacon| op.apply(*beacon))
+                .collect();
+            Some((first_common, second_common, op))
+        })
+}
+
+/// All beacons and scanner positions, relative to the first scanner.
+#[derive(Debug)]
+struct BeaconMap {
+    beacons: HashSet<Point>,
+    scanners: Vec<Point>,
+}
+
+/// The scanners that share 12 beacons with none of the placed ones.
+#[derive(Debug, PartialEq, Eq)]
+pub struct UnplacedScanners(Vec<i32>);
+
+impl Display for UnplacedScanners {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "could not place scanners {}",
+            self.0.iter().map(|id| id.to_string()).join(", ")
+        )
+    }
+}
+
+impl std::error::Error for UnplacedScanners {}
+
+/// Places the scanners one by one, breadth first from the first one,
+/// matching each against the beacons of the scanners already placed.
+fn combine_sensors(sensors: &[(i32, Vec<Point>)]) -> Result<BeaconMap, UnplacedScanners> {
+    let local: Vec<Fingerprint> = sensors
+        .iter()
+        .map(|(_, beacons)| Fingerprint::new(beacons.clone()))
+        .collect();
+    let mut placed: Vec<Option<(Fingerprint, Point)>> = sensors.iter().map(|_| None).collect();
+    placed[0] = Some((Fingerprint::new(sensors[0].1.clone()), (0, 0, 0)));
+
+    let mut queue = VecDeque::from([0]);
+    while let Some(i) = queue.pop_front() {
+        for j in 0..sensors.len() {
+            if placed[j].is_some() {
+                continue;
+            }
+            let (reference, _) = placed[i].as_ref().unwrap();
+            if let Some((_, _, op)) = find_common_12(reference, &local[j]) {
+                let beacons = local[j].beacons.iter().map(|b| op.apply(*b)).collect();
+                placed[j] = Some((Fingerprint::new(beacons), op.translation));
+                queue.push_back(j);
+            }
+        }
+    }
+
+    let unplaced: Vec<i32> = sensors
+        .iter()
+        .zip(&placed)
+        .filter(|(_, placed)| placed.is_none())
+        .map(|((id, _), _)| *id)
+        .collect();
+    if !unplaced.is_empty() {
+        return Err(UnplacedScanners(unplaced));
+    }
+    let placed = placed.into_iter().flatten();
+    let (beacons, scanners): (Vec<_>, _) = placed
+        .map(|(fingerprint, position)| (fingerprint.beacons, position))
+        .unzip();
+    Ok(BeaconMap {
+        beacons: beacons.into_iter().flatten().collect(),
+        scanners,
+    })
+}
+
+fn max_manhattan_distance(points: Vec<Point>) -> i32 {
+    let mut max = 0;
+    for pair in points.iter().combinations(2) {
+        let first = pair[0];
+        let second = pair[1];
+        let dist =
+            (first.0 - second.0).abs() + (first.1 - second.1).abs() + (first.2 - second.2).abs();
+        if dist > max {
+            max = dist;
+        }
+    }
+    max
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<(i32, Vec<Point>)>;
+
+    fn parse(input: &str) -> Self::Input {
+        parse_sensors(input)
+    }
+
+    fn part1(sensors: &Self::Input) -> Answer {
+        let map = combine_sensors(sensors).unwrap_or_else(|e| panic!("{e}"));
+        map.beacons.len().into()
+    }
+
+    fn part2(sensors: &Self::Input) -> Answer {
+        let map = combine_sensors(sensors).unwrap_or_else(|e| panic!("{e}"));
+        max_manhattan_distance(map.scanners).into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use indoc::indoc;
+    use test_case::test_case;
+
+    use super::*;
+    use aoc_core::{read, Part};
+
+    #[test]
+    fn total_distance_for_a_match() {
+        let beacons1 = indoc! {"
+            -618,-824,-621
+            -537,-823,-458
+            -447,-329,318
+            404,-588,-901
+            544,-627,-890
+            528,-643,409
+            -661,-816,-575
+            390,-675,-793
+            423,-701,434
+            -345,-311,381
+            459,-707,401
+            -485,-357,347
+        "};
+        let beacons2 = indoc! {"
+            686,422,578
+            605,423,415
+            515,917,-361
+            -336,658,858
+            -476,619,847
+            -460,603,-452
+            729,430,532
+            -322,571,750
+            -355,545,-477
+            413,935,-424
+            -391,539,-444
+            553,889,-390
+        "};
+
+        let b1 = parse_coordinates(beacons1);
+        let b2 = parse_coordinates(beacons2);
+
+        let distance1 = total_distance_squared(b1.iter().collect());
+        let distance2 = total_distance_squared(b2.iter().collect());
+        assert_eq!(distance1, distance2);
+    }
+
+    #[test]
+    fn some_triplet_distances() {
+        let coords = parse_coordinates(indoc! {"
+            0,0,0
+            1,1,2
+            2,1,2
+            4,4,4
+        "});
+
+        let mut dists = triplet_distances(&coords);
+        assert_eq!(1, dists.remove(&40).unwrap().len());
+        assert_eq!(1, dists.remove(&76).unwrap().len());
+        assert_eq!(1, dists.remove(&74).unwrap().len());
+        assert_eq!(1, dists.remove(&16).unwrap().len());
+        assert!(dists.is_empty());
+    }
+
+    #[test_case(vec![(0,0,0),(1,1,1)] => 3; "1")]
+    #[test_case(vec![(0,0,0),(1,1,1),(0,1,0)] => 3; "2")]
+    #[test_case(vec![(0,0,0),(1,1,1),(-1,-2,1)] => 5; "3")]
+    #[test_case(vec![(-1,-2,1),(0,0,0)] => 4; "4")]
+    fn finding_max_manhattan_distances(points: Vec<Point>) -> i32 {
+        max_manhattan_distance(points)
+    }
+
+    #[test]
+    fn find_common_12_from_self() {
+        let beacons = parse_coordinates(indoc! {"
+            -618,-824,-621
+            -537,-823,-458
+            -447,-329,318
+            404,-588,-901
+            544,-627,-890
+            528,-643,409
+            -661,-816,-575
+            390,-675,-793
+            423,-701,434
+            -345,-311,381
+            459,-707,401
+            -485,-357,347
+        "});
+
+        let (common0, common1, _) = find_common_12(
+            &Fingerprint::new(beacons.clone()),
+            &Fingerprint::new(beacons.clone()),
+        )
+        .unwrap();
+
+        assert_eq!(
+            HashSet::<_>::from_iter(beacons.clone()),
+            HashSet::from_iter(common0)
+        );
+        assert_eq!(
+            HashSet::<_>::from_iter(beacons),
+            HashSet::from_iter(common1)
+        );
+    }
+
+    #[test]
+    fn are_collinear() {
+        let l1 = (1, 1, 1);
+        let l2 = (2, 2, 2);
+        let l3 = (3, 3, 3);
+        let n = (1, 2, 3);
+
+        assert!(collinear(l1, l2, l3));
+        assert!(!collinear(l1, l2, n));
+        assert!(!collinear(l1, n, l3));
+        assert!(!collinear(n, l2, l3));
+    }
+
+    #[test]
+    fn find_matching_ops_just_rotate() {
+        let reference1 = [&(0, 0, 0), &(1, 0, 0), &(0, 1, 0)];
+        let reference2 = [&(0, 0, 1), &(2, 0, 0), &(0, 3, 0)];
+        let other1 = [&(0, 0, 0), &(-1, 0, 0), &(0, 1, 0)];
+        let other2 = [&(0, 0, -1), &(0, -2, 0), &(-3, 0, 0)];
+
+        assert_eq!(
+            reference1,
+            apply_rotation(other1, &find_op(reference1, other1).unwrap().rotation)
+                .iter()
+                .collect::<Vec<&Point>>()[0..3]
+        );
+        assert_eq!(
+            reference1,
+            apply_rotation(
+                reference1,
+                &find_op(reference1, reference1).unwrap().rotation
+            )
+            .iter()
+            .collect::<Vec<&Point>>()[0..3]
+        );
+        assert_eq!(
+            reference2,
+            apply_rotation(other2, &find_op(reference2, other2).unwrap().rotation)
+                .iter()
+                .collect::<Vec<&Point>>()[0..3]
+        );
+    }
+
+    #[test]
+    fn find_matching_ops_rotate_permute() {
+        let triplet0 = [&(0, 0, 0), &(1, 0, 0), &(0, 1, 0)];
+        let triplet1 = [&(-1, 0, 0), &(0, 0, 0), &(0, 1, 0)];
+
+        let rotated = apply_rotation(triplet1, &find_op(triplet0, triplet1).unwrap().rotation);
+        assert_eq!(triplet0[0], &rotated[1]);
+        assert_eq!(triplet0[1], &rotated[0]);
+        assert_eq!(triplet0[2], &rotated[2]);
+    }
+
+    #[test]
+    fn find_matching_ops_rotate_permute_translate() {
+        let triplet0 = [&(0, 0, 0), &(1, 0, 0), &(0, 1, 0)];
+        let triplet1 = [&(-1, 0, 1), &(0, 0, 1), &(0, 1, 1)];
+
+        let op = find_op(triplet0, triplet1).unwrap();
+        assert_eq!(op.translation, (0, 0, 1));
+        let rotated = apply_rotation(triplet1, &op.rotation);
+        let translated = apply_translation(
+            rotated.iter().collect::<Vec<&Point>>()[0..3]
+                .try_into()
+                .expect("slice with incorrect length"),
+            &op.translation,
+        );
+        assert_eq!(*triplet0[0], translated[1]);
+        assert_eq!(triplet0[1], &translated[0]);
+        assert_eq!(*triplet0[2], translated[2]);
+    }
+
+    #[test]
+    fn find_common_12_from_sample_0_1() {
+        let sensors = Day::parse(&read("sample1.txt"));
+
+        let common_from_0 = parse_coordinates(indoc! {"
+            -618,-824,-621
+            -537,-823,-458
+            -447,-329,318
+            404,-588,-901
+            544,-627,-890
+            528,-643,409
+            -661,-816,-575
+            390,-675,-793
+            423,-701,434
+            -345,-311,381
+            459,-707,401
+            -485,-357,347
+        "});
+
+        let (common0, common1, _) = find_common_12(
+            &Fingerprint::new(sensors[0].1.clone()),
+            &Fingerprint::new(sensors[1].1.clone()),
+        )
+        .unwrap();
+
+        assert_eq!(common_from_0.len(), common0.len());
+        assert_eq!(common_from_0.len(), common1.len());
+
+        assert_eq!(HashSet::<_>::from_iter(common_from_0), common0);
+    }
+
+    #[test]
+    fn scanner_positions() {
+        let map = combine_sensors(&Day::parse(&read("sample1.txt"))).unwrap();
+        assert_eq!(
+            vec![
+                (0, 0, 0),
+                (68, -1246, -43),
+                (1105, -1205, 1229),
+                (-92, -2380, -20),
+                (-20, -1133, 1061)
+            ],
+            map.scanners
+        );
+    }
+
+    #[test]
+    fn unplaced_scanners() {
+        let mut sensors = Day::parse(&read("sample1.txt"));
+        sensors.push((5, parse_coordinates("1,2,3\n40,-50,60\n-700,800,900\n")));
+        sensors.swap(2, 5);
+        assert_eq!(
+            UnplacedScanners(vec![5]),
+            combine_sensors(&sensors).unwrap_err()
+        );
+    }
+
+    #[test_case("sample1.txt" => 79 ; "sample1")]
+    #[test_case("input.txt" => 381 ; "input")]
+    fn part1(input: &str) -> Answer {
+        Day::solve(Part::One, &read(input))
+    }
+
+    #[test_case("sample1.txt" => 3621 ; "sample1")]
+    #[test_case("input.txt" => 12201 ; "input")]
+    fn part2(input: &str) -> Answer {
+        Day::solve(Part::Two, &read(input))
+    }
+}
@@ -0,0 +1,79 @@
+use aoc_core::{Answer, Solution};
+
+pub enum Command {
+    Forward(i32),
+    Down(i32),
+    Up(i32),
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Command>;
+
+    fn parse(input: &str) -> Self::Input {
+        input
+            .lines()
+            .map(|line| {
+                let (movement, amount) = line.split_once(' ').expect("a command");
+                let amount = amount.parse().expect("an amount");
+                match movement {
+                    "forward" => Command::Forward(amount),
+                    "down" => Command::Down(amount),
+                    "up" => Command::Up(amount),
+                    _ => panic!("unknown movement {movement}"),
+                }
+            })
+            .collect()
+    }
+
+    fn part1(commands: &Self::Input) -> Answer {
+        let mut h = 0;
+        let mut d = 0;
+        for command in commands {
+            match command {
+                Command::Forward(amount) => h += amount,
+                Command::Down(amount) => d += amount,
+                Command::Up(amount) => d -= amount,
+            }
+        }
+        (h * d).into()
+    }
+
+    fn part2(commands: &Self::Input) -> Answer {
+        let mut a = 0;
+        let mut h = 0;
+        let mut d = 0;
+        for command in commands {
+            match command {
+                Command::Forward(amount) => {
+                    h += amount;
+                    d += a * amount;
+                }
+                Command::Down(amount) => a += amount,
+                Command::Up(amount) => a -= amount,
+            }
+        }
+        (h * d).into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use test_case::test_case;
+
+    use super::*;
+    use aoc_core::{read, Part};
+
+    #[test_case("sample1.txt" => 150 ; "sample")]
+    #[test_case("input1.txt" => 1855814 ; "input")]
+    fn part1(input: &str) -> Answer {
+        Day::solve(Part::One, &read(input))
+    }
+
+    #[test_case("sample1.txt" => 900 ; "sample")]
+    #[test_case("input1.txt" => 1845455714 ; "input")]
+    fn part2(input: &str) -> Answer {
+        Day::solve(Part::Two, &read(input))
+    }
+}
@@ -0,0 +1,267 @@
+use aoc_core::{Answer, Solution};
+use std::fmt::{self, Display, Formatter};
+use std::ops::Not;
+
+/// An infinite image: a finite rectangle of pixels, with every pixel
+/// outside it in the background colour.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    /// The pixels row by row, 64 to a word.
+    bits: Vec<u64>,
+    background: bool,
+}
+
+impl Image {
+    fn new(width: usize, height: usize, background: bool) -> Image {
+        Image {
+            width,
+            height,
+            bits: vec![0; (width * height).div_ceil(64)],
+            background,
+        }
+    }
+
+    fn set(&mut self, x: usize, y: usize, lit: bool) {
+        let i = y * self.width + x;
+        if lit {
+            self.bits[i / 64] |= 1 << (i % 64);
+        } else {
+            self.bits[i / 64] &= !(1 << (i % 64));
+        }
+    }
+
+    fn get(&self, x: i64, y: i64) -> bool {
+        if x < 0 || y < 0 || x >= self.width as i64 || y >= self.height as i64 {
+            return self.background;
+        }
+        let i = y as usize * self.width + x as usize;
+        self.bits[i / 64] >> (i % 64) & 1 == 1
+    }
+
+    /// The 3×3 square around the pixel read as a binary number, top left
+    /// first.
+    fn neighbours_to_index(&self, x: i64, y: i64) -> usize {
+        (y - 1..=y + 1)
+            .flat_map(|y| (x - 1..=x + 1).map(move |x| (x, y)))
+            .fold(0, |index, (x, y)| index << 1 | self.get(x, y) as usize)
+    }
+
+    /// Applies `algo` to every pixel. The image grows by one pixel on each
+    /// side, and the background becomes what `algo` makes of a square
+    /// that is all background.
+    pub fn enhance(&self, algo: &[bool]) -> Image {
+        let background = algo[if self.background { 511 } else { 0 }];
+        let mut out = Image::new(self.width + 2, self.height + 2, background);
+        for y in 0..out.height {
+            for x in 0..out.width {
+                let index = self.neighbours_to_index(x as i64 - 1, y as i64 - 1);
+                out.set(x, y, algo[index]);
+            }
+        }
+        out
+    }
+
+    /// The number of lit pixels, unless the background is lit and there
+    /// are infinitely many.
+    pub fn lit(&self) -> Option<usize> {
+        if self.background {
+            return None;
+        }
+        Some(
+            self.bits
+                .iter()
+                .map(|word| word.count_ones() as usize)
+                .sum(),
+        )
+    }
+}
+
+/// Swaps lit and dark pixels, the background too.
+impl Not for Image {
+    type Output = Image;
+
+    fn not(self) -> Image {
+        let mut out = Image::new(self.width, self.height, !self.background);
+        for y in 0..self.height {
+            for x in 0..self.width {
+                out.set(x, y, !self.get(x as i64, y as i64));
+            }
+        }
+        out
+    }
+}
+
+/// Draws the finite part of the image.
+impl Display for Image {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let lit = self.get(x as i64, y as i64);
+                write!(f, "{}", if lit { '#' } else { '.' })?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+fn parse_input(input: &str) -> (Vec<bool>, Image) {
+    let mut lines = input.lines();
+
+    let algorithm: Vec<bool> = lines.next().unwrap().chars().map(|c| c == '#').collect();
+    assert_eq!(512, algorithm.len());
+
+    assert!(lines.next().unwrap().is_empty());
+
+    let rows: Vec<&str> = lines.collect();
+    let width = rows.first().map_or(0, |row| row.len());
+    let mut image = Image::new(width, rows.len(), false);
+    for (y, line) in rows.iter().enumerate() {
+        for (x, c) in line.chars().enumerate() {
+            image.set(x, y, c == '#');
+        }
+    }
+
+    (algorithm, image)
+}
+
+fn enhance((algo, image): &(Vec<bool>, Image), times: usize) -> usize {
+    let mut image = image.clone();
+    for _ in 0..times {
+        image = image.enhance(algo);
+    }
+    image.lit().expect("infinitely many pixels are lit")
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = (Vec<bool>, Image);
+
+    fn parse(input: &str) -> Self::Input {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        enhance(input, 2).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        enhance(input, 50).into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use test_case::test_case;
+
+    use super::*;
+    use aoc_core::{read, Part};
+
+    #[test]
+    fn index_for_center_in_sample() {
+        let (_, image) = Day::parse(&read("sample1.txt"));
+
+        assert_eq!(34, image.neighbours_to_index(2, 2));
+        assert_eq!(477, (!image).neighbours_to_index(2, 2));
+    }
+
+    #[test]
+    fn index_for_center_in_sample2() {
+        let (_, image) = Day::parse(&read("sample2.txt"));
+
+        assert_eq!(16, image.neighbours_to_index(0, 0));
+    }
+
+    #[test]
+    fn index_for_center_in_sample3() {
+        let (_, image) = Day::parse(&read("sample3.txt"));
+
+        assert_eq!(Some(0), image.lit());
+        let negative = !image.clone();
+        assert_eq!(0, image.neighbours_to_index(0, 0));
+        assert_eq!(511, negative.neighbours_to_index(0, 0));
+
+        assert_eq!(0, image.neighbours_to_index(1, 1));
+        assert_eq!(511, negative.neighbours_to_index(1, 1));
+        assert_eq!(0, image.neighbours_to_index(-100, 100));
+        assert_eq!(511, negative.neighbours_to_index(-100, 100));
+    }
+
+    /// An algorithm with the given ends that otherwise keeps the middle pixel.
+    fn algorithm(first: bool, last: bool) -> Vec<bool> {
+        let mut algo: Vec<bool> = (0..512).map(|index| index & 0b10000 != 0).collect();
+        algo[0] = first;
+        algo[511] = last;
+        algo
+    }
+
+    #[test_case(false, false, false => false; "dark stays dark")]
+    #[test_case(false, false, true => false; "lit goes dark")]
+    #[test_case(true, false, false => true; "dark flips")]
+    #[test_case(true, false, true => false; "lit flips")]
+    #[test_case(false, true, false => false; "dark stays")]
+    #[test_case(false, true, true => true; "lit stays")]
+    #[test_case(true, true, false => true; "dark lights up")]
+    #[test_case(true, true, true => true; "lit stays lit")]
+    fn background(first: bool, last: bool, background: bool) -> bool {
+        let (_, image) = Day::parse(&read("sample1.txt"));
+        let image = if background { !image } else { image };
+
+        let enhanced = image.enhance(&algorithm(first, last));
+        assert_eq!(enhanced.background, enhanced.lit().is_none());
+        enhanced.background
+    }
+
+    #[test]
+    fn copying_keeps_the_image() {
+        let (_, image) = Day::parse(&read("sample1.txt"));
+        let enhanced = image.enhance(&algorithm(false, true));
+
+        assert_eq!(image.lit(), enhanced.lit());
+        for y in 0..5 {
+            for x in 0..5 {
+                assert_eq!(image.get(x, y), enhanced.get(x + 1, y + 1));
+            }
+        }
+    }
+
+    #[test]
+    fn indices_for_input() {
+        let (_, image) = Day::parse(&read("input.txt"));
+        let negative = !image.clone();
+
+        assert_eq!(431, image.neighbours_to_index(98, 98));
+        assert_eq!(31, image.neighbours_to_index(1, 1));
+        assert_eq!(0, image.neighbours_to_index(-1, -1));
+        assert_eq!(511, negative.neighbours_to_index(-1, -1));
+        assert_eq!(4, image.neighbours_to_index(100, -1));
+        assert_eq!(507, negative.neighbours_to_index(100, -1));
+    }
+
+    #[test]
+    fn display() {
+        let (algo, image) = Day::parse(&read("sample1.txt"));
+        assert_eq!("#..#.\n#....\n##..#\n..#..\n..###\n", image.to_string());
+        assert_eq!(
+            ".##.##.\n#..#.#.\n##.#..#\n####..#\n.#..##.\n..##..#\n...#.#.\n",
+            image.enhance(&algo).to_string()
+        );
+    }
+
+    #[test_case("sample1.txt" => 35 ; "sample1")]
+    #[test_case("sample2.txt" => 1 ; "sample2")]
+    #[test_case("sample3.txt" => 0 ; "sample3")]
+    #[test_case("input.txt" => 5306 ; "input")]
+    fn part1(input: &str) -> Answer {
+        Day::solve(Part::One, &read(input))
+    }
+
+    #[test_case("sample1.txt" => 3351 ; "sample1")]
+    #[test_case("input.txt" => 17497 ; "input")]
+    fn part2(input: &str) -> Answer {
+        Day::solve(Part::Two, &read(input))
+    }
+}
@@ -0,0 +1,157 @@
+use aoc_core::{Answer, Solution};
+use scan_fmt::scan_fmt;
+
+struct DeterministicDie {
+    iter: Box<dyn Iterator<Item = i32>>,
+    throws: i32,
+}
+
+impl DeterministicDie {
+    fn new() -> DeterministicDie {
+        let cycle = (1..=100).cycle();
+        DeterministicDie {
+            iter: Box::new(cycle),
+            throws: 0,
+        }
+    }
+
+    fn next3_mod10(&mut self) -> i32 {
+        (self.next().unwrap() % 10 + self.next().unwrap() % 10 + self.next().unwrap() % 10) % 10
+    }
+}
+
+impl Iterator for DeterministicDie {
+    type Item = i32;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.throws += 1;
+        self.iter.next()
+    }
+}
+
+type Players = ((i32, i32), (i32, i32));
+
+fn parse_starting_positions(input: &str) -> Players {
+    let mut lines = input.lines();
+
+    (
+        scan_fmt!(
+            lines.next().unwrap(),
+            "Player {d} starting position: {d}",
+            i32,
+            i32
+        )
+        .unwrap(),
+        scan_fmt!(
+            lines.next().unwrap(),
+            "Player {d} starting position: {d}",
+            i32,
+            i32
+        )
+        .unwrap(),
+    )
+}
+
+fn play(pos1: i32, pos2: i32, score1: i32, score2: i32, repeat: usize) -> (usize, usize) {
+    if score1 >= 21 {
+        return (repeat, 0);
+    } else if score2 >= 21 {
+        return (0, repeat);
+    }
+
+    let throws = [(3, 1), (4, 3), (5, 6), (6, 7), (7, 6), (8, 3), (9, 1)];
+
+    throws
+        .map(|(t, r)| {
+            play(
+                pos2,
+                (pos1 + t) % 10,
+                score2,
+                score1 + ((pos1 + t) % 10) + 1,
+                repeat * r,
+            )
+        })
+        .iter()
+        .fold((0, 0), |(sum1, sum2), (wins2, wins1)| {
+            (sum1 + wins1, sum2 + wins2)
+        })
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Players;
+
+    fn parse(input: &str) -> Self::Input {
+        parse_starting_positions(input)
+    }
+
+    fn part1(&((_, pos1), (_, pos2)): &Self::Input) -> Answer {
+        let mut pos = [pos1 - 1, pos2 - 1];
+        let mut score = [0; 2];
+        let mut die = DeterministicDie::new();
+
+        let mut cur = 0;
+        while score[0] < 1000 && score[1] < 1000 {
+            let throw = die.next3_mod10();
+            pos[cur] = (pos[cur] + throw) % 10;
+            score[cur] += pos[cur] + 1;
+            cur = (cur + 1) % 2;
+        }
+
+        (score[cur] * die.throws).into()
+    }
+
+    fn part2(&((_, pos1), (_, pos2)): &Self::Input) -> Answer {
+        let (wins1, wins2) = play(pos1 - 1, pos2 - 1, 0, 0, 1);
+
+        wins1.max(wins2).into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use test_case::test_case;
+
+    use super::*;
+    use aoc_core::{read, Part};
+
+    #[test]
+    fn die_as_iterator() {
+        let mut die = DeterministicDie::new();
+
+        assert_eq!(0, die.throws);
+
+        assert_eq!(1, die.next().unwrap());
+        assert_eq!(2, die.next().unwrap());
+        assert_eq!(3, die.next().unwrap());
+
+        assert_eq!(3, die.throws);
+    }
+
+    #[test]
+    fn die_as_iterator_next3_mod10() {
+        let mut die = DeterministicDie::new();
+
+        assert_eq!(0, die.throws);
+
+        assert_eq!(6, die.next3_mod10());
+        assert_eq!(5, die.next3_mod10());
+        assert_eq!(4, die.next3_mod10());
+        assert_eq!(3, die.next3_mod10());
+
+        assert_eq!(12, die.throws);
+    }
+
+    #[test_case("sample1.txt" => 739785 ; "sample1")]
+    #[test_case("input.txt" => 903630 ; "input")]
+    fn part1(input: &str) -> Answer {
+        Day::solve(Part::One, &read(input))
+    }
+
+    #[test_case("sample1.txt" => 444356092776315 ; "sample1")]
+    #[test_case("input.txt" => 303121579983974 ; "input")]
+    fn part2(input: &str) -> Answer {
+        Day::solve(Part::Two, &read(input))
+    }
+}
@@ -0,0 +1,109 @@
+use aoc_core::{Answer, Solution};
+use aoc_cuboid::{Cuboid, CuboidSet};
+use scan_fmt::scan_fmt;
+
+type Step = (bool, Cuboid<3>);
+
+fn parse_reboot_steps(input: &str) -> Vec<Step> {
+    input
+        .lines()
+        .map(|line| {
+            scan_fmt!(
+                line,
+                "{} x={d}..{d},y={d}..{d},z={d}..{d}",
+                String,
+                i64,
+                i64,
+                i64,
+                i64,
+                i64,
+                i64
+            )
+            .unwrap()
+        })
+        .map(|(on_off, xmin, xmax, ymin, ymax, zmin, zmax)| {
+            (
+                "on" == on_off,
+                Cuboid::inclusive([xmin, ymin, zmin], [xmax, ymax, zmax]),
+            )
+        })
+        .collect()
+}
+
+/// The cubes the initialization procedure is about.
+const INITIALIZATION: Cuboid<3> = Cuboid {
+    min: [-50; 3],
+    max: [51; 3],
+};
+
+fn run(steps: impl Iterator<Item = Step>) -> u64 {
+    let mut on = CuboidSet::new();
+    for (turn_on, cubes) in steps {
+        if turn_on {
+            on.union(cubes);
+        } else {
+            on.subtract(cubes);
+        }
+    }
+    on.volume()
+}
+
+fn initialization(steps: &[Step]) -> u64 {
+    run(steps
+        .iter()
+        .filter_map(|(on, cubes)| Some((*on, cubes.intersection(&INITIALIZATION)?))))
+}
+
+fn reboot(steps: &[Step]) -> u64 {
+    run(steps.iter().copied())
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Step>;
+
+    fn parse(input: &str) -> Self::Input {
+        parse_reboot_steps(input)
+    }
+
+    fn part1(steps: &Self::Input) -> Answer {
+        initialization(steps).into()
+    }
+
+    fn part2(steps: &Self::Input) -> Answer {
+        reboot(steps).into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use test_case::test_case;
+
+    use super::*;
+    use aoc_core::{read, Part};
+
+    #[test]
+    fn parsing() {
+        let steps = Day::parse(&read("sample1.txt"));
+
+        assert_eq!(4, steps.len());
+        assert_eq!((true, Cuboid::new([10; 3], [13; 3])), steps[0]);
+        assert_eq!((false, Cuboid::new([9; 3], [12; 3])), steps[2]);
+    }
+
+    #[test_case("sample1.txt" => 39 ; "sample1")]
+    #[test_case("sample2.txt" => 590784 ; "sample2")]
+    #[test_case("sample3.txt" => 474140 ; "sample3")]
+    #[test_case("input.txt" => 648681 ; "input")]
+    fn part1(input: &str) -> Answer {
+        Day::solve(Part::One, &read(input))
+    }
+
+    #[test_case("sample1.txt" => 39 ; "sample1")]
+    #[test_case("sample3.txt" => 2758514936282235 ; "sample3")]
+    #[test_case("input.txt" => 1302784472088899 ; "input")]
+    fn part2(input: &str) -> Answer {
+        Day::solve(Part::Two, &read(input))
+    }
+}
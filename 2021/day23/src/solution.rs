@@ -0,0 +1,906 @@
+use aoc_core::{Answer, Solution};
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+
+/// Who is where: the hallway from left to right, then each room from the
+/// top down. Empty cells are `.`.
+pub type State = Box<[u8]>;
+
+const EMPTY: u8 = b'.';
+
+/// The shape of a burrow: a hallway with rooms of the same depth below
+/// it, one for each kind of amphipod from `A` on the left.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Burrow {
+    hallway: usize,
+    /// The hallway cell above each room.
+    doors: Vec<usize>,
+    depth: usize,
+}
+
+/// Reads the burrow and where the amphipods are from the diagram.
+pub fn parse_situation(s: &str) -> (Burrow, State) {
+    let mut lines = s.lines();
+
+    let wall = lines.next().unwrap().trim();
+    assert!(wall.bytes().all(|c| c == b'#'), "no wall on top: {wall}");
+    let hallway = lines.next().unwrap().trim_end().as_bytes();
+    let hallway = &hallway[1..hallway.len() - 1];
+
+    let is_cell = |c: &u8| *c == EMPTY || c.is_ascii_uppercase();
+    let rows: Vec<&[u8]> = lines
+        .map(|line| line.as_bytes())
+        .take_while(|line| line.iter().any(is_cell))
+        .collect();
+    let columns: Vec<usize> = (0..rows[0].len())
+        .filter(|&x| is_cell(&rows[0][x]))
+        .collect();
+
+    let burrow = Burrow {
+        hallway: hallway.len(),
+        doors: columns.iter().map(|x| x - 1).collect(),
+        depth: rows.len(),
+    };
+    let mut state = hallway.to_vec();
+    for x in &columns {
+        state.extend(rows.iter().map(|row| row[*x]));
+    }
+    (burrow, state.into())
+}
+
+fn energy(amphipod: u8) -> usize {
+    10usize.pow((amphipod - b'A') as u32)
+}
+
+impl Burrow {
+    fn cell(&self, room: usize, level: usize) -> usize {
+        self.hallway + room * self.depth + level
+    }
+
+    fn room<'a>(&self, state: &'a State, room: usize) -> impl Iterator<Item = u8> + 'a {
+        let start = self.cell(room, 0);
+        state[start..start + self.depth].iter().copied()
+    }
+
+    fn home(amphipod: u8) -> usize {
+        (amphipod - b'A') as usize
+    }
+
+    pub fn is_finished(&self, state: &State) -> bool {
+        (0..self.doors.len()).all(|room| {
+            self.room(state, room)
+                .all(|amphipod| amphipod != EMPTY && Burrow::home(amphipod) == room)
+        })
+    }
+
+    /// Whether only amphipods that live in the room are in it.
+    fn only_locals(&self, state: &State, room: usize) -> bool {
+        self.room(state, room)
+            .all(|amphipod| amphipod == EMPTY || Burrow::home(amphipod) == room)
+    }
+
+    /// The deepest free level of the room, if only locals are in it.
+    fn free_level(&self, state: &State, room: usize) -> Option<usize> {
+        if !self.only_locals(state, room) {
+            return None;
+        }
+        let cells: Vec<u8> = self.room(state, room).collect();
+        cells.iter().rposition(|&cell| cell == EMPTY)
+    }
+
+    /// Whether the hallway is empty from `from` to `to`, leaving out `from`.
+    fn clear(&self, state: &State, from: usize, to: usize) -> bool {
+        let cells = if from < to {
+            from + 1..to + 1
+        } else {
+            to..from
+        };
+        state[cells].iter().all(|&cell| cell == EMPTY)
+    }
+
+    /// Every state one amphipod move away, with the energy it takes. Out of
+    /// a room amphipods stop in the hallway or go straight home; from the
+    /// hallway they only go home.
+    pub fn moves(&self, state: &State) -> Vec<(State, usize)> {
+        let mut moves = vec![];
+        let mut push = |from: usize, to: usize, steps: usize| {
+            let mut next = state.clone();
+            next[to] = state[from];
+            next[from] = EMPTY;
+            moves.push((next, steps * energy(state[from])));
+        };
+
+        for h in 0..self.hallway {
+            let amphipod = state[h];
+            if amphipod == EMPTY {
+                continue;
+            }
+            let home = Burrow::home(amphipod);
+            let door = self.doors[home];
+            if let Some(level) = self.free_level(state, home) {
+                if self.clear(state, h, door) {
+                    push(h, self.cell(home, level), h.abs_diff(door) + level + 1);
+                }
+            }
+        }
+
+        for (room, &door) in self.doors.iter().enumerate() {
+            let Some(level) = self.room(state, room).position(|cell| cell != EMPTY) else {
+                continue;
+            };
+            let amphipod = state[self.cell(room, level)];
+            let home = Burrow::home(amphipod);
+            if home == room && self.only_locals(state, room) {
+                continue;
+            }
+            let from = self.cell(room, level);
+            if home != room {
+                let home_door = self.doors[home];
+                if let Some(home_level) = self.free_level(state, home) {
+                    if self.clear(state, door, home_door) {
+                        let steps = level + 1 + door.abs_diff(home_door) + home_level + 1;
+                        push(from, self.cell(home, home_level), steps);
+                    }
+                }
+            }
+            for h in (0..self.hallway).filter(|h| !self.doors.contains(h)) {
+                if state[h] == EMPTY && self.clear(state, door, h) {
+                    push(from, h, level + 1 + door.abs_diff(h));
+                }
+            }
+        }
+
+        moves
+    }
+
+    /// A lower bound for the energy left: every amphipod not yet home walks
+    /// straight to the top of its room, and one that has to make way in its
+    /// own room steps out and back in.
+    pub fn cost_estimate(&self, state: &State) -> usize {
+        let mut estimate = 0;
+        for (h, &amphipod) in state[..self.hallway].iter().enumerate() {
+            if amphipod != EMPTY {
+                let door = self.doors[Burrow::home(amphipod)];
+                estimate += (h.abs_diff(door) + 1) * energy(amphipod);
+            }
+        }
+        for (room, &door) in self.doors.iter().enumerate() {
+            for level in 0..self.depth {
+                let amphipod = state[self.cell(room, level)];
+                if amphipod == EMPTY {
+                    continue;
+                }
+                let home = Burrow::home(amphipod);
+                let below = self.room(state, room).skip(level);
+                let steps = if home != room {
+                    level + 1 + door.abs_diff(self.doors[home]) + 1
+                } else if below.into_iter().any(|other| other != amphipod) {
+                    level + 1 + 2 + 1
+                } else {
+                    0
+                };
+                estimate += steps * energy(amphipod);
+            }
+        }
+        estimate
+    }
+
+    /// The least energy that gets every amphipod home, along with the states
+    /// on the way, found with A* using [`Burrow::cost_estimate`].
+    pub fn solve(&self, start: &State) -> Option<(usize, Vec<State>)> {
+        let mut best: HashMap<State, (usize, Option<State>)> = HashMap::new();
+        best.insert(start.clone(), (0, None));
+        let mut queue = BinaryHeap::from([Reverse((self.cost_estimate(start), 0, start.clone()))]);
+
+        while let Some(Reverse((_, cost, state))) = queue.pop() {
+            if cost > best[&state].0 {
+                continue;
+            }
+            if self.is_finished(&state) {
+                let mut path = vec![state];
+                while let Some(previous) = &best[path.last().unwrap()].1 {
+                    path.push(previous.clone());
+                }
+                path.reverse();
+                return Some((cost, path));
+            }
+            for (next, energy) in self.moves(&state) {
+                let next_cost = cost + energy;
+                if best.get(&next).is_none_or(|&(known, _)| next_cost < known) {
+                    best.insert(next.clone(), (next_cost, Some(state.clone())));
+                    let estimate = next_cost + self.cost_estimate(&next);
+                    queue.push(Reverse((estimate, next_cost, next)));
+                }
+            }
+        }
+        None
+    }
+
+    /// Draws the state the way the puzzle does.
+    pub fn print(&self, state: &State) -> String {
+        let width = self.hallway + 2;
+        let first = self.doors[0] + 1;
+        let last = self.doors[self.doors.len() - 1] + 1;
+
+        let mut s = "#".repeat(width) + "\n";
+        s += "#";
+        s.extend(state[..self.hallway].iter().map(|&c| c as char));
+        s += "#\n";
+        for level in 0..self.depth {
+            let (margin, end) = if level == 0 {
+                ('#', width)
+            } else {
+                (' ', last + 2)
+            };
+            for x in 0..end {
+                s.push(match self.doors.iter().position(|&door| door + 1 == x) {
+                    Some(room) => state[self.cell(room, level)] as char,
+                    None if x + 1 < first || x > last + 1 => margin,
+                    None => '#',
+                });
+            }
+            s += "\n";
+        }
+        s += &" ".repeat(first - 1);
+        s += &"#".repeat(last - first + 3);
+        s += "\n";
+
+        s
+    }
+
+    /// Every way to get the amphipods home.
+    #[cfg(test)]
+    fn dfs(&self, state: &State) -> Vec<Vec<State>> {
+        if self.is_finished(state) {
+            vec![vec![state.clone()]]
+        } else {
+            let mut solutions = vec![];
+            for m in self.moves(state) {
+                for mut solution in self.dfs(&m.0) {
+                    solution.insert(0, state.clone());
+                    solutions.push(solution);
+                }
+            }
+            solutions
+        }
+    }
+}
+
+/// The full burrow of part two, with the two folded-away rows inserted
+/// after the first row of the rooms. Burrows four deep are taken to be
+/// unfolded already; the rows only fit the puzzle's four rooms.
+fn unfold(input: &str) -> String {
+    let (burrow, _) = parse_situation(input);
+    assert_eq!(
+        vec![2, 4, 6, 8],
+        burrow.doors,
+        "the folded rows don't fit these rooms"
+    );
+    match burrow.depth {
+        2 => {
+            let mut lines: Vec<&str> = input.lines().collect();
+            lines.splice(3..3, ["  #D#C#B#A#", "  #D#B#A#C#"]);
+            lines.join("\n") + "\n"
+        }
+        4 => input.to_string(),
+        depth => panic!("can't unfold a burrow {depth} deep"),
+    }
+}
+
+fn solve(input: &str) -> Answer {
+    let (burrow, state) = parse_situation(input);
+    burrow
+        .solve(&state)
+        .expect("the amphipods cannot get home")
+        .0
+        .into()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = String;
+
+    fn parse(input: &str) -> Self::Input {
+        input.to_string()
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        solve(input)
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        solve(&unfold(input))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use indoc::indoc;
+    use test_case::test_case;
+
+    use super::*;
+    use aoc_core::{read, Part};
+
+    #[test]
+    fn print_sample1() {
+        let (burrow, state) = parse_situation(&read("sample1_2.txt"));
+
+        assert_eq!(read("sample1_2.txt"), burrow.print(&state));
+    }
+
+    #[test]
+    fn any_shape_of_burrow() {
+        let diagram = indoc! {"
+            ###########
+            #.........#
+            ###B#A#C###
+              #######
+        "};
+        let (burrow, state) = parse_situation(diagram);
+
+        assert_eq!(diagram, burrow.print(&state));
+        let (energy, states) = burrow.solve(&state).unwrap();
+        assert_eq!(46, energy);
+        let spent: usize = states
+            .windows(2)
+            .map(|step| {
+                let moves = burrow.moves(&step[0]);
+                moves.iter().find(|m| m.0 == step[1]).unwrap().1
+            })
+            .sum();
+        assert_eq!(energy, spent);
+        assert!(burrow.is_finished(states.last().unwrap()));
+    }
+
+    #[test]
+    fn only_one_possible_move_in_for_a() {
+        let (burrow, state) = parse_situation(indoc! {"
+                #############
+                #A..........#
+                ###.#B#C#D###
+                  #A#B#C#D#
+                  #########
+        "});
+
+        let moves = burrow.moves(&state);
+
+        assert_eq!(1, moves.len());
+
+        assert!(burrow.is_finished(&moves.first().unwrap().0));
+        assert_eq!(3, moves.first().unwrap().1);
+    }
+
+    #[test]
+    fn only_one_possible_move_in_for_d() {
+        let (burrow, state) = parse_situation(indoc! {"
+                #############
+                #.......D...#
+                ###A#B#C#.###
+                  #A#B#C#D#
+                  #########
+        "});
+
+        let moves = burrow.moves(&state);
+
+        assert_eq!(1, moves.len());
+
+        assert!(burrow.is_finished(&moves.first().unwrap().0));
+        assert_eq!(2000, moves.first().unwrap().1);
+    }
+
+    #[test]
+    fn two_possible_moves_out_for_d() {
+        let (burrow, state) = parse_situation(indoc! {"
+                #############
+                #...A.B.C.D.#
+                ###.#.#.#.###
+                  #D#C#B#A#
+                  #########
+        "});
+
+        let moves = burrow.moves(&state);
+
+        assert_eq!(2, moves.len());
+
+        assert!(!burrow.is_finished(&moves.first().unwrap().0));
+        assert!(!burrow.is_finished(&moves.last().unwrap().0));
+        assert_ne!(moves.first().unwrap(), moves.last().unwrap());
+        assert_eq!(7000, moves.first().unwrap().1 + moves.last().unwrap().1);
+    }
+
+    #[test]
+    fn two_possible_moves_out_for_c_and_d() {
+        let (burrow, state) = parse_situation(indoc! {"
+                #############
+                #.A...B.C.D.#
+                ###.#.#.#.###
+                  #D#C#B#A#
+                  #########
+        "});
+
+        println!("{}", burrow.print(&state));
+
+        let moves = burrow.moves(&state);
+
+        for m in moves.iter() {
+            println!("{}", burrow.print(&m.0));
+        }
+
+        assert_eq!(2, moves.len());
+
+        assert!(!burrow.is_finished(&moves.first().unwrap().0));
+        assert!(!burrow.is_finished(&moves.last().unwrap().0));
+        assert_ne!(moves.first().unwrap(), moves.last().unwrap());
+        assert_eq!(3300, moves.first().unwrap().1 + moves.last().unwrap().1);
+    }
+
+    #[test]
+    fn find_solution_for_easy_case() {
+        let (burrow, state) = parse_situation(indoc! {"
+                #############
+                #A..........#
+                ###.#B#C#D###
+                  #A#B#C#D#
+                  #########
+        "});
+
+        let solutions = burrow.dfs(&state);
+
+        assert_eq!(1, solutions.len());
+        assert_eq!(2, solutions.first().unwrap().len());
+    }
+
+    #[test]
+    fn find_solution_for_slightly_harder_case() {
+        let (burrow, state) = parse_situation(indoc! {"
+                #############
+                #AA.........#
+                ###.#B#C#D###
+                  #.#B#C#D#
+                  #########
+        "});
+
+        let solutions = burrow.dfs(&state);
+
+        assert_eq!(1, solutions.len());
+
+        for m in solutions.first().unwrap() {
+            println!("{}", burrow.print(m));
+        }
+
+        assert_eq!(3, solutions.first().unwrap().len());
+    }
+
+    #[test]
+    fn find_solution_for_harder_case_based_on_input() {
+        let (burrow, state) = parse_situation(indoc! {"
+                #############
+                #.A.........#
+                ###.#A#C#D###
+                  #B#C#B#D#
+                  #########
+          "});
+
+        let solution = burrow.solve(&state).unwrap();
+
+        for m in &solution.1 {
+            println!("{}", burrow.print(m));
+        }
+
+        assert_eq!(1143, solution.0);
+        assert_eq!(11, solution.1.len());
+
+        assert!(solution.0 > burrow.cost_estimate(&state));
+    }
+
+    #[test]
+    fn why_choose_an_expensive_extra_step_for_b() {
+        let (burrow, state) = parse_situation(indoc! {"
+                #############
+                #.A.....A...#
+                ###.#.#C#D###
+                  #B#B#C#D#
+                  #########
+            "});
+
+        println!("{}", burrow.print(&state));
+
+        let solution = burrow.solve(&state).unwrap();
+
+        for m in &solution.1 {
+            println!("{}", burrow.print(m));
+        }
+
+        assert_eq!(4, solution.1.len());
+        assert_eq!(59, solution.0); // not sure if 59 is the correct answer, but 79 isn't
+    }
+
+    #[test]
+    fn why_choose_an_expensive_extra_step_for_b_2() {
+        let (burrow, state) = parse_situation(indoc! {"
+                #############
+                #.A.........#
+                ###B#.#C#D###
+                  #A#B#C#D#
+                  #########
+            "});
+
+        let solution = burrow.solve(&state).unwrap();
+
+        for m in &solution.1 {
+            println!("{}", burrow.print(m));
+        }
+
+        assert_eq!(4, solution.1.len());
+        assert_eq!(42, solution.0);
+    }
+
+    #[test]
+    fn why_doesnt_a_go_home() {
+        let (burrow, state) = parse_situation(indoc! {"
+                #############
+                #.A.....A...#
+                ###.#B#C#D###
+                  #.#B#C#D#
+                  #########
+              "});
+
+        println!("{}", burrow.print(&state));
+
+        let solution = burrow.solve(&state).unwrap();
+
+        for m in &solution.1 {
+            println!("{}", burrow.print(m));
+        }
+
+        assert_eq!(3, solution.1.len());
+        assert_eq!(9, solution.0);
+    }
+
+    #[test]
+    fn cost_for_deep_rooms() {
+        let (burrow, state) = parse_situation(indoc! {"
+                #############
+                #DD.D.D.....#
+                ###A#B#C#.###
+                  #A#B#C#.#
+                  #A#B#C#.#
+                  #A#B#C#.#
+                  #########
+              "});
+
+        println!("{}", burrow.print(&state));
+
+        let solution = burrow.solve(&state).unwrap();
+
+        for m in &solution.1 {
+            println!("{}", burrow.print(m));
+        }
+
+        assert_eq!(5, solution.1.len());
+        assert_eq!(33000, solution.0);
+    }
+
+    #[test]
+    fn cost_estimate_for_four_ds_outside() {
+        let (burrow, state) = parse_situation(indoc! {"
+                #############
+                #DD.D.D.....#
+                ###A#B#C#.###
+                  #A#B#C#.#
+                  #A#B#C#.#
+                  #A#B#C#.#
+                  #########
+              "});
+
+        let cost_estimate = burrow.cost_estimate(&state);
+
+        assert_eq!(27000, cost_estimate);
+    }
+
+    #[test]
+    fn verify_part2_sample1_cost() {
+        let moves = [
+            parse_situation(indoc! {"
+                #############
+                #...........#
+                ###B#C#B#D###
+                  #D#C#B#A#
+                  #D#B#A#C#
+                  #A#D#C#A#
+                  #########
+                "}),
+            parse_situation(indoc! {"
+                #############
+                #..........D#
+                ###B#C#B#.###
+                  #D#C#B#A#
+                  #D#B#A#C#
+                  #A#D#C#A#
+                  #########
+                "}),
+            parse_situation(indoc! {"
+                #############
+                #A.........D#
+                ###B#C#B#.###
+                  #D#C#B#.#
+                  #D#B#A#C#
+                  #A#D#C#A#
+                  #########
+                "}),
+            parse_situation(indoc! {"
+                #############
+                #A........BD#
+                ###B#C#.#.###
+                  #D#C#B#.#
+                  #D#B#A#C#
+                  #A#D#C#A#
+                  #########
+                "}),
+            parse_situation(indoc! {"
+                #############
+                #A......B.BD#
+                ###B#C#.#.###
+                  #D#C#.#.#
+                  #D#B#A#C#
+                  #A#D#C#A#
+                  #########
+                "}),
+            parse_situation(indoc! {"
+                #############
+                #AA.....B.BD#
+                ###B#C#.#.###
+                  #D#C#.#.#
+                  #D#B#.#C#
+                  #A#D#C#A#
+                  #########
+                "}),
+            parse_situation(indoc! {"
+                #############
+                #AA...C.B.BD#
+                ###B#.#.#.###
+                  #D#C#.#.#
+                  #D#B#.#C#
+                  #A#D#C#A#
+                  #########
+                "}),
+            parse_situation(indoc! {"
+                #############
+                #AA.....B.BD#
+                ###B#.#.#.###
+                  #D#C#.#.#
+                  #D#B#C#C#
+                  #A#D#C#A#
+                  #########
+                "}),
+            parse_situation(indoc! {"
+                #############
+                #AA...C.B.BD#
+                ###B#.#.#.###
+                  #D#.#.#.#
+                  #D#B#C#C#
+                  #A#D#C#A#
+                  #########
+                "}),
+            parse_situation(indoc! {"
+                #############
+                #AA.....B.BD#
+                ###B#.#.#.###
+                  #D#.#C#.#
+                  #D#B#C#C#
+                  #A#D#C#A#
+                  #########
+                "}),
+            parse_situation(indoc! {"
+                #############
+                #AA...B.B.BD#
+                ###B#.#.#.###
+                  #D#.#C#.#
+                  #D#.#C#C#
+                  #A#D#C#A#
+                  #########
+                "}),
+            parse_situation(indoc! {"
+                #############
+                #AA.D.B.B.BD#
+                ###B#.#.#.###
+                  #D#.#C#.#
+                  #D#.#C#C#
+                  #A#.#C#A#
+                  #########
+                "}),
+            parse_situation(indoc! {"
+                #############
+                #AA.D...B.BD#
+                ###B#.#.#.###
+                  #D#.#C#.#
+                  #D#.#C#C#
+                  #A#B#C#A#
+                  #########
+                "}),
+            parse_situation(indoc! {"
+                #############
+                #AA.D.....BD#
+                ###B#.#.#.###
+                  #D#.#C#.#
+                  #D#B#C#C#
+                  #A#B#C#A#
+                  #########
+                "}),
+            parse_situation(indoc! {"
+                #############
+                #AA.D......D#
+                ###B#.#.#.###
+                  #D#B#C#.#
+                  #D#B#C#C#
+                  #A#B#C#A#
+                  #########
+                "}),
+            parse_situation(indoc! {"
+                #############
+                #AA.D...C..D#
+                ###B#.#.#.###
+                  #D#B#C#.#
+                  #D#B#C#.#
+                  #A#B#C#A#
+                  #########
+                "}),
+            parse_situation(indoc! {"
+                #############
+                #AA.D......D#
+                ###B#.#C#.###
+                  #D#B#C#.#
+                  #D#B#C#.#
+                  #A#B#C#A#
+                  #########
+                "}),
+            parse_situation(indoc! {"
+                #############
+                #AA.D.....AD#
+                ###B#.#C#.###
+                  #D#B#C#.#
+                  #D#B#C#.#
+                  #A#B#C#.#
+                  #########
+                "}),
+            parse_situation(indoc! {"
+                #############
+                #AA.......AD#
+                ###B#.#C#.###
+                  #D#B#C#.#
+                  #D#B#C#.#
+                  #A#B#C#D#
+                  #########
+                "}),
+            parse_situation(indoc! {"
+                #############
+                #AA.B.....AD#
+                ###.#.#C#.###
+                  #D#B#C#.#
+                  #D#B#C#.#
+                  #A#B#C#D#
+                  #########
+                "}),
+            parse_situation(indoc! {"
+                #############
+                #AA.......AD#
+                ###.#B#C#.###
+                  #D#B#C#.#
+                  #D#B#C#.#
+                  #A#B#C#D#
+                  #########
+                "}),
+            parse_situation(indoc! {"
+                #############
+                #AA...D...AD#
+                ###.#B#C#.###
+                  #.#B#C#.#
+                  #D#B#C#.#
+                  #A#B#C#D#
+                  #########
+                "}),
+            parse_situation(indoc! {"
+                #############
+                #AA.......AD#
+                ###.#B#C#.###
+                  #.#B#C#.#
+                  #D#B#C#D#
+                  #A#B#C#D#
+                  #########
+                "}),
+            parse_situation(indoc! {"
+                #############
+                #AA.D.....AD#
+                ###.#B#C#.###
+                  #.#B#C#.#
+                  #.#B#C#D#
+                  #A#B#C#D#
+                  #########
+                "}),
+            parse_situation(indoc! {"
+                #############
+                #A..D.....AD#
+                ###.#B#C#.###
+                  #.#B#C#.#
+                  #A#B#C#D#
+                  #A#B#C#D#
+                  #########
+                "}),
+            parse_situation(indoc! {"
+                #############
+                #...D.....AD#
+                ###.#B#C#.###
+                  #A#B#C#.#
+                  #A#B#C#D#
+                  #A#B#C#D#
+                  #########
+                "}),
+            parse_situation(indoc! {"
+                #############
+                #.........AD#
+                ###.#B#C#.###
+                  #A#B#C#D#
+                  #A#B#C#D#
+                  #A#B#C#D#
+                  #########
+                "}),
+            parse_situation(indoc! {"
+                #############
+                #..........D#
+                ###A#B#C#.###
+                  #A#B#C#D#
+                  #A#B#C#D#
+                  #A#B#C#D#
+                  #########
+                "}),
+            parse_situation(indoc! {"
+                #############
+                #...........#
+                ###A#B#C#D###
+                  #A#B#C#D#
+                  #A#B#C#D#
+                  #A#B#C#D#
+                  #########
+                "}),
+        ];
+
+        let burrow = &moves[0].0;
+        let mut cost = 0;
+        for i in 0..moves.len() {
+            for m in burrow.moves(&moves[i].1) {
+                if m.0 == moves[i + 1].1 {
+                    cost += m.1;
+                }
+            }
+        }
+
+        assert_eq!(44169, cost);
+    }
+
+    #[test]
+    fn unfolding() {
+        assert_eq!(read("sample1_2.txt"), unfold(&read("sample1.txt")));
+        assert_eq!(read("sample1_2.txt"), unfold(&read("sample1_2.txt")));
+    }
+
+    #[test_case("#######\n#.....#\n###A#B#\n  #A#B#\n  ###\n" => panics "don't fit"; "two rooms")]
+    #[test_case("#############\n#...........#\n###B#C#B#D###\n  #########\n" => panics "1 deep"; "one deep")]
+    fn unfolding_other_shapes(diagram: &str) {
+        unfold(diagram);
+    }
+
+    #[test_case("sample1.txt" => 12521 ; "sample1")]
+    #[test_case("input.txt" => 14148 ; "input")]
+    fn part1(input: &str) -> Answer {
+        Day::solve(Part::One, &read(input))
+    }
+
+    #[test_case("sample1_2.txt" => 44169 ; "sample1")]
+    #[test_case("input_2.txt" => 43814 ; "input")]
+    #[test_case("sample1.txt" => 44169 ; "sample1 unfolded")]
+    #[test_case("input.txt" => 43814 ; "input unfolded")]
+    fn part2(input: &str) -> Answer {
+        Day::solve(Part::Two, &read(input))
+    }
+}
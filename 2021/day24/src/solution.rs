@@ -0,0 +1,628 @@
+use aoc_core::{Answer, Solution};
+use std::fmt;
+use std::str::FromStr;
+use strum_macros::EnumString;
+use Instruction::*;
+
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Clone, EnumString)]
+enum Instruction {
+    INP,
+    ADD,
+    MUL,
+    DIV,
+    MOD,
+    EQL,
+}
+
+struct Op {
+    instruction: Instruction,
+    a: usize,
+    b: usize,
+    b_literal: Option<i64>,
+}
+
+impl Op {
+    fn parse(s: &str) -> Op {
+        let p: Vec<&str> = s.split(" ").collect();
+        match p[0] {
+            "inp" => Op::inp(p[1]),
+            _ => Op::binary(
+                Instruction::from_str(p[0].to_uppercase().as_str()).unwrap(),
+                p[1],
+                p[2],
+            ),
+        }
+    }
+
+    fn variable_or_literal(variable: &str) -> (usize, Option<i64>) {
+        match variable {
+            "w" => (0, None),
+            "x" => (1, None),
+            "y" => (2, None),
+            "z" => (3, None),
+            _ => (0, variable.parse().ok()),
+        }
+    }
+
+    fn inp(a: &str) -> Op {
+        Op {
+            instruction: INP,
+            a: Op::variable_or_literal(a).0,
+            b: 0,
+            b_literal: None,
+        }
+    }
+
+    fn binary(instruction: Instruction, a: &str, b: &str) -> Op {
+        let b = Op::variable_or_literal(b);
+        Op {
+            instruction,
+            a: Op::variable_or_literal(a).0,
+            b: b.0,
+            b_literal: b.1,
+        }
+    }
+}
+
+pub struct Program {
+    ops: Vec<Op>,
+    pc: usize,
+    variables: [i64; 4],
+}
+
+impl Program {
+    pub fn parse_program(s: &str) -> Program {
+        Program {
+            ops: s.lines().map(Op::parse).collect(),
+            pc: 0,
+            variables: [0; 4],
+        }
+    }
+
+    pub fn run(&mut self, input: Vec<i64>) -> (i64, i64, i64, i64) {
+        self.reset();
+        let mut i = input.iter();
+        while !self.end() {
+            let input = *i.next().unwrap();
+            self.run_one_input(input);
+        }
+        (
+            self.variables[0],
+            self.variables[1],
+            self.variables[2],
+            self.variables[3],
+        )
+    }
+
+    fn run_one_input(&mut self, input: i64) {
+        let mut input_consumed = false;
+        while let Some(op) = self.step() {
+            match (&op.instruction, input_consumed) {
+                (INP, false) => input_consumed = true,
+                (INP, true) => {
+                    self.unstep();
+                    break;
+                }
+                _ => (),
+            }
+            let instruction = op.instruction.clone();
+            let a = op.a;
+            let b_value = op.b_literal.unwrap_or(self.variables[op.b]);
+            match instruction {
+                INP => self.variables[a] = input,
+                ADD => self.variables[a] += b_value,
+                MUL => self.variables[a] *= b_value,
+                DIV => {
+                    assert_ne!(0, b_value);
+                    self.variables[a] /= b_value
+                }
+                MOD => {
+                    assert!(self.variables[a] >= 0);
+                    assert!(b_value > 0);
+                    self.variables[a] %= b_value
+                }
+                EQL => self.variables[a] = if self.variables[a] == b_value { 1 } else { 0 },
+            }
+        }
+    }
+
+    fn step(&mut self) -> Option<&Op> {
+        let op = self.ops.get(self.pc);
+        self.pc += 1;
+        op
+    }
+
+    fn unstep(&mut self) {
+        self.pc -= 1;
+    }
+
+    fn end(&self) -> bool {
+        self.pc >= self.ops.len()
+    }
+
+    fn reset(&mut self) -> (usize, [i64; 4]) {
+        let state = (self.pc, self.variables);
+        self.pc = 0;
+        self.variables = [0; 4];
+        state
+    }
+}
+
+const BASE: i64 = 26;
+const Z: usize = 3;
+
+/// An input digit plus `offset`, or just `offset` when there is no input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Term {
+    input: Option<usize>,
+    offset: i64,
+}
+
+impl Term {
+    fn constant(offset: i64) -> Term {
+        Term {
+            input: None,
+            offset,
+        }
+    }
+
+    fn range(&self) -> (i64, i64) {
+        match self.input {
+            Some(_) => (self.offset + 1, self.offset + 9),
+            None => (self.offset, self.offset),
+        }
+    }
+
+    fn within(&self, low: i64, high: i64) -> bool {
+        let (min, max) = self.range();
+        min >= low && max < high
+    }
+
+    fn value(&self, digits: &[i64]) -> Option<i64> {
+        match self.input {
+            Some(i) => digits.get(i).map(|digit| digit + self.offset),
+            None => Some(self.offset),
+        }
+    }
+}
+
+impl fmt::Display for Term {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.input, self.offset) {
+            (None, offset) => write!(f, "{offset}"),
+            (Some(i), 0) => write!(f, "d{i}"),
+            (Some(i), offset) if offset < 0 => write!(f, "d{i} - {}", -offset),
+            (Some(i), offset) => write!(f, "d{i} + {offset}"),
+        }
+    }
+}
+
+/// A register while the digits are unknown. `Stack` is `z` read as base 26
+/// digits, lowest last, each of them a `Term` between 0 and 25. It always
+/// has at least two entries; shorter ones are kept as a plain `Term`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Value {
+    Term(Term),
+    Stack(Vec<Term>),
+}
+
+impl Value {
+    fn constant(value: i64) -> Value {
+        Value::Term(Term::constant(value))
+    }
+
+    fn as_constant(&self) -> Option<i64> {
+        match self {
+            Value::Term(Term {
+                input: None,
+                offset,
+            }) => Some(*offset),
+            _ => None,
+        }
+    }
+
+    fn into_stack(self) -> Vec<Term> {
+        match self {
+            Value::Term(term) if term == Term::constant(0) => vec![],
+            Value::Term(term) if term.within(0, BASE) => vec![term],
+            Value::Stack(stack) => stack,
+            Value::Term(term) => panic!("{term} doesn't fit on the stack"),
+        }
+    }
+
+    fn from_stack(mut stack: Vec<Term>) -> Value {
+        match stack.len() {
+            0 => Value::constant(0),
+            1 => Value::Term(stack.pop().unwrap()),
+            _ => Value::Stack(stack),
+        }
+    }
+}
+
+/// A condition on the digits that decided an `eql`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Constraint {
+    left: Term,
+    right: Term,
+    equal: bool,
+}
+
+impl Constraint {
+    /// Whether the constraint can still hold once the rest of the digits are
+    /// chosen, given the ones in `digits`.
+    fn possible(&self, digits: &[i64]) -> bool {
+        let range = |term: &Term| match term.value(digits) {
+            Some(value) => (value, value),
+            None => term.range(),
+        };
+        let (left_min, left_max) = range(&self.left);
+        let (right_min, right_max) = range(&self.right);
+        if self.equal {
+            left_min <= right_max && right_min <= left_max
+        } else {
+            !(left_min == left_max && right_min == right_max && left_min == right_min)
+        }
+    }
+}
+
+impl fmt::Display for Constraint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let relation = if self.equal { "==" } else { "!=" };
+        write!(f, "{} {relation} {}", self.left, self.right)
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Symbolic {
+    registers: [Value; 4],
+    inputs: usize,
+    constraints: Vec<Constraint>,
+}
+
+fn unsupported(op: &Op, a: &Value, b: &Value) -> ! {
+    panic!("can't follow {:?} on {a:?} and {b:?}", op.instruction)
+}
+
+impl Program {
+    /// Runs the program on unknown digits, splitting at every `eql` that
+    /// depends on them, and returns the constraints of each way through that
+    /// ends with `z` at zero, along with the number of digits read.
+    fn analyse(&self) -> (Vec<Vec<Constraint>>, usize) {
+        let start = Symbolic {
+            registers: [
+                Value::constant(0),
+                Value::constant(0),
+                Value::constant(0),
+                Value::constant(0),
+            ],
+            inputs: 0,
+            constraints: vec![],
+        };
+        let mut valid = vec![];
+        let digits = self
+            .ops
+            .iter()
+            .filter(|op| matches!(op.instruction, INP))
+            .count();
+        Program::explore(&self.ops, start, &mut valid);
+        (valid, digits)
+    }
+
+    fn explore(ops: &[Op], mut state: Symbolic, valid: &mut Vec<Vec<Constraint>>) {
+        for (pc, op) in ops.iter().enumerate() {
+            let a = state.registers[op.a].clone();
+            let b = match op.b_literal {
+                Some(literal) => Value::constant(literal),
+                None => state.registers[op.b].clone(),
+            };
+            let result = match op.instruction {
+                INP => {
+                    state.inputs += 1;
+                    Value::Term(Term {
+                        input: Some(state.inputs - 1),
+                        offset: 0,
+                    })
+                }
+                ADD => match (&a, &b) {
+                    (Value::Term(x), Value::Term(y)) if x.input.is_none() || y.input.is_none() => {
+                        Value::Term(Term {
+                            input: x.input.or(y.input),
+                            offset: x.offset + y.offset,
+                        })
+                    }
+                    (Value::Stack(stack), Value::Term(y)) => {
+                        let mut stack = stack.clone();
+                        let top = stack.last_mut().unwrap();
+                        *top = match (top.input, y.input) {
+                            (Some(_), Some(_)) => unsupported(op, &a, &b),
+                            (x, y_input) => Term {
+                                input: x.or(y_input),
+                                offset: top.offset + y.offset,
+                            },
+                        };
+                        if !top.within(0, BASE) {
+                            unsupported(op, &a, &b);
+                        }
+                        Value::Stack(stack)
+                    }
+                    (_, Value::Stack(_)) if a.as_constant() == Some(0) => b,
+                    _ => unsupported(op, &a, &b),
+                },
+                MUL => match (a.as_constant(), b.as_constant()) {
+                    (Some(x), Some(y)) => Value::constant(x * y),
+                    (Some(0), _) | (_, Some(0)) => Value::constant(0),
+                    (_, Some(1)) => a,
+                    (Some(1), _) => b,
+                    (_, Some(BASE)) => {
+                        let mut stack = a.into_stack();
+                        if !stack.is_empty() {
+                            stack.push(Term::constant(0));
+                        }
+                        Value::from_stack(stack)
+                    }
+                    _ => unsupported(op, &a, &b),
+                },
+                DIV => match (&a, b.as_constant()) {
+                    (_, Some(0)) => panic!("division by zero"),
+                    (_, Some(1)) => a,
+                    (Value::Term(x), Some(y)) if x.input.is_none() => Value::constant(x.offset / y),
+                    (Value::Term(x), Some(y)) if x.within(0, y) => Value::constant(0),
+                    (_, Some(BASE)) => {
+                        let mut stack = a.into_stack();
+                        stack.pop();
+                        Value::from_stack(stack)
+                    }
+                    _ => unsupported(op, &a, &b),
+                },
+                MOD => match (&a, b.as_constant()) {
+                    (Value::Term(x), Some(y)) if x.input.is_none() && y > 0 => {
+                        Value::constant(x.offset % y)
+                    }
+                    (Value::Term(x), Some(y)) if x.within(0, y) => a,
+                    (_, Some(BASE)) => {
+                        Value::Term(a.into_stack().pop().unwrap_or(Term::constant(0)))
+                    }
+                    _ => unsupported(op, &a, &b),
+                },
+                EQL => match (&a, &b) {
+                    (Value::Term(x), Value::Term(y)) => {
+                        let (x_min, x_max) = x.range();
+                        let (y_min, y_max) = y.range();
+                        if x.input == y.input {
+                            Value::constant((x.offset == y.offset) as i64)
+                        } else if x_max < y_min || y_max < x_min {
+                            Value::constant(0)
+                        } else {
+                            // either way may lead to a valid model number
+                            for equal in [true, false] {
+                                let mut branch = state.clone();
+                                branch.registers[op.a] = Value::constant(equal as i64);
+                                branch.constraints.push(Constraint {
+                                    left: *x,
+                                    right: *y,
+                                    equal,
+                                });
+                                Program::explore(&ops[pc + 1..], branch, valid);
+                            }
+                            return;
+                        }
+                    }
+                    _ => unsupported(op, &a, &b),
+                },
+            };
+            state.registers[op.a] = result;
+        }
+
+        if state.registers[Z] == Value::constant(0) {
+            valid.push(state.constraints);
+        }
+    }
+}
+
+/// The first model number, trying the digits of each place in the given
+/// order, that meets all the constraints.
+fn model_number(constraints: &[Constraint], length: usize, order: [i64; 9]) -> Option<i64> {
+    fn search(
+        constraints: &[Constraint],
+        digits: &mut Vec<i64>,
+        length: usize,
+        order: [i64; 9],
+    ) -> bool {
+        if digits.len() == length {
+            return true;
+        }
+        for digit in order {
+            digits.push(digit);
+            if constraints.iter().all(|c| c.possible(digits))
+                && search(constraints, digits, length, order)
+            {
+                return true;
+            }
+            digits.pop();
+        }
+        false
+    }
+
+    let mut digits = Vec::with_capacity(length);
+    search(constraints, &mut digits, length, order)
+        .then(|| digits.iter().fold(0, |number, digit| number * 10 + digit))
+}
+
+const LARGEST_FIRST: [i64; 9] = [9, 8, 7, 6, 5, 4, 3, 2, 1];
+const SMALLEST_FIRST: [i64; 9] = [1, 2, 3, 4, 5, 6, 7, 8, 9];
+
+/// The largest and smallest model numbers the program accepts, with the
+/// constraints that make them valid.
+pub struct Analysis {
+    pub largest: i64,
+    pub smallest: i64,
+    pub constraints: Vec<Vec<Constraint>>,
+}
+
+pub fn analyse(program: &Program) -> Analysis {
+    let (constraints, length) = program.analyse();
+    let largest = constraints
+        .iter()
+        .filter_map(|c| model_number(c, length, LARGEST_FIRST))
+        .max()
+        .expect("a valid model number");
+    let smallest = constraints
+        .iter()
+        .filter_map(|c| model_number(c, length, SMALLEST_FIRST))
+        .min()
+        .expect("a valid model number");
+    Analysis {
+        largest,
+        smallest,
+        constraints,
+    }
+}
+
+pub fn digits(number: i64) -> Vec<i64> {
+    number
+        .to_string()
+        .chars()
+        .map(|c| c.to_digit(10).unwrap() as i64)
+        .collect()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Program;
+
+    fn parse(input: &str) -> Self::Input {
+        Program::parse_program(input)
+    }
+
+    fn part1(program: &Self::Input) -> Answer {
+        analyse(program).largest.into()
+    }
+
+    fn part2(program: &Self::Input) -> Answer {
+        analyse(program).smallest.into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use aoc_core::read;
+    use indoc::indoc;
+
+    #[test]
+    fn sample1() {
+        let mut program = Program::parse_program(&read("sample1.txt"));
+
+        assert_eq!(-1, program.run(vec![1]).1);
+        assert_eq!(1, program.run(vec![-1]).1);
+        assert_eq!(-42, program.run(vec![42]).1);
+    }
+
+    #[test]
+    fn sample2() {
+        let mut program = Program::parse_program(&read("sample2.txt"));
+
+        assert_eq!(1, program.run(vec![1, 3]).3);
+        assert_eq!(1, program.run(vec![2, 6]).3);
+        assert_eq!(1, program.run(vec![-2, -6]).3);
+        assert_eq!(0, program.run(vec![1, 1]).3);
+    }
+
+    #[test]
+    fn sample3() {
+        let mut program = Program::parse_program(&read("sample3.txt"));
+
+        assert_eq!((0, 0, 0, 0), program.run(vec![0]));
+        assert_eq!((0, 0, 0, 1), program.run(vec![1]));
+        assert_eq!((0, 0, 1, 1), program.run(vec![3]));
+        assert_eq!((0, 1, 1, 1), program.run(vec![7]));
+        assert_eq!((1, 1, 1, 1), program.run(vec![15]));
+    }
+
+    #[test]
+    fn two_digit_monad() {
+        // pushes d0 + 10, then pops it if d0 + 10 - 15 == d1
+        let program = Program::parse_program(indoc! {"
+                inp w
+                mul x 0
+                add x z
+                mod x 26
+                div z 1
+                add x 12
+                eql x w
+                eql x 0
+                mul y 0
+                add y 25
+                mul y x
+                add y 1
+                mul z y
+                mul y 0
+                add y w
+                add y 10
+                mul y x
+                add z y
+                inp w
+                mul x 0
+                add x z
+                mod x 26
+                div z 26
+                add x -15
+                eql x w
+                eql x 0
+                mul y 0
+                add y 25
+                mul y x
+                add y 1
+                mul z y
+                mul y 0
+                add y w
+                add y 3
+                mul y x
+                add z y
+            "});
+
+        let analysis = analyse(&program);
+
+        assert_eq!(1, analysis.constraints.len());
+        let constraints: Vec<String> = analysis.constraints[0]
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(vec!["d0 - 5 == d1"], constraints);
+        assert_eq!(94, analysis.largest);
+        assert_eq!(61, analysis.smallest);
+    }
+
+    #[test]
+    fn input_constraints() {
+        let program = Program::parse_program(&read("input.txt"));
+
+        let analysis = analyse(&program);
+
+        assert_eq!(1, analysis.constraints.len());
+        assert_eq!(7, analysis.constraints[0].len());
+        assert!(analysis.constraints[0].iter().all(|c| c.equal));
+    }
+
+    #[test]
+    fn part1() {
+        let mut program = Program::parse_program(&read("input.txt"));
+
+        let serial = analyse(&program).largest;
+
+        assert_eq!(98998519596997, serial);
+        assert_eq!(0, program.run(digits(serial)).3);
+    }
+
+    #[test]
+    fn part2() {
+        let mut program = Program::parse_program(&read("input.txt"));
+
+        let serial = analyse(&program).smallest;
+
+        assert_eq!(31521119151421, serial);
+        assert_eq!(0, program.run(digits(serial)).3);
+    }
+}
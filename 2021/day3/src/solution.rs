@@ -0,0 +1,149 @@
+use aoc_core::{Answer, Solution};
+
+fn count_bitwise_ones<'a, I>(nums: I, bits: usize) -> Vec<i32>
+where
+    I: Iterator<Item = &'a isize>,
+{
+    let mut counts = vec![0; bits];
+
+    let n: Vec<isize> = nums.cloned().collect();
+
+    for i in 0..bits {
+        let mut value = 0;
+        for (j, num) in n.iter().enumerate() {
+            value |= ((num & (1 << i)) as usize >> i) << j;
+        }
+        counts[bits - i - 1] = value.count_ones() as i32;
+    }
+
+    counts
+}
+
+fn increment_one_counts(bits: usize, sums: &mut [i32], nums: &[isize]) {
+    let counts = count_bitwise_ones(nums.iter(), bits);
+    sums.iter_mut().zip(counts).for_each(|(a, b)| *a += b);
+}
+
+fn count_ones(bits: usize, nums: &[isize]) -> Vec<i32> {
+    let mut sums = vec![0; bits];
+    let input_count = nums.len();
+    for i in 0..input_count.div_ceil(SIZE) {
+        increment_one_counts(
+            bits,
+            &mut sums,
+            &nums[i * SIZE..usize::min((i + 1) * SIZE, input_count)],
+        );
+    }
+    sums
+}
+
+fn lifesupport(bits: usize, mut candidates: Vec<isize>, compare: fn(i32, i32) -> bool) -> u32 {
+    for i in 0..bits {
+        let counts = count_ones(bits, &candidates);
+
+        if compare(counts[i], candidates.len() as i32) {
+            candidates.retain(|v| (*v & (1 << (bits - i - 1))) > 0);
+        } else {
+            candidates.retain(|v| (*v & (1 << (bits - i - 1))) == 0);
+        }
+
+        if candidates.len() <= 1 {
+            break;
+        }
+    }
+
+    candidates[0] as u32
+}
+
+const SIZE: usize = usize::BITS as usize;
+
+pub struct Report {
+    bits: usize,
+    numbers: Vec<isize>,
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Report;
+
+    fn parse(input: &str) -> Self::Input {
+        Report {
+            bits: input.lines().next().map_or(0, str::len),
+            numbers: input
+                .lines()
+                .map(|line| isize::from_str_radix(line, 2).unwrap())
+                .collect(),
+        }
+    }
+
+    fn part1(report: &Self::Input) -> Answer {
+        let Report { bits, numbers } = report;
+        let bits = *bits;
+
+        let input_count = numbers.len();
+
+        let sums = count_ones(bits, numbers);
+
+        let mut g = 0;
+        for b in 0..bits {
+            let over_half_ones = sums[bits - b - 1] > input_count as i32 / 2;
+            g |= (over_half_ones as u32) << b;
+        }
+
+        // flip bits to get epsilon
+        let mut e = !g;
+        e <<= i32::BITS as usize - bits;
+        e >>= i32::BITS as usize - bits;
+
+        (g * e).into()
+    }
+
+    fn part2(report: &Self::Input) -> Answer {
+        let Report { bits, numbers } = report;
+
+        let oxygen = lifesupport(*bits, numbers.clone(), |num_ones, num_total| {
+            num_ones >= (num_total - num_ones)
+        });
+
+        let co2 = lifesupport(*bits, numbers.clone(), |num_ones, num_total| {
+            num_ones < (num_total - num_ones)
+        });
+
+        (oxygen * co2).into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use test_case::test_case;
+
+    use super::*;
+    use aoc_core::read;
+
+    #[test]
+    fn count() {
+        assert_eq!([0], count_bitwise_ones([0].iter(), 1)[..]);
+        assert_eq!([1], count_bitwise_ones([1].iter(), 1)[..]);
+        assert_eq!([2], count_bitwise_ones([1, 1].iter(), 1)[..]);
+        assert_eq!([2, 1], count_bitwise_ones([2, 3].iter(), 2)[..]);
+        assert_eq!([64], count_bitwise_ones([1; 64].iter(), 1)[..]);
+    }
+
+    #[test_case("sample1.txt", 5 => 198 ; "sample")]
+    #[test_case("input.txt", 12 => 3309596 ; "input")]
+    #[test_case("test1.txt", 5 => 198 ; "test")]
+    fn part1(input: &str, bits: usize) -> Answer {
+        let report = Day::parse(&read(input));
+        assert_eq!(bits, report.bits);
+        Day::part1(&report)
+    }
+
+    #[test_case("sample1.txt", 5 => 230 ; "sample")]
+    #[test_case("input.txt", 12 => 2981085 ; "input")]
+    fn part2(input: &str, bits: usize) -> Answer {
+        let report = Day::parse(&read(input));
+        assert_eq!(bits, report.bits);
+        Day::part2(&report)
+    }
+}
@@ -0,0 +1,232 @@
+use aoc_core::{Answer, Solution};
+use std::collections::HashMap;
+
+const SIZE: i32 = 5;
+
+#[derive(Hash, Eq, PartialEq, Clone, Debug)]
+pub struct Tile {
+    x: i32,
+    y: i32,
+}
+
+#[derive(Clone, Debug)]
+pub struct Board {
+    marked: Vec<Tile>,
+    numbers: HashMap<i32, Tile>,
+}
+
+impl Board {
+    fn new(rows: &[&str]) -> Board {
+        assert_eq!("", rows[0]);
+        let mut numbers = HashMap::new();
+        for y in 0..SIZE {
+            let row = rows.get(y as usize + 1).unwrap();
+            for x in 0..SIZE {
+                let string = row.chars().skip(x as usize * 3).take(2).collect::<String>();
+                let number = string.trim().parse::<i32>().unwrap();
+                numbers.insert(number, Tile { x, y });
+            }
+        }
+        assert_eq!(SIZE * SIZE, numbers.len() as i32);
+        Board {
+            marked: vec![],
+            numbers,
+        }
+    }
+
+    fn play(&mut self, num: i32) {
+        if let Some(tile) = self.numbers.get(&num) {
+            self.marked.push(tile.clone());
+        }
+    }
+
+    fn sum_unused(&self) -> i32 {
+        let mut sum = 0;
+        for (num, tile) in &self.numbers {
+            if !self.marked.contains(tile) {
+                sum += num;
+            }
+        }
+        sum
+    }
+
+    fn is_winner(&self) -> bool {
+        let mut by_rows = self.marked.clone();
+        by_rows.sort_by_key(|t1| t1.y);
+        let mut by_cols = self.marked.clone();
+        by_cols.sort_by_key(|t1| t1.x);
+
+        self.marked.len() as i32 >= SIZE
+            && (by_cols
+                .chunk_by(|t1, t2| t1.x == t2.x)
+                .map(|col| col.len())
+                .max()
+                .unwrap() as i32
+                >= SIZE
+                || by_rows
+                    .chunk_by(|t1, t2| t1.y == t2.y)
+                    .map(|row| row.len())
+                    .max()
+                    .unwrap() as i32
+                    >= SIZE)
+    }
+}
+
+fn numbers_and_boards(input: &str) -> (Vec<i32>, Vec<Board>) {
+    let lines: Vec<&str> = input.trim().lines().collect();
+
+    let numbers: Vec<i32> = lines[0]
+        .split(',')
+        .map(|n| n.parse::<i32>().unwrap())
+        .collect();
+
+    let mut boards: Vec<Board> = Vec::new();
+    for b in lines[1..].chunks(SIZE as usize + 1) {
+        boards.push(Board::new(b));
+    }
+    (numbers, boards)
+}
+
+fn play(boards: &mut [Board], numbers: &[i32], play_to_win: bool) -> (i32, Board) {
+    let num_boards = boards.len() as i32;
+    let mut num_winners = 0;
+    for n in numbers.iter() {
+        for b in boards.iter_mut() {
+            if b.is_winner() {
+                continue;
+            }
+            b.play(*n);
+
+            if b.is_winner() {
+                num_winners += 1;
+                if play_to_win || num_winners == num_boards {
+                    return (*n, b.clone());
+                }
+            }
+        }
+    }
+    panic!("No winners")
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = (Vec<i32>, Vec<Board>);
+
+    fn parse(input: &str) -> Self::Input {
+        numbers_and_boards(input)
+    }
+
+    fn part1((numbers, boards): &Self::Input) -> Answer {
+        let (last_number, winner) = play(&mut boards.clone(), numbers, true);
+
+        (last_number * winner.sum_unused()).into()
+    }
+
+    fn part2((numbers, boards): &Self::Input) -> Answer {
+        let (last_number, loser) = play(&mut boards.clone(), numbers, false);
+
+        (last_number * loser.sum_unused()).into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use test_case::test_case;
+
+    use super::*;
+    use aoc_core::{read, Part};
+
+    #[test]
+    fn sum_unused() {
+        assert_eq!(
+            42,
+            Board {
+                marked: vec![Tile { x: 1, y: 1 }],
+                numbers: [(42, Tile { x: 0, y: 0 })].iter().cloned().collect(),
+            }
+            .sum_unused()
+        );
+
+        assert_eq!(
+            0,
+            Board {
+                marked: vec![Tile { x: 0, y: 0 }],
+                numbers: [(42, Tile { x: 0, y: 0 })].iter().cloned().collect(),
+            }
+            .sum_unused()
+        );
+    }
+
+    #[test]
+    fn is_winner() {
+        assert!(Board {
+            marked: vec![
+                Tile { x: 1, y: 0 },
+                Tile { x: 1, y: 1 },
+                Tile { x: 1, y: 2 },
+                Tile { x: 1, y: 3 },
+                Tile { x: 1, y: 4 },
+            ],
+            numbers: HashMap::new(),
+        }
+        .is_winner());
+        assert!(Board {
+            marked: vec![
+                Tile { x: 0, y: 4 },
+                Tile { x: 1, y: 4 },
+                Tile { x: 2, y: 4 },
+                Tile { x: 3, y: 4 },
+                Tile { x: 4, y: 4 },
+            ],
+            numbers: HashMap::new(),
+        }
+        .is_winner());
+        assert!(!Board {
+            marked: vec![
+                Tile { x: 0, y: 4 },
+                Tile { x: 1, y: 4 },
+                Tile { x: 2, y: 4 },
+                Tile { x: 3, y: 4 },
+                Tile { x: 4, y: 3 },
+            ],
+            numbers: HashMap::new(),
+        }
+        .is_winner());
+        assert!(!Board {
+            marked: vec![],
+            numbers: HashMap::new(),
+        }
+        .is_winner());
+        assert!(Board {
+            marked: vec![
+                Tile { x: 4, y: 4 },
+                Tile { x: 4, y: 0 },
+                Tile { x: 3, y: 1 },
+                Tile { x: 4, y: 3 },
+                Tile { x: 1, y: 3 },
+                Tile { x: 2, y: 0 },
+                Tile { x: 2, y: 2 },
+                Tile { x: 0, y: 4 },
+                Tile { x: 1, y: 4 },
+                Tile { x: 0, y: 0 },
+                Tile { x: 1, y: 0 },
+                Tile { x: 3, y: 0 },
+            ],
+            numbers: HashMap::new(),
+        }
+        .is_winner());
+    }
+
+    #[test_case("sample1.txt" => 4512 ; "sample")]
+    #[test_case("input.txt" => 33348 ; "input")]
+    fn part1(input: &str) -> Answer {
+        Day::solve(Part::One, &read(input))
+    }
+
+    #[test_case("sample1.txt" => 1924 ; "sample")]
+    #[test_case("input.txt" => 8112 ; "input")]
+    fn part2(input: &str) -> Answer {
+        Day::solve(Part::Two, &read(input))
+    }
+}
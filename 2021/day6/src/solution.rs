@@ -0,0 +1,95 @@
+use aoc_core::{Answer, Solution};
+use cached::proc_macro::cached;
+
+#[cfg(test)]
+fn fishes(mut fishy_fish: Vec<i8>, days: i32) -> usize {
+    for _ in 0..days {
+        fishy_fish = go_forth(fishy_fish.clone());
+    }
+
+    fishy_fish.len()
+}
+
+#[cfg(test)]
+fn go_forth(mut fishy_fish: Vec<i8>) -> Vec<i8> {
+    for f in 0..fishy_fish.len() {
+        let fish = fishy_fish.get_mut(f).unwrap();
+        if *fish == 0 {
+            *fish = 6;
+            fishy_fish.push(8);
+        } else {
+            *fish -= 1;
+        }
+    }
+    fishy_fish
+}
+
+#[cached]
+fn fish_rec(value: i8, days: i32) -> usize {
+    let remaining = days - 1 - value as i32;
+    if remaining < 0 {
+        1
+    } else {
+        fish_rec(6, remaining) + fish_rec(8, remaining)
+    }
+}
+
+fn population(fishes: &[i8], days: i32) -> usize {
+    fishes.iter().map(|&f| fish_rec(f, days)).sum()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<i8>;
+
+    fn parse(input: &str) -> Self::Input {
+        input
+            .trim()
+            .split(',')
+            .map(|f| f.parse().unwrap())
+            .collect()
+    }
+
+    fn part1(fishes: &Self::Input) -> Answer {
+        population(fishes, 80).into()
+    }
+
+    fn part2(fishes: &Self::Input) -> Answer {
+        population(fishes, 256).into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use test_case::test_case;
+
+    use super::*;
+    use aoc_core::{read, Part};
+
+    #[test]
+    fn one_fish() {
+        assert_eq!(fishes(vec![1], 1), fish_rec(1, 1));
+        for days in 1..80 {
+            let init_fish = 0;
+            assert_eq!(
+                fishes(vec![init_fish], days),
+                fish_rec(init_fish, days),
+                "Not equal after {} days",
+                days
+            );
+        }
+    }
+
+    #[test_case("sample1.txt" => 5934 ; "sample")]
+    #[test_case("input.txt" => 391888 ; "input")]
+    fn part1(input: &str) -> Answer {
+        Day::solve(Part::One, &read(input))
+    }
+
+    #[test_case("sample1.txt" => 26984457539 ; "sample")]
+    #[test_case("input.txt" => 1754597645339 ; "input")]
+    fn part2(input: &str) -> Answer {
+        Day::solve(Part::Two, &read(input))
+    }
+}
@@ -0,0 +1,202 @@
+use aoc_core::{Answer, Solution};
+use itertools::Itertools;
+use std::collections::HashMap;
+
+fn parse_line(l: &str) -> Vec<Vec<String>> {
+    l.trim()
+        .split("|")
+        .map(str::to_string)
+        .map(|s| {
+            s.trim()
+                .split(" ")
+                .map(|s| s.chars().sorted().collect::<String>())
+                .collect()
+        })
+        .collect()
+}
+
+fn deduce_mappings(inputs: &[String]) -> HashMap<String, i32> {
+    let mut known = HashMap::new();
+
+    let easies = easy_mappings(inputs);
+
+    for (s, v) in &easies {
+        known.insert(*v, s.clone());
+    }
+
+    let mut remaining: Vec<String> = inputs
+        .iter()
+        .filter(|i| !easies.contains_key(*i))
+        .map(|s| s.to_string())
+        .collect();
+
+    known.insert(
+        9,
+        remaining
+            .iter()
+            .filter(|i| i.len() == 6)
+            .filter(|s| {
+                let mut s = (*s).clone();
+                s.retain(|x| !known.get(&(4)).unwrap().contains(x));
+                s.retain(|x| !known.get(&(7)).unwrap().contains(x));
+                s.len() == 1
+            })
+            .map(|s| s.to_string())
+            .next()
+            .unwrap(),
+    );
+    remaining.retain(|x| x != known.get(&(9)).unwrap());
+
+    known.insert(
+        0,
+        remaining
+            .iter()
+            .filter(|i| i.len() == 6)
+            .filter(|s| {
+                let mut s = (*s).clone();
+                s.retain(|x| !known.get(&(1)).unwrap().contains(x));
+                s.len() == 4
+            })
+            .map(|s| s.to_string())
+            .next_back()
+            .unwrap(),
+    );
+    remaining.retain(|x| x != known.get(&(0)).unwrap());
+
+    known.insert(
+        6,
+        remaining
+            .iter()
+            .filter(|i| i.len() == 6)
+            .map(|s| s.to_string())
+            .next_back()
+            .unwrap(),
+    );
+    remaining.retain(|x| x != known.get(&(6)).unwrap());
+
+    known.insert(
+        3,
+        remaining
+            .iter()
+            .filter(|s| {
+                let mut s = (*s).clone();
+                s.retain(|x| !known.get(&(1)).unwrap().contains(x));
+                s.len() == 3
+            })
+            .map(|s| s.to_string())
+            .next_back()
+            .unwrap(),
+    );
+    remaining.retain(|x| x != known.get(&(3)).unwrap());
+
+    known.insert(
+        5,
+        remaining
+            .iter()
+            .filter(|s| {
+                let mut s = (*s).clone();
+                s.retain(|x| !known.get(&(9)).unwrap().contains(x));
+                s.is_empty()
+            })
+            .map(|s| s.to_string())
+            .next_back()
+            .unwrap(),
+    );
+    remaining.retain(|x| x != known.get(&(5)).unwrap());
+
+    known.insert(2, remaining.first().unwrap().clone());
+
+    let mut out = HashMap::new();
+    for (v, s) in known {
+        out.insert(s, v);
+    }
+
+    out
+}
+
+fn easy_mappings(input: &[String]) -> HashMap<String, i32> {
+    input
+        .iter()
+        .filter_map(|l| match l.len() {
+            7 => Some((l.clone(), 8)),
+            4 => Some((l.clone(), 4)),
+            3 => Some((l.clone(), 7)),
+            2 => Some((l.clone(), 1)),
+            _ => None,
+        })
+        .collect()
+}
+
+fn output_value(outputs: &[String], known: HashMap<String, i32>) -> i32 {
+    outputs
+        .iter()
+        .map(|x| known.get(x).unwrap().to_string())
+        .collect::<Vec<String>>()
+        .join("")
+        .parse::<i32>()
+        .unwrap()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Vec<Vec<String>>>;
+
+    fn parse(input: &str) -> Self::Input {
+        input.trim().lines().map(parse_line).collect()
+    }
+
+    fn part1(entries: &Self::Input) -> Answer {
+        let easy_ones = [7, 4, 3, 2];
+        entries
+            .iter()
+            .flat_map(|l| &l[1])
+            .filter(|o| easy_ones.contains(&o.len()))
+            .count()
+            .into()
+    }
+
+    fn part2(entries: &Self::Input) -> Answer {
+        entries
+            .iter()
+            .map(|l| output_value(&l[1], deduce_mappings(&l[0])))
+            .sum::<i32>()
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use test_case::test_case;
+
+    use super::*;
+    use aoc_core::{read, Part};
+
+    #[test]
+    fn single() {
+        let l = parse_line(
+            "acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab | cdfeb fcadb cdfeb cdbaf",
+        );
+
+        let inputs = l.first().unwrap();
+        let outputs = l.get(1).unwrap();
+
+        let known = deduce_mappings(inputs);
+
+        assert_eq!(2, *known.get("acdfg").unwrap());
+
+        assert_eq!(5353, output_value(outputs, known));
+    }
+
+    #[test_case("sample1.txt" => 26 ; "sample")]
+    #[test_case("input.txt" => 479 ; "input")]
+    fn part1(input: &str) -> Answer {
+        Day::solve(Part::One, &read(input))
+    }
+
+    #[test_case("sample1.txt" => 61229 ; "sample")]
+    #[test_case("input.txt" => 1041746 ; "input")]
+    fn part2(input: &str) -> Answer {
+        Day::solve(Part::Two, &read(input))
+    }
+}
@@ -1,6 +1,6 @@
 use anyhow::bail;
+use aoc_core::{Answer, Solution};
 use scan_fmt::scan_fmt;
-use std::fs::read_to_string;
 
 #[derive(PartialEq, Eq, Debug, Copy, Clone)]
 pub enum RPS {
@@ -88,23 +88,44 @@ where
     from(first.as_str(), second.as_str())
 }
 
-fn read<F>(input: &str, parse: F) -> anyhow::Result<Vec<(RPS, RPS)>>
+fn rounds<F>(input: &str, parse: F) -> anyhow::Result<Vec<(RPS, RPS)>>
 where
     F: Fn(&str) -> anyhow::Result<(RPS, RPS)>,
 {
-    read_to_string(input)?.lines().map(parse).collect()
+    input.lines().map(parse).collect()
 }
 
 pub fn play(input: &str, parse: fn(&str) -> anyhow::Result<(RPS, RPS)>) -> anyhow::Result<i32> {
-    Ok(read(input, parse)?
+    Ok(rounds(input, parse)?
         .into_iter()
         .map(|(other, me)| other.score(me))
         .sum())
 }
 
+pub struct Day;
+
+impl Solution for Day {
+    // The second column means something different in each part, so the
+    // rounds can only be decoded once the part is known
+    type Input = String;
+
+    fn parse(input: &str) -> Self::Input {
+        input.to_string()
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        play(input, parse1).unwrap().into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        play(input, parse2).unwrap().into()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_core::read;
 
     #[test]
     fn rps() {
@@ -124,14 +145,14 @@ mod tests {
     }
 
     #[test]
-    fn reading() {
+    fn decoding_rounds() {
         assert_eq!(
             vec![
                 (RPS::Rock, RPS::Paper),
                 (RPS::Paper, RPS::Rock),
                 (RPS::Scissors, RPS::Scissors),
             ],
-            read("sample.txt", parse1).unwrap()
+            rounds(&read("sample.txt"), parse1).unwrap()
         );
     }
 
@@ -142,9 +163,9 @@ mod tests {
 
     #[test]
     fn playing() {
-        assert_eq!(15, play("sample.txt", parse1).unwrap());
-        assert_eq!(15422, play("input.txt", parse1).unwrap());
-        assert_eq!(15442, play("input.txt", parse2).unwrap());
+        assert_eq!(15, play(&read("sample.txt"), parse1).unwrap());
+        assert_eq!(15422, play(&read("input.txt"), parse1).unwrap());
+        assert_eq!(15442, play(&read("input.txt"), parse2).unwrap());
     }
     #[test]
     fn lose_draw_win() {
@@ -1,8 +1,12 @@
+use aoc_core::{read, Part, Solution};
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
-use d03::solution::part2;
+use y2022_d03::solution::Day;
 
 pub fn criterion_benchmark(c: &mut Criterion) {
-    c.bench_function("part2", |b| b.iter(|| part2(black_box("input.txt"))));
+    let input = read("input.txt");
+    c.bench_function("part2", |b| {
+        b.iter(|| Day::solve(Part::Two, black_box(&input)))
+    });
 }
 
 criterion_group!(benches, criterion_benchmark);
@@ -1,31 +1,41 @@
+use aoc_core::{Answer, Solution};
 use itertools::Itertools;
 use std::cmp::Ordering;
-use std::fs::read_to_string;
 
 #[cfg(feature = "bench_nightly")]
 extern crate test;
 
-pub fn part1(input: &str) -> usize {
-    read_to_string(input)
-        .unwrap()
-        .lines()
-        .map(split)
-        .map(find_first_common)
-        .map(priority)
-        .sum()
-}
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Self::Input {
+        input.lines().map(String::from).collect()
+    }
+
+    fn part1(rucksacks: &Self::Input) -> Answer {
+        rucksacks
+            .iter()
+            .map(|rucksack| split(rucksack))
+            .map(find_first_common)
+            .map(priority)
+            .sum::<usize>()
+            .into()
+    }
 
-pub fn part2(input: &str) -> usize {
-    read_to_string(input)
-        .unwrap()
-        .lines()
-        .enumerate()
-        .map(|(index, item)| (index / 3, to_bitmask(item)))
-        .group_by(|(group, _)| *group)
-        .into_iter()
-        .map(|(_, items)| items.fold(usize::MAX, |a, (_, b)| a & b))
-        .map(to_priority)
-        .sum()
+    fn part2(rucksacks: &Self::Input) -> Answer {
+        rucksacks
+            .iter()
+            .enumerate()
+            .map(|(index, item)| (index / 3, to_bitmask(item)))
+            .group_by(|(group, _)| *group)
+            .into_iter()
+            .map(|(_, items)| items.fold(usize::MAX, |a, (_, b)| a & b))
+            .map(to_priority)
+            .sum::<usize>()
+            .into()
+    }
 }
 
 fn to_bitmask(core: &str) -> usize {
@@ -83,6 +93,7 @@ fn find_first_common(pair: (&str, &str)) -> char {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_core::{read, Part};
     #[cfg(feature = "bench_nightly")]
     use test::Bencher;
 
@@ -118,20 +129,21 @@ mod tests {
 
     #[test]
     fn part1_sample() {
-        assert_eq!(157, part1("sample.txt"));
+        assert_eq!(157, Day::solve(Part::One, &read("sample.txt")));
     }
 
     #[test]
     fn part2_sample() {
-        assert_eq!(70, part2("sample.txt"));
+        assert_eq!(70, Day::solve(Part::Two, &read("sample.txt")));
     }
 
     #[cfg(feature = "bench_nightly")]
     #[bench]
     fn bench(b: &mut Bencher) {
+        let input = read("input.txt");
         b.iter(|| {
             for _ in 1..100 {
-                part2("input.txt");
+                Day::solve(Part::Two, &read("input.txt"));
             }
         })
     }
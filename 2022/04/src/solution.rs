@@ -1,18 +1,28 @@
+use aoc_core::{Answer, Solution};
 use scan_fmt::scan_fmt;
-use std::fs::read_to_string;
 use std::ops::RangeInclusive;
 
-pub fn part1(input: &str) -> usize {
-    let input = read_to_string(input).unwrap();
-    input.lines().map(parse_row).filter(contains).count()
-}
+type Pair = (RangeInclusive<usize>, RangeInclusive<usize>);
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Pair>;
+
+    fn parse(input: &str) -> Self::Input {
+        input.lines().map(parse_row).collect()
+    }
+
+    fn part1(pairs: &Self::Input) -> Answer {
+        pairs.iter().filter(|pair| contains(pair)).count().into()
+    }
 
-pub fn part2(input: &str) -> usize {
-    let input = read_to_string(input).unwrap();
-    input.lines().map(parse_row).filter(overlaps).count()
+    fn part2(pairs: &Self::Input) -> Answer {
+        pairs.iter().filter(|pair| overlaps(pair)).count().into()
+    }
 }
 
-fn parse_row(row: &str) -> (RangeInclusive<usize>, RangeInclusive<usize>) {
+fn parse_row(row: &str) -> Pair {
     let (start1, end1, start2, end2) =
         scan_fmt!(row, "{d}-{d},{d}-{d}", usize, usize, usize, usize).unwrap();
     assert!(start1 <= end1);
@@ -20,13 +30,13 @@ fn parse_row(row: &str) -> (RangeInclusive<usize>, RangeInclusive<usize>) {
     (start1..=end1, start2..=end2)
 }
 
-fn contains(ranges: &(RangeInclusive<usize>, RangeInclusive<usize>)) -> bool {
+fn contains(ranges: &Pair) -> bool {
     let mut ranges1 = ranges.clone();
     let mut ranges2 = ranges.clone();
     ranges1.0.all(|v| ranges1.1.contains(&v)) || ranges2.1.all(|v| ranges2.0.contains(&v))
 }
 
-fn overlaps(ranges: &(RangeInclusive<usize>, RangeInclusive<usize>)) -> bool {
+fn overlaps(ranges: &Pair) -> bool {
     let mut ranges1 = ranges.clone();
     let mut ranges2 = ranges.clone();
     ranges1.0.any(|v| ranges1.1.contains(&v)) || ranges2.1.any(|v| ranges2.0.contains(&v))
@@ -35,6 +45,7 @@ fn overlaps(ranges: &(RangeInclusive<usize>, RangeInclusive<usize>)) -> bool {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_core::{read, Part};
 
     #[test]
     fn parsing_rows() {
@@ -67,11 +78,11 @@ mod tests {
 
     #[test]
     fn part1_sample() {
-        assert_eq!(2, part1("sample.txt"));
+        assert_eq!(2, Day::solve(Part::One, &read("sample.txt")));
     }
 
     #[test]
     fn part2_sample() {
-        assert_eq!(4, part2("sample.txt"));
+        assert_eq!(4, Day::solve(Part::Two, &read("sample.txt")));
     }
 }
@@ -1,15 +1,16 @@
+use aoc_core::{Answer, Solution};
 use scan_fmt::scan_fmt;
 use std::fmt;
 use std::fmt::Formatter;
-use std::fs::read_to_string;
 
-struct Board {
+#[derive(Clone)]
+pub struct Board {
     stacks: Vec<String>,
 }
 
 impl Board {
-    pub(crate) fn make_some(&mut self, moves: Moves) {
-        for (count, from, to) in moves.moves {
+    pub(crate) fn make_some(&mut self, moves: &Moves) {
+        for &(count, from, to) in &moves.moves {
             for _ in 0..count {
                 let what = self.stacks.get_mut(from - 1).unwrap().pop().unwrap();
                 self.stacks.get_mut(to - 1).unwrap().push(what);
@@ -17,8 +18,8 @@ impl Board {
         }
     }
 
-    pub(crate) fn make_some_modern(&mut self, moves: Moves) {
-        for (count, from, to) in moves.moves {
+    pub(crate) fn make_some_modern(&mut self, moves: &Moves) {
+        for &(count, from, to) in &moves.moves {
             let mut crane = String::new();
             for _ in 0..count {
                 let what = self.stacks.get_mut(from - 1).unwrap().pop().unwrap();
@@ -93,7 +94,7 @@ impl fmt::Display for Board {
     }
 }
 
-struct Moves {
+pub struct Moves {
     moves: Vec<(usize, usize, usize)>,
 }
 
@@ -124,7 +125,7 @@ fn parse(input: &str) -> (Board, Moves) {
     let mut board = Board::new();
     let mut moves = Moves::new();
     let mut parsing_board = true;
-    for line in read_to_string(input).unwrap().lines() {
+    for line in input.lines() {
         if line.is_empty() {
             parsing_board = false;
         } else if parsing_board {
@@ -137,25 +138,36 @@ fn parse(input: &str) -> (Board, Moves) {
     (board, moves)
 }
 
-pub fn part1(input: &str) -> String {
-    let (mut board, moves) = parse(input);
+pub struct Day;
 
-    board.make_some(moves);
+impl Solution for Day {
+    type Input = (Board, Moves);
 
-    board.result()
-}
+    fn parse(input: &str) -> Self::Input {
+        parse(input)
+    }
 
-pub fn part2(input: &str) -> String {
-    let (mut board, moves) = parse(input);
+    fn part1((board, moves): &Self::Input) -> Answer {
+        let mut board = board.clone();
 
-    board.make_some_modern(moves);
+        board.make_some(moves);
+
+        board.result().into()
+    }
 
-    board.result()
+    fn part2((board, moves): &Self::Input) -> Answer {
+        let mut board = board.clone();
+
+        board.make_some_modern(moves);
+
+        board.result().into()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_core::{read, Part};
 
     #[test]
     fn displaying_a_board() {
@@ -177,27 +189,24 @@ mod tests {
 
     #[test]
     fn parsing() {
-        let (board, moves) = parse("sample.txt");
+        let (board, moves) = parse(&read("sample.txt"));
 
-        assert_eq!(
-            read_to_string("sample.txt").unwrap(),
-            format!("{board}\n{moves}")
-        );
+        assert_eq!(read("sample.txt"), format!("{board}\n{moves}"));
     }
 
     #[test]
     fn result_from_board() {
-        let (board, _) = parse("sample.txt");
+        let (board, _) = parse(&read("sample.txt"));
         assert_eq!("NDP", board.result());
     }
 
     #[test]
     fn part1_sample() {
-        assert_eq!("CMZ", part1("sample.txt"));
+        assert_eq!("CMZ", Day::solve(Part::One, &read("sample.txt")));
     }
 
     #[test]
     fn part2_sample() {
-        assert_eq!("MCD", part2("sample.txt"));
+        assert_eq!("MCD", Day::solve(Part::Two, &read("sample.txt")));
     }
 }
@@ -1,6 +1,6 @@
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
-use d06::solution::find_marker;
 use std::fs::read_to_string;
+use y2022_d06::solution::find_marker;
 
 pub fn criterion_benchmark(c: &mut Criterion) {
     let input = read_to_string("input.txt").unwrap();
@@ -1,15 +1,21 @@
-use std::fs::read_to_string;
+use aoc_core::{Answer, Solution};
 
-pub fn part1(input: &str) -> usize {
-    let input = read_to_string(input).unwrap();
-    let signal = input.lines().next().unwrap();
-    find_marker(signal, 4)
-}
+pub struct Day;
+
+impl Solution for Day {
+    type Input = String;
+
+    fn parse(input: &str) -> Self::Input {
+        input.lines().next().unwrap().to_string()
+    }
 
-pub fn part2(input: &str) -> usize {
-    let input = read_to_string(input).unwrap();
-    let signal = input.lines().next().unwrap();
-    find_marker(signal, 14)
+    fn part1(signal: &Self::Input) -> Answer {
+        find_marker(signal, 4).into()
+    }
+
+    fn part2(signal: &Self::Input) -> Answer {
+        find_marker(signal, 14).into()
+    }
 }
 
 pub fn find_marker(signal: &str, num: usize) -> usize {
@@ -38,6 +44,7 @@ fn has_duplicates(buf: &[u8]) -> bool {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_core::{read, Part};
 
     #[test]
     fn duplicates() {
@@ -67,11 +74,11 @@ mod tests {
 
     #[test]
     fn part1_sample() {
-        assert_eq!(7, part1("sample.txt"));
+        assert_eq!(7, Day::solve(Part::One, &read("sample.txt")));
     }
 
     #[test]
     fn part2_sample() {
-        assert_eq!(19, part2("sample.txt"));
+        assert_eq!(19, Day::solve(Part::Two, &read("sample.txt")));
     }
 }
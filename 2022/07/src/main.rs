@@ -0,0 +1,15 @@
+use std::env;
+
+use aoc_core::read;
+use y2022_d07::solution::{Day, FileSystem};
+
+fn main() {
+    match env::args().nth(1) {
+        // Show the tree the given terminal output explores instead.
+        Some(input) => {
+            let fs: FileSystem = read(&input).parse().unwrap_or_else(|e| panic!("{e}"));
+            print!("{fs}");
+        }
+        None => aoc_core::solve_measure_and_print::<Day>(env!("CARGO_PKG_NAME"), "input.txt"),
+    }
+}
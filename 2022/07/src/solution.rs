@@ -0,0 +1,316 @@
+use aoc_core::{Answer, Solution};
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+const DISK: u64 = 70_000_000;
+const NEEDED: u64 = 30_000_000;
+
+/// The directory tree a terminal session explored, kept as an arena of
+/// nodes with the root at index 0.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileSystem {
+    nodes: Vec<Node>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Node {
+    name: String,
+    parent: Option<usize>,
+    kind: Kind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Kind {
+    File { size: u64 },
+    Dir { children: Vec<usize>, listed: bool },
+}
+
+impl FileSystem {
+    fn new() -> Self {
+        FileSystem {
+            nodes: vec![Node {
+                name: "/".to_string(),
+                parent: None,
+                kind: Kind::Dir {
+                    children: Vec::new(),
+                    listed: false,
+                },
+            }],
+        }
+    }
+
+    fn children(&self, dir: usize) -> &[usize] {
+        match &self.nodes[dir].kind {
+            Kind::Dir { children, .. } => children,
+            Kind::File { .. } => &[],
+        }
+    }
+
+    fn child(&self, dir: usize, name: &str) -> Option<usize> {
+        self.children(dir)
+            .iter()
+            .copied()
+            .find(|&child| self.nodes[child].name == name)
+    }
+
+    fn add(&mut self, dir: usize, name: &str, kind: Kind) {
+        let node = self.nodes.len();
+        self.nodes.push(Node {
+            name: name.to_string(),
+            parent: Some(dir),
+            kind,
+        });
+        if let Kind::Dir { children, .. } = &mut self.nodes[dir].kind {
+            children.push(node);
+        }
+    }
+
+    fn path(&self, node: usize) -> String {
+        match self.nodes[node].parent {
+            None => "/".to_string(),
+            Some(0) => format!("/{}", self.nodes[node].name),
+            Some(parent) => format!("{}/{}", self.path(parent), self.nodes[node].name),
+        }
+    }
+
+    /// The total size of every directory, the files in its subdirectories
+    /// included.
+    pub fn dir_sizes(&self) -> Vec<u64> {
+        // Nodes are only ever added after their parent, so going backwards
+        // sees every child before its parent.
+        let mut sizes = vec![0; self.nodes.len()];
+        for (node, Node { parent, kind, .. }) in self.nodes.iter().enumerate().rev() {
+            if let Kind::File { size } = kind {
+                sizes[node] = *size;
+            }
+            if let Some(parent) = parent {
+                sizes[*parent] += sizes[node];
+            }
+        }
+        self.nodes
+            .iter()
+            .zip(sizes)
+            .filter(|(node, _)| matches!(node.kind, Kind::Dir { .. }))
+            .map(|(_, size)| size)
+            .collect()
+    }
+
+    fn fmt_node(&self, f: &mut Formatter<'_>, node: usize, depth: usize) -> fmt::Result {
+        let Node { name, kind, .. } = &self.nodes[node];
+        match kind {
+            Kind::File { size } => writeln!(f, "{:depth$}- {name} (file, size={size})", "")?,
+            Kind::Dir { children, .. } => {
+                writeln!(f, "{:depth$}- {name} (dir)", "")?;
+                let mut children = children.clone();
+                children.sort_by_key(|&child| &self.nodes[child].name);
+                for child in children {
+                    self.fmt_node(f, child, depth + 2)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Draws the tree the way the puzzle does, entries sorted by name.
+impl Display for FileSystem {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.fmt_node(f, 0, 0)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LogError {
+    UnknownDirectory { line: usize, name: String },
+    AboveRoot { line: usize },
+    DuplicateListing { line: usize, path: String },
+    UnknownCommand { line: usize },
+    UnexpectedOutput { line: usize },
+}
+
+impl Display for LogError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            LogError::UnknownDirectory { line, name } => {
+                write!(f, "line {line}: no directory '{name}' was listed here")
+            }
+            LogError::AboveRoot { line } => write!(f, "line {line}: can't leave the root"),
+            LogError::DuplicateListing { line, path } => {
+                write!(f, "line {line}: {path} is listed a second time")
+            }
+            LogError::UnknownCommand { line } => write!(f, "line {line}: unknown command"),
+            LogError::UnexpectedOutput { line } => {
+                write!(f, "line {line}: expected 'dir NAME' or 'SIZE NAME'")
+            }
+        }
+    }
+}
+
+impl std::error::Error for LogError {}
+
+/// Replays the `cd` and `ls` commands of a terminal session, with lines
+/// numbered from 1 in the errors.
+impl FromStr for FileSystem {
+    type Err = LogError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut fs = FileSystem::new();
+        let mut cwd = 0;
+        // The directory `ls` is listing, if the lines are its output.
+        let mut listing = None;
+
+        for (line, text) in (1..).zip(s.lines()) {
+            if let Some(command) = text.strip_prefix("$ ") {
+                listing = None;
+                if command == "ls" {
+                    match &mut fs.nodes[cwd].kind {
+                        Kind::Dir { listed, .. } if !*listed => *listed = true,
+                        _ => {
+                            let path = fs.path(cwd);
+                            return Err(LogError::DuplicateListing { line, path });
+                        }
+                    }
+                    listing = Some(cwd);
+                } else if let Some(name) = command.strip_prefix("cd ") {
+                    cwd = match name {
+                        "/" => 0,
+                        ".." => fs.nodes[cwd].parent.ok_or(LogError::AboveRoot { line })?,
+                        _ => fs
+                            .child(cwd, name)
+                            .filter(|&dir| matches!(fs.nodes[dir].kind, Kind::Dir { .. }))
+                            .ok_or_else(|| LogError::UnknownDirectory {
+                                line,
+                                name: name.to_string(),
+                            })?,
+                    };
+                } else {
+                    return Err(LogError::UnknownCommand { line });
+                }
+                continue;
+            }
+
+            let dir = listing.ok_or(LogError::UnexpectedOutput { line })?;
+            let (info, name) = text
+                .split_once(' ')
+                .ok_or(LogError::UnexpectedOutput { line })?;
+            let kind = if info == "dir" {
+                Kind::Dir {
+                    children: Vec::new(),
+                    listed: false,
+                }
+            } else {
+                let size = info
+                    .parse()
+                    .map_err(|_| LogError::UnexpectedOutput { line })?;
+                Kind::File { size }
+            };
+            if fs.child(dir, name).is_some() {
+                let path = fs.path(dir);
+                return Err(LogError::DuplicateListing { line, path });
+            }
+            fs.add(dir, name, kind);
+        }
+
+        Ok(fs)
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = FileSystem;
+
+    fn parse(input: &str) -> Self::Input {
+        input.parse().unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    fn part1(fs: &Self::Input) -> Answer {
+        fs.dir_sizes()
+            .into_iter()
+            .filter(|&size| size <= 100_000)
+            .sum::<u64>()
+            .into()
+    }
+
+    fn part2(fs: &Self::Input) -> Answer {
+        let sizes = fs.dir_sizes();
+        let free = DISK.saturating_sub(sizes[0]);
+        let missing = NEEDED.saturating_sub(free);
+        sizes
+            .into_iter()
+            .filter(|&size| size >= missing)
+            .min()
+            .unwrap()
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::{read, Part};
+    use test_case::test_case;
+
+    #[test]
+    fn sizes() {
+        let fs: FileSystem = read("sample.txt").parse().unwrap();
+        assert_eq!(vec![48381165, 94853, 24933642, 584], fs.dir_sizes());
+    }
+
+    #[test]
+    fn printing() {
+        let fs: FileSystem = read("sample.txt").parse().unwrap();
+        assert_eq!(
+            "- / (dir)
+  - a (dir)
+    - e (dir)
+      - i (file, size=584)
+    - f (file, size=29116)
+    - g (file, size=2557)
+    - h.lst (file, size=62596)
+  - b.txt (file, size=14848514)
+  - c.dat (file, size=8504156)
+  - d (dir)
+    - d.ext (file, size=5626152)
+    - d.log (file, size=8033020)
+    - j (file, size=4060174)
+    - k (file, size=7214296)
+",
+            fs.to_string()
+        );
+    }
+
+    #[test_case("$ cd /\n$ cd a" => LogError::UnknownDirectory { line: 2, name: "a".to_string() }; "unlisted")]
+    #[test_case("$ ls\n1 a\n$ cd a" => LogError::UnknownDirectory { line: 3, name: "a".to_string() }; "file")]
+    #[test_case("$ cd .." => LogError::AboveRoot { line: 1 }; "above root")]
+    #[test_case("$ ls\ndir a\n$ cd a\n$ ls\n$ cd /\n$ cd a\n$ ls" => LogError::DuplicateListing { line: 7, path: "/a".to_string() }; "listed twice")]
+    #[test_case("$ ls\n1 a\n2 a" => LogError::DuplicateListing { line: 3, path: "/".to_string() }; "same name")]
+    #[test_case("$ rm -rf /" => LogError::UnknownCommand { line: 1 }; "command")]
+    #[test_case("1 a" => LogError::UnexpectedOutput { line: 1 }; "output without ls")]
+    #[test_case("$ ls\nbig a" => LogError::UnexpectedOutput { line: 2 }; "size")]
+    fn malformed_logs(log: &str) -> LogError {
+        log.parse::<FileSystem>().unwrap_err()
+    }
+
+    #[test]
+    fn part1_sample() {
+        assert_eq!(95437, Day::solve(Part::One, &read("sample.txt")));
+    }
+
+    #[test]
+    fn part2_sample() {
+        assert_eq!(24933642, Day::solve(Part::Two, &read("sample.txt")));
+    }
+
+    #[test]
+    fn overfull_disk() {
+        let log = "$ ls\n80000000 a\ndir b\n$ cd b\n$ ls\n1 c";
+        assert_eq!(80000001, Day::solve(Part::Two, log));
+    }
+
+    #[test]
+    fn input() {
+        assert_eq!(1391690, Day::solve(Part::One, &read("input.txt")));
+        assert_eq!(5469168, Day::solve(Part::Two, &read("input.txt")));
+    }
+}
@@ -1,141 +1,89 @@
-use scan_fmt::scan_fmt;
+use aoc_core::{Answer, Solution};
+use aoc_grid::{Direction, Pos};
 use std::collections::HashSet;
-use std::fs::read_to_string;
-use std::str::FromStr;
 
 #[derive(PartialEq, Debug)]
-enum Direction {
-    Up,
-    Down,
-    Left,
-    Right,
-    Null,
-}
-
-impl FromStr for Direction {
-    type Err = ();
-
-    fn from_str(dir: &str) -> Result<Self, Self::Err> {
-        Ok(match dir {
-            "U" => Direction::Up,
-            "D" => Direction::Down,
-            "L" => Direction::Left,
-            "R" => Direction::Right,
-            _ => Direction::Null,
-        })
-    }
-}
-
-#[derive(PartialEq, Debug)]
-struct Move {
+pub struct Move {
     direction: Direction,
     count: usize,
 }
 
-impl Move {
-    fn from((direction, count): (Direction, usize)) -> Move {
-        Move { direction, count }
-    }
-}
-
-#[derive(Copy, Clone)]
-struct End {
-    x: i64,
-    y: i64,
-}
-
-impl End {
-    fn move_towards(&mut self, d: &Direction) {
-        match *d {
-            Direction::Up => self.y += 1,
-            Direction::Down => self.y -= 1,
-            Direction::Left => self.x -= 1,
-            Direction::Right => self.x += 1,
-            Direction::Null => panic!("This is stupid"),
-        }
-    }
-
-    fn follow(&mut self, head: &End) {
-        match (head.x - self.x, head.y - self.y) {
-            (-1, -2) | (-2, -1) | (-2, -2) => {
-                self.x -= 1;
-                self.y -= 1;
-            }
-            (1, -2) | (2, -1) | (2, -2) => {
-                self.x += 1;
-                self.y -= 1;
-            }
-            (1, 2) | (2, 1) | (2, 2) => {
-                self.x += 1;
-                self.y += 1;
-            }
-            (-1, 2) | (-2, 1) | (-2, 2) => {
-                self.x -= 1;
-                self.y += 1;
-            }
-            (2, 0) => self.x += 1,
-            (-2, 0) => self.x -= 1,
-            (0, 2) => self.y += 1,
-            (0, -2) => self.y -= 1,
-            _ => (),
-        }
+/// Moves a knot one step towards `head`, if they are no longer touching.
+fn follow(knot: &mut Pos, head: &Pos) {
+    if knot.chebyshev(*head) > 1 {
+        knot.x += (head.x - knot.x).signum();
+        knot.y += (head.y - knot.y).signum();
     }
 }
 
 struct Rope {
-    head: End,
-    tail: Vec<End>,
+    head: Pos,
+    tail: Vec<Pos>,
 }
 
 impl Rope {
     fn new(x: i64, y: i64, tail_length: usize) -> Rope {
         Rope {
-            head: End { x, y },
-            tail: (0..tail_length).map(|_| End { x, y }).collect(),
+            head: Pos::new(x, y),
+            tail: vec![Pos::new(x, y); tail_length],
         }
     }
 
     fn perform(&mut self, moves: &Vec<Move>) -> usize {
         let mut tail_visited = HashSet::new();
-        let last = self.tail.last().unwrap();
-        tail_visited.insert((last.x, last.y));
+        tail_visited.insert(*self.tail.last().unwrap());
         for Move { direction, count } in moves {
             for _ in 0..*count {
-                self.head.move_towards(direction);
+                self.head += *direction;
                 let mut prev = self.head;
                 for next in self.tail.iter_mut() {
-                    next.follow(&prev);
+                    follow(next, &prev);
                     prev = *next;
                 }
-                let last = self.tail.last().unwrap();
-                tail_visited.insert((last.x, last.y));
+                tail_visited.insert(*self.tail.last().unwrap());
             }
         }
         tail_visited.len()
     }
 }
 
-pub fn part1(input: &str) -> usize {
-    let mut rope = Rope::new(0, 0, 1);
-    rope.perform(&parse_moves(&read_to_string(input).unwrap()))
-}
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Move>;
 
-pub fn part2(input: &str) -> usize {
-    let mut rope = Rope::new(0, 0, 9);
-    rope.perform(&parse_moves(&read_to_string(input).unwrap()))
+    fn parse(input: &str) -> Self::Input {
+        parse_moves(input)
+    }
+
+    fn part1(moves: &Self::Input) -> Answer {
+        let mut rope = Rope::new(0, 0, 1);
+        rope.perform(moves).into()
+    }
+
+    fn part2(moves: &Self::Input) -> Answer {
+        let mut rope = Rope::new(0, 0, 9);
+        rope.perform(moves).into()
+    }
 }
 
 fn parse_moves(input: &str) -> Vec<Move> {
     input
         .lines()
-        .map(|l| scan_fmt!(l, "{} {}", Direction, usize).unwrap())
-        .map(Move::from)
+        .map(|l| {
+            let (direction, count) = l.split_once(' ').expect("a direction and a count");
+            Move {
+                direction: direction.parse().unwrap(),
+                count: count.parse().unwrap(),
+            }
+        })
         .collect()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_core::{read, Part};
     use test_case::test_case;
 
     #[test]
@@ -160,10 +108,10 @@ mod tests {
     #[test_case(0, -2, 0, 0 => (0, -1); "two below")]
     #[test_case(2, -2, 0, 0 => (1, -1); "diagon alley")]
     fn following(x1: i64, y1: i64, x2: i64, y2: i64) -> (i64, i64) {
-        let head = End { x: x1, y: y1 };
-        let mut tail = End { x: x2, y: y2 };
+        let head = Pos::new(x1, y1);
+        let mut tail = Pos::new(x2, y2);
 
-        tail.follow(&head);
+        follow(&mut tail, &head);
 
         (tail.x, tail.y)
     }
@@ -175,28 +123,28 @@ mod tests {
         assert_eq!(1, rope.perform(&parse_moves("R 2")));
 
         assert_eq!(2, rope.head.x);
-        assert_eq!(1, rope.tail.get(0).unwrap().x);
+        assert_eq!(1, rope.tail.first().unwrap().x);
         assert_eq!(0, rope.tail.get(1).unwrap().x);
 
         assert_eq!(3, rope.perform(&parse_moves("R 2")));
 
         assert_eq!(4, rope.head.x);
-        assert_eq!(3, rope.tail.get(0).unwrap().x);
+        assert_eq!(3, rope.tail.first().unwrap().x);
         assert_eq!(2, rope.tail.get(1).unwrap().x);
     }
 
     #[test]
     fn part1_sample() {
-        assert_eq!(13, part1("sample.txt"));
+        assert_eq!(13, Day::solve(Part::One, &read("sample.txt")));
     }
 
     #[test]
     fn part2_sample() {
-        assert_eq!(1, part2("sample.txt"));
+        assert_eq!(1, Day::solve(Part::Two, &read("sample.txt")));
     }
 
     #[test]
     fn part2_sample2() {
-        assert_eq!(36, part2("sample2.txt"));
+        assert_eq!(36, Day::solve(Part::Two, &read("sample2.txt")));
     }
 }
@@ -1,16 +1,17 @@
+use aoc_core::{Answer, Solution};
+use aoc_grid::{read_letters, Grid};
 use std::fmt;
 use std::fmt::{Display, Formatter};
-use std::fs::read_to_string;
 use std::str::FromStr;
 
-#[derive(PartialEq, Debug)]
+#[derive(PartialEq, Debug, Clone)]
 enum InstructionType {
     Noop,
     Addx(i64),
 }
 
-#[derive(Debug)]
-struct Instruction {
+#[derive(Debug, Clone)]
+pub struct Instruction {
     instr_type: InstructionType,
     cycles: usize,
 }
@@ -101,6 +102,15 @@ impl Cpu {
         self.instr_cycle = 0;
     }
 
+    fn screen(&self) -> Grid<bool> {
+        Grid::from(
+            self.crt
+                .chunks(40)
+                .map(<[bool]>::to_vec)
+                .collect::<Vec<_>>(),
+        )
+    }
+
     fn signal_strength(&self) -> i64 {
         if (self.cycle as i64 - 20) % 40 == 0 {
             self.cycle as i64 * self.register
@@ -110,55 +120,56 @@ impl Cpu {
     }
 }
 
-pub fn part1(input: &str) -> i64 {
-    let program: Vec<Instruction> = read_to_string(input)
-        .unwrap()
-        .lines()
-        .map(str::parse)
-        .filter_map(Result::ok)
-        .collect();
-
-    let mut cpu = Cpu::new();
-    let mut signal_strength = 0;
-    for instr in program {
-        cpu.set_instr(instr);
-        loop {
-            let (more_ticks, current_signal_strength) = cpu.tick();
-            signal_strength += current_signal_strength;
-            if !more_ticks {
-                break;
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str) -> Self::Input {
+        input
+            .lines()
+            .map(str::parse)
+            .filter_map(Result::ok)
+            .collect()
+    }
+
+    fn part1(program: &Self::Input) -> Answer {
+        let mut cpu = Cpu::new();
+        let mut signal_strength = 0;
+        for instr in program {
+            cpu.set_instr(instr.clone());
+            loop {
+                let (more_ticks, current_signal_strength) = cpu.tick();
+                signal_strength += current_signal_strength;
+                if !more_ticks {
+                    break;
+                }
             }
         }
-    }
 
-    signal_strength
-}
+        signal_strength.into()
+    }
 
-pub fn part2(input: &str) -> String {
-    let program: Vec<Instruction> = read_to_string(input)
-        .unwrap()
-        .lines()
-        .map(str::parse)
-        .filter_map(Result::ok)
-        .collect();
-
-    let mut cpu = Cpu::new();
-    for instr in program {
-        cpu.set_instr(instr);
-        loop {
-            let (more_ticks, _) = cpu.tick();
-            if !more_ticks {
-                break;
+    fn part2(program: &Self::Input) -> Answer {
+        let mut cpu = Cpu::new();
+        for instr in program {
+            cpu.set_instr(instr.clone());
+            loop {
+                let (more_ticks, _) = cpu.tick();
+                if !more_ticks {
+                    break;
+                }
             }
         }
-    }
 
-    format!("{cpu}")
+        read_letters(&cpu.screen()).map_or_else(|| Answer::Art(format!("{cpu}")), Answer::from)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_core::{read, Part};
 
     #[test]
     fn parsing() {
@@ -203,7 +214,7 @@ mod tests {
 
     #[test]
     fn part1_sample() {
-        assert_eq!(13140, part1("sample.txt"));
+        assert_eq!(13140, Day::solve(Part::One, &read("sample.txt")));
     }
 
     #[test]
@@ -215,6 +226,11 @@ mod tests {
 ######......######......######......####
 #######.......#######.......#######.....";
 
-        assert_eq!(expected, part2("sample.txt"));
+        assert_eq!(expected, Day::solve(Part::Two, &read("sample.txt")));
+    }
+
+    #[test]
+    fn part2_input() {
+        assert_eq!("EGLHBLFJ", Day::solve(Part::Two, &read("input.txt")));
     }
 }
@@ -1,28 +1,22 @@
-use crate::solution::{part1, part2};
-use std::time::Instant;
+use std::env;
 
-mod solution;
-
-#[derive(Debug)]
-enum Part {
-    One,
-    Two,
-}
-
-fn solve_measure_and_print(part: Part, input: &str) {
-    let solver = match part {
-        Part::One => part1,
-        Part::Two => part2,
-    };
-    let start = Instant::now();
-    let result = solver(input);
-    let micros = start.elapsed().as_micros();
-    println!("Part {part:?}: {result} ({micros}µs)");
-}
+use aoc_core::read;
+use y2022_d11::solution::{parse, Barrel, Day, Plain};
 
 fn main() {
-    const NAME: &str = env!("CARGO_PKG_NAME");
-    println!("{NAME}:");
-    solve_measure_and_print(Part::One, "input.txt");
-    solve_measure_and_print(Part::Two, "input.txt");
+    match env::args().nth(1) {
+        // Show the items after each of the first rounds instead.
+        Some(input) => {
+            let monkeys = parse(&read(&input));
+            let mut barrel = Barrel::new(&monkeys, Plain, 3);
+            for round in 1..=20 {
+                barrel.round();
+                println!(
+                    "After round {round}, the monkeys are holding items with these worry levels:"
+                );
+                println!("{barrel}");
+            }
+        }
+        None => aoc_core::solve_measure_and_print::<Day>(env!("CARGO_PKG_NAME"), "input.txt"),
+    }
 }
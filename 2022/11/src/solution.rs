@@ -1,87 +1,329 @@
+use aoc_core::{Answer, Solution};
 use scan_fmt::scan_fmt;
 use std::collections::VecDeque;
-use std::fmt::{Debug, Formatter};
-use std::fs::read_to_string;
+use std::fmt::{self, Debug, Display, Formatter};
 use std::str::FromStr;
 
-#[derive(Debug)]
-struct Barrel {
-    monkeys: Vec<Monkey>,
+/// The numbers worry levels are kept as while the monkeys play.
+pub trait Worry {
+    type Level: Copy + Display;
+
+    fn level(&self, value: i64) -> Self::Level;
+    fn add(&self, a: Self::Level, b: Self::Level) -> Self::Level;
+    fn multiply(&self, a: Self::Level, b: Self::Level) -> Self::Level;
+    fn divide(&self, level: Self::Level, by: i64) -> Self::Level;
+    fn is_divisible(&self, level: Self::Level, divider: i64) -> bool;
 }
 
-impl Barrel {
-    fn do_throws(&mut self) {
-        for m in 0..self.monkeys.len() {
-            for (target, item) in self.do_monkey(m) {
-                self.monkeys[target].items.push_back(item);
+/// Plain `i64` worry levels, panicking when they grow too large.
+#[derive(Debug, Clone, Copy)]
+pub struct Plain;
+
+impl Worry for Plain {
+    type Level = i64;
+
+    fn level(&self, value: i64) -> i64 {
+        value
+    }
+
+    fn add(&self, a: i64, b: i64) -> i64 {
+        a.checked_add(b).expect("worry level overflow")
+    }
+
+    fn multiply(&self, a: i64, b: i64) -> i64 {
+        a.checked_mul(b).expect("worry level overflow")
+    }
+
+    fn divide(&self, level: i64, by: i64) -> i64 {
+        level / by
+    }
+
+    fn is_divisible(&self, level: i64, divider: i64) -> bool {
+        level % divider == 0
+    }
+}
+
+/// Worry levels modulo a multiple of every divider, which keeps them small
+/// without changing where any item is thrown. They can't be divided.
+#[derive(Debug, Clone, Copy)]
+pub struct Modular(pub i64);
+
+impl Modular {
+    /// The domain modulo the product of the monkeys' dividers.
+    pub fn for_monkeys(monkeys: &[Monkey]) -> Modular {
+        Modular(monkeys.iter().map(|m| m.divider).product())
+    }
+}
+
+impl Worry for Modular {
+    type Level = i64;
+
+    fn level(&self, value: i64) -> i64 {
+        value.rem_euclid(self.0)
+    }
+
+    // Widened, so any modulus works.
+    fn add(&self, a: i64, b: i64) -> i64 {
+        ((a as i128 + b as i128) % self.0 as i128) as i64
+    }
+
+    fn multiply(&self, a: i64, b: i64) -> i64 {
+        (a as i128 * b as i128 % self.0 as i128) as i64
+    }
+
+    fn divide(&self, level: i64, by: i64) -> i64 {
+        assert_eq!(1, by, "worry levels modulo {} can't be divided", self.0);
+        level
+    }
+
+    fn is_divisible(&self, level: i64, divider: i64) -> bool {
+        assert_eq!(0, self.0 % divider, "{divider} doesn't divide {}", self.0);
+        level % divider == 0
+    }
+}
+
+/// `u128` worry levels, panicking when even they grow too large.
+#[derive(Debug, Clone, Copy)]
+pub struct Wide;
+
+impl Worry for Wide {
+    type Level = u128;
+
+    fn level(&self, value: i64) -> u128 {
+        value.try_into().expect("a worry level can't be negative")
+    }
+
+    fn add(&self, a: u128, b: u128) -> u128 {
+        a.checked_add(b).expect("worry level overflow")
+    }
+
+    fn multiply(&self, a: u128, b: u128) -> u128 {
+        a.checked_mul(b).expect("worry level overflow")
+    }
+
+    fn divide(&self, level: u128, by: i64) -> u128 {
+        level / self.level(by)
+    }
+
+    fn is_divisible(&self, level: u128, divider: i64) -> bool {
+        level.is_multiple_of(self.level(divider))
+    }
+}
+
+/// How a monkey changes the worry level of an item it inspects.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Operation {
+    Old,
+    Value(i64),
+    Add(Box<Operation>, Box<Operation>),
+    Multiply(Box<Operation>, Box<Operation>),
+}
+
+impl Operation {
+    pub fn apply<W: Worry>(&self, worry: &W, old: W::Level) -> W::Level {
+        match self {
+            Operation::Old => old,
+            Operation::Value(value) => worry.level(*value),
+            Operation::Add(a, b) => worry.add(a.apply(worry, old), b.apply(worry, old)),
+            Operation::Multiply(a, b) => worry.multiply(a.apply(worry, old), b.apply(worry, old)),
+        }
+    }
+}
+
+impl Display for Operation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Operation::Old => write!(f, "old"),
+            Operation::Value(value) => write!(f, "{value}"),
+            Operation::Add(a, b) => write!(f, "{a} + {b}"),
+            Operation::Multiply(a, b) => {
+                for (i, factor) in [a, b].into_iter().enumerate() {
+                    if i > 0 {
+                        write!(f, " * ")?;
+                    }
+                    if let Operation::Add(..) = **factor {
+                        write!(f, "({factor})")?;
+                    } else {
+                        write!(f, "{factor}")?;
+                    }
+                }
+                Ok(())
             }
         }
     }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseOperationError {
+    position: usize,
+    expected: &'static str,
+}
+
+impl Display for ParseOperationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "expected {} at byte {}", self.expected, self.position)
+    }
+}
 
-    fn do_monkey(&mut self, m: usize) -> Vec<(usize, i64)> {
-        let mut throws = vec![];
-        let monkey = self.monkeys.get_mut(m).unwrap();
-        while let Some(throw) = monkey.throw_one() {
-            throws.push(throw);
+impl std::error::Error for ParseOperationError {}
+
+/// Reads the right hand side of `new = ...`: sums of products of `old`
+/// and integers, with the words separated by spaces.
+impl FromStr for Operation {
+    type Err = ParseOperationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut start = 0;
+        let words = s
+            .split(' ')
+            .map(|word| {
+                let position = start;
+                start += word.len() + 1;
+                (position, word)
+            })
+            .collect();
+        let mut parser = Parser {
+            words,
+            next: 0,
+            end: s.len(),
+        };
+        let operation = parser.sum()?;
+        if parser.next < parser.words.len() {
+            return Err(parser.error("'+' or '*'"));
         }
-        throws
+        Ok(operation)
     }
+}
 
-    fn monkey_business(&self) -> usize {
-        let mut monkeys = self
-            .monkeys
-            .iter()
-            .map(|m| m.inspections)
-            .collect::<Vec<usize>>();
-        monkeys.sort();
-        monkeys[monkeys.len() - 1] * monkeys[monkeys.len() - 2]
+struct Parser<'a> {
+    /// Each word, with its position.
+    words: Vec<(usize, &'a str)>,
+    next: usize,
+    end: usize,
+}
+
+impl Parser<'_> {
+    fn error(&self, expected: &'static str) -> ParseOperationError {
+        ParseOperationError {
+            position: self.words.get(self.next).map_or(self.end, |&(at, _)| at),
+            expected,
+        }
+    }
+
+    fn next_is(&mut self, word: &str) -> bool {
+        let found = self.words.get(self.next).is_some_and(|&(_, w)| w == word);
+        if found {
+            self.next += 1;
+        }
+        found
+    }
+
+    fn sum(&mut self) -> Result<Operation, ParseOperationError> {
+        let mut sum = self.product()?;
+        while self.next_is("+") {
+            sum = Operation::Add(Box::new(sum), Box::new(self.product()?));
+        }
+        Ok(sum)
+    }
+
+    fn product(&mut self) -> Result<Operation, ParseOperationError> {
+        let mut product = self.factor()?;
+        while self.next_is("*") {
+            product = Operation::Multiply(Box::new(product), Box::new(self.factor()?));
+        }
+        Ok(product)
+    }
+
+    fn factor(&mut self) -> Result<Operation, ParseOperationError> {
+        let factor = match self.words.get(self.next) {
+            Some((_, "old")) => Operation::Old,
+            Some((_, word)) => match word.parse() {
+                Ok(value) => Operation::Value(value),
+                Err(_) => return Err(self.error("'old' or an integer")),
+            },
+            None => return Err(self.error("'old' or an integer")),
+        };
+        self.next += 1;
+        Ok(factor)
     }
 }
 
-struct Monkey {
-    items: VecDeque<i64>,
-    operation: Box<dyn Fn(i64) -> i64>,
-    divider: i64,
-    target_true: usize,
-    target_false: usize,
-    inspections: usize,
-    relaxed: i64,
+/// The monkeys playing keep-away, with the items they hold and how often
+/// each has inspected one.
+pub struct Barrel<'a, W: Worry> {
+    monkeys: &'a [Monkey],
+    worry: W,
+    /// What worry levels are divided by after each inspection.
+    relief: i64,
+    items: Vec<VecDeque<W::Level>>,
+    inspections: Vec<usize>,
 }
 
-impl Monkey {
-    fn parse_operation(input: &str) -> Box<dyn Fn(i64) -> i64> {
-        if let Ok(x) = scan_fmt!(input, "old + {d}", i64) {
-            Box::new(move |old| old + x)
-        } else if let Ok(x) = scan_fmt!(input, "old * {d}", i64) {
-            Box::new(move |old| old * x)
-        } else if input == "old * old" {
-            Box::new(move |old| old * old)
-        } else {
-            panic!("Unsupported operation: {input}");
+impl<'a, W: Worry> Barrel<'a, W> {
+    pub fn new(monkeys: &'a [Monkey], worry: W, relief: i64) -> Self {
+        let items = monkeys
+            .iter()
+            .map(|m| m.items.iter().map(|&item| worry.level(item)).collect())
+            .collect();
+        Barrel {
+            monkeys,
+            worry,
+            relief,
+            items,
+            inspections: vec![0; monkeys.len()],
         }
     }
 
-    fn throw_one(&mut self) -> Option<(usize, i64)> {
-        if let Some(item) = self.items.pop_front() {
-            self.inspections += 1;
-            let item = (self.operation)(item);
-            let item = if self.relaxed == 3 {
-                item / self.relaxed
-            } else {
-                item % self.relaxed
-            };
-            let target = if item % self.divider == 0 {
-                self.target_true
-            } else {
-                self.target_false
-            };
-            Some((target, item))
+    /// Lets every monkey in turn throw all its items.
+    pub fn round(&mut self) {
+        for m in 0..self.monkeys.len() {
+            while let Some((target, item)) = self.throw_one(m) {
+                self.items[target].push_back(item);
+            }
+        }
+    }
+
+    fn throw_one(&mut self, m: usize) -> Option<(usize, W::Level)> {
+        let item = self.items[m].pop_front()?;
+        let monkey = &self.monkeys[m];
+        self.inspections[m] += 1;
+        let item = monkey.operation.apply(&self.worry, item);
+        let item = self.worry.divide(item, self.relief);
+        let target = if self.worry.is_divisible(item, monkey.divider) {
+            monkey.target_true
         } else {
-            None
+            monkey.target_false
+        };
+        Some((target, item))
+    }
+
+    fn monkey_business(&self) -> usize {
+        let mut inspections = self.inspections.clone();
+        inspections.sort();
+        inspections[inspections.len() - 1] * inspections[inspections.len() - 2]
+    }
+}
+
+/// The items each monkey holds, as the puzzle lists them after a round.
+impl<W: Worry> Display for Barrel<'_, W> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (m, items) in self.items.iter().enumerate() {
+            let items: Vec<String> = items.iter().map(ToString::to_string).collect();
+            writeln!(f, "Monkey {m}: {}", items.join(", "))?;
         }
+        Ok(())
     }
 }
 
+#[derive(Debug, Clone)]
+pub struct Monkey {
+    items: Vec<i64>,
+    operation: Operation,
+    divider: i64,
+    target_true: usize,
+    target_false: usize,
+}
+
 impl FromStr for Monkey {
     type Err = ();
 
@@ -97,13 +339,13 @@ impl FromStr for Monkey {
             .map(str::parse)
             .map(Result::unwrap)
             .collect();
-        let operation = Self::parse_operation(
-            lines
-                .next()
-                .unwrap()
-                .strip_prefix("  Operation: new = ")
-                .unwrap(),
-        );
+        let operation = lines
+            .next()
+            .unwrap()
+            .strip_prefix("  Operation: new = ")
+            .unwrap()
+            .parse()
+            .unwrap_or_else(|e| panic!("{e}"));
         let divider = scan_fmt!(lines.next().unwrap(), "Test: divisible by {d}", i64).unwrap();
         let target_true = scan_fmt!(
             lines.next().unwrap(),
@@ -124,52 +366,43 @@ impl FromStr for Monkey {
             divider,
             target_true,
             target_false,
-            inspections: 0,
-            relaxed: 3,
         })
     }
 }
 
-impl Debug for Monkey {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{:?}", self.items)
-    }
-}
+pub struct Day;
 
-pub fn part1(input: &str) -> usize {
-    let mut barrel = Barrel {
-        monkeys: parse(&read_to_string(input).unwrap()),
-    };
+impl Solution for Day {
+    type Input = Vec<Monkey>;
 
-    for _ in 0..20 {
-        barrel.do_throws();
+    fn parse(input: &str) -> Self::Input {
+        parse(input)
     }
 
-    barrel.monkey_business()
-}
-
-pub fn part2(input: &str) -> usize {
-    let mut barrel = Barrel {
-        monkeys: parse(&read_to_string(input).unwrap()),
-    };
+    fn part1(monkeys: &Self::Input) -> Answer {
+        let mut barrel = Barrel::new(monkeys, Plain, 3);
 
-    let product: i64 = barrel.monkeys.iter().map(|m| m.divider).product();
+        for _ in 0..20 {
+            barrel.round();
+        }
 
-    for monkey in &mut barrel.monkeys {
-        monkey.relaxed = product;
+        barrel.monkey_business().into()
     }
 
-    for _ in 0..10000 {
-        barrel.do_throws();
-    }
+    fn part2(monkeys: &Self::Input) -> Answer {
+        let mut barrel = Barrel::new(monkeys, Modular::for_monkeys(monkeys), 1);
 
-    barrel.monkey_business()
+        for _ in 0..10000 {
+            barrel.round();
+        }
+
+        barrel.monkey_business().into()
+    }
 }
 
-fn parse(input: &str) -> Vec<Monkey> {
+pub fn parse(input: &str) -> Vec<Monkey> {
     input
         .split("\n\n")
-        .into_iter()
         .map(str::parse)
         .map(Result::unwrap)
         .collect()
@@ -178,91 +411,155 @@ fn parse(input: &str) -> Vec<Monkey> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_core::{read, Part};
+    use test_case::test_case;
+
+    fn operation(s: &str) -> Operation {
+        s.parse().unwrap()
+    }
 
     #[test]
     fn parsing_sample() {
-        let monkeys = parse(&read_to_string("sample.txt").unwrap());
+        let monkeys = parse(&read("sample.txt"));
         assert_eq!(4, monkeys.len());
 
         let monkey = monkeys.first().unwrap();
-        assert_eq!(vec![79, 98], monkey.items.clone().make_contiguous());
-        assert_eq!(19, (monkey.operation)(1));
+        assert_eq!(vec![79, 98], monkey.items);
+        assert_eq!(19, monkey.operation.apply(&Plain, 1));
+        assert_eq!("old * 19", monkey.operation.to_string());
         assert_eq!(23, monkey.divider);
         assert_eq!(2, monkey.target_true);
         assert_eq!(3, monkey.target_false);
 
         let monkey = monkeys.last().unwrap();
-        assert_eq!(vec![74], monkey.items.clone().make_contiguous());
-        assert_eq!(4, (monkey.operation)(1));
+        assert_eq!(vec![74], monkey.items);
+        assert_eq!(4, monkey.operation.apply(&Plain, 1));
+        assert_eq!("old + 3", monkey.operation.to_string());
         assert_eq!(17, monkey.divider);
         assert_eq!(0, monkey.target_true);
         assert_eq!(1, monkey.target_false);
     }
 
+    #[test_case("old * old" => 49; "square")]
+    #[test_case("3 + old" => 10; "constant first")]
+    #[test_case("old + old * 2" => 21; "products first")]
+    #[test_case("old * 2 + 1 * old" => 21; "sum of products")]
+    #[test_case("5" => 5; "constant")]
+    fn applying(s: &str) -> i64 {
+        let operation = operation(s);
+        assert_eq!(s, operation.to_string());
+        assert_eq!(
+            operation.apply(&Plain, 7) as u128,
+            operation.apply(&Wide, 7)
+        );
+        assert_eq!(
+            operation.apply(&Plain, 7) % 4,
+            operation.apply(&Modular(4), 7)
+        );
+
+        operation.apply(&Plain, 7)
+    }
+
+    #[test]
+    fn large_moduli() {
+        let worry = Modular(i64::MAX);
+        let level = worry.level(i64::MAX - 1);
+        assert_eq!(1, worry.multiply(level, level));
+        assert_eq!(i64::MAX - 2, worry.add(level, level));
+    }
+
+    #[test]
+    fn displaying_nested_sums() {
+        let sum = operation("old + 1");
+        let product = Operation::Multiply(Box::new(sum.clone()), Box::new(sum));
+        assert_eq!("(old + 1) * (old + 1)", product.to_string());
+    }
+
+    #[test_case("" => (0, "'old' or an integer"); "empty")]
+    #[test_case("old +" => (5, "'old' or an integer"); "missing operand")]
+    #[test_case("old - 3" => (4, "'+' or '*'"); "subtraction")]
+    #[test_case("old * new" => (6, "'old' or an integer"); "unknown")]
+    #[test_case("old  * 3" => (4, "'+' or '*'"); "double space")]
+    fn parse_errors(s: &str) -> (usize, &'static str) {
+        let error = s.parse::<Operation>().unwrap_err();
+        (error.position, error.expected)
+    }
+
     #[test]
     fn monkey_business() {
-        let barrel = Barrel {
-            monkeys: vec![
-                Monkey {
-                    items: VecDeque::new(),
-                    operation: Box::new(|x| x),
-                    divider: 0,
-                    target_true: 0,
-                    target_false: 0,
-                    inspections: 20,
-                    relaxed: 3,
-                },
-                Monkey {
-                    items: VecDeque::new(),
-                    operation: Box::new(|x| x),
-                    divider: 0,
-                    target_true: 0,
-                    target_false: 0,
-                    inspections: 10,
-                    relaxed: 3,
-                },
-                Monkey {
-                    items: VecDeque::new(),
-                    operation: Box::new(|x| x),
-                    divider: 0,
-                    target_true: 0,
-                    target_false: 0,
-                    inspections: 30,
-                    relaxed: 3,
-                },
-            ],
-        };
+        let mut barrel = Barrel::new(&[], Plain, 3);
+        barrel.inspections = vec![20, 10, 30];
 
         assert_eq!(600, barrel.monkey_business());
     }
 
     #[test]
     fn throw_one() {
-        let mut monkey = Monkey {
-            items: VecDeque::from(vec![1]),
-            operation: Box::new(|x| x * 4),
+        let monkeys = [Monkey {
+            items: vec![1],
+            operation: operation("old * 4"),
             divider: 1,
             target_true: 42,
             target_false: 0,
-            inspections: 0,
-            relaxed: 3,
-        };
+        }];
+        let mut barrel = Barrel::new(&monkeys, Plain, 3);
 
-        let (target, item) = monkey.throw_one().unwrap();
+        let (target, item) = barrel.throw_one(0).unwrap();
 
         assert_eq!(42, target);
         assert_eq!(1, item);
-        assert_eq!(1, monkey.inspections);
-        assert!(monkey.items.is_empty())
+        assert_eq!(vec![1], barrel.inspections);
+        assert!(barrel.items[0].is_empty())
+    }
+
+    #[test]
+    fn tracing_rounds() {
+        let monkeys = parse(&read("sample.txt"));
+        let mut barrel = Barrel::new(&monkeys, Plain, 3);
+
+        barrel.round();
+        assert_eq!(
+            "Monkey 0: 20, 23, 27, 26\n\
+             Monkey 1: 2080, 25, 167, 207, 401, 1046\n\
+             Monkey 2: \n\
+             Monkey 3: \n",
+            barrel.to_string()
+        );
+    }
+
+    #[test]
+    fn worry_domains_agree() {
+        let monkeys = parse(&read("sample.txt"));
+        let mut plain = Barrel::new(&monkeys, Plain, 3);
+        let mut wide = Barrel::new(&monkeys, Wide, 3);
+        for _ in 0..20 {
+            plain.round();
+            wide.round();
+        }
+        assert_eq!(plain.to_string(), wide.to_string());
+
+        let mut modular = Barrel::new(&monkeys, Modular::for_monkeys(&monkeys), 1);
+        let mut wide = Barrel::new(&monkeys, Wide, 1);
+        for _ in 0..3 {
+            modular.round();
+            wide.round();
+        }
+        assert_eq!(modular.inspections, wide.inspections);
     }
 
     #[test]
     fn part1_sample() {
-        assert_eq!(10605, part1("sample.txt"));
+        assert_eq!(10605, Day::solve(Part::One, &read("sample.txt")));
     }
 
     #[test]
     fn part2_sample() {
-        assert_eq!(2713310158, part2("sample.txt"));
+        assert_eq!(2713310158, Day::solve(Part::Two, &read("sample.txt")));
+    }
+
+    #[test]
+    fn input() {
+        assert_eq!(316888, Day::solve(Part::One, &read("input.txt")));
+        assert_eq!(35270398814, Day::solve(Part::Two, &read("input.txt")));
     }
 }
@@ -1,110 +1,94 @@
-use ndarray::Array2;
+use aoc_core::{Answer, Solution};
+use aoc_grid::{Grid, Point};
 use pathfinding::prelude::bfs;
-use std::fs::read_to_string;
 
 #[derive(PartialEq, Debug)]
-struct Map {
-    heights: Array2<char>,
-    start: (usize, usize),
-    end: (usize, usize),
+pub struct Map {
+    heights: Grid<char>,
+    start: Point,
+    end: Point,
 }
 
 impl Map {
-    fn successors(&self, (row, col): &(usize, usize)) -> Vec<(usize, usize)> {
-        let mut s = vec![];
-        let nrows = self.heights.nrows();
-        let ncols = self.heights.ncols();
-        let cur_plus_one = char::from(self.heights[[*row, *col]] as u8 + 1);
-        if *row > 0 && cur_plus_one >= self.heights[[row - 1, *col]] {
-            s.push((*row - 1, *col))
-        }
-        if *col > 0 && cur_plus_one >= self.heights[[*row, *col - 1]] {
-            s.push((*row, *col - 1))
-        }
-        if *row < nrows - 1 && cur_plus_one >= self.heights[[*row + 1, *col]] {
-            s.push((*row + 1, *col))
-        }
-        if *col < ncols - 1 && cur_plus_one >= self.heights[[*row, *col + 1]] {
-            s.push((*row, *col + 1))
-        }
-        s
+    fn successors(&self, &pos: &Point) -> Vec<Point> {
+        let cur_plus_one = char::from(self.heights[pos] as u8 + 1);
+        self.heights
+            .neighbours4(pos)
+            .filter(|&next| cur_plus_one >= self.heights[next])
+            .collect()
     }
 
     fn from(input: &str) -> Map {
-        let map: Vec<Vec<char>> = input.lines().map(str::chars).map(|c| c.collect()).collect();
-        let nrows = map.len();
-        let ncols = map.first().unwrap().len();
-
-        let mut map: Vec<char> = map.into_iter().flatten().collect();
-        let start = map.iter().position(|c| *c == 'S').unwrap();
-        let end = map.iter().position(|c| *c == 'E').unwrap();
-        map[start] = 'a';
-        map[end] = 'z';
+        let mut heights = Grid::parse(input);
+        let start = heights.find(|&c| c == 'S').unwrap();
+        let end = heights.find(|&c| c == 'E').unwrap();
+        heights[start] = 'a';
+        heights[end] = 'z';
 
         Map {
-            heights: Array2::from_shape_vec((nrows, ncols), map).unwrap(),
-            start: (start / ncols, start % ncols),
-            end: (end / ncols, end % ncols),
+            heights,
+            start,
+            end,
         }
     }
 
-    fn with_start(&self, start: usize) -> Map {
-        let shape = self.heights.shape();
+    fn with_start(&self, start: Point) -> Map {
         Map {
             heights: self.heights.clone(),
-            start: (start / shape[1], start % shape[1]),
+            start,
             end: self.end,
         }
     }
 }
 
-pub fn part1(input: &str) -> usize {
-    let map = Map::from(&read_to_string(input).unwrap());
+pub struct Day;
 
-    let result = bfs(&map.start, |p| map.successors(p), |p| *p == map.end);
+impl Solution for Day {
+    type Input = Map;
 
-    result.unwrap().len() - 1
-}
+    fn parse(input: &str) -> Self::Input {
+        Map::from(input)
+    }
+
+    fn part1(map: &Self::Input) -> Answer {
+        let result = bfs(&map.start, |p| map.successors(p), |p| *p == map.end);
 
-pub fn part2(input: &str) -> usize {
-    let map = Map::from(&read_to_string(input).unwrap());
-
-    let starts = map
-        .heights
-        .iter()
-        .enumerate()
-        .filter(|(_, c)| **c == 'a')
-        .map(|(index, _)| index);
-
-    let mut best = usize::MAX;
-    for start in starts {
-        let newmap = map.with_start(start);
-        if let Some(result) = bfs(
-            &newmap.start,
-            |p| newmap.successors(p),
-            |p| *p == newmap.end,
-        ) {
-            let len = result.len() - 1;
-            if len < best {
-                best = len;
+        (result.unwrap().len() - 1).into()
+    }
+
+    fn part2(map: &Self::Input) -> Answer {
+        let starts = map.heights.find_all(|&c| c == 'a');
+
+        let mut best = usize::MAX;
+        for start in starts {
+            let newmap = map.with_start(start);
+            if let Some(result) = bfs(
+                &newmap.start,
+                |p| newmap.successors(p),
+                |p| *p == newmap.end,
+            ) {
+                let len = result.len() - 1;
+                if len < best {
+                    best = len;
+                }
             }
         }
-    }
 
-    best
+        best.into()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use ndarray::arr2;
+    use aoc_core::{read, Part};
 
     #[test]
     fn pathfinding() {
         let map = Map {
-            heights: arr2(&[['b', 'c'], ['e', 'd']]),
+            heights: Grid::from(vec![vec!['b', 'c'], vec!['e', 'd']]),
             start: (0, 0),
-            end: (1, 0),
+            end: (0, 1),
         };
 
         let result = bfs(&map.start, |p| map.successors(p), |p| *p == map.end);
@@ -115,8 +99,8 @@ mod tests {
     #[test]
     fn parsing() {
         let expected = Map {
-            heights: arr2(&[['z', 'b'], ['a', 'c']]),
-            start: (1, 0),
+            heights: Grid::from(vec![vec!['z', 'b'], vec!['a', 'c']]),
+            start: (0, 1),
             end: (0, 0),
         };
         let map = Map::from("Eb\nSc");
@@ -126,8 +110,8 @@ mod tests {
     #[test]
     fn parsing2() {
         let expected = Map {
-            heights: arr2(&[['a', 'a'], ['b', 'z']]),
-            start: (0, 1),
+            heights: Grid::from(vec![vec!['a', 'a'], vec!['b', 'z']]),
+            start: (1, 0),
             end: (1, 1),
         };
         let map = Map::from("aS\nbE");
@@ -137,8 +121,8 @@ mod tests {
     #[test]
     fn parsing3() {
         let expected = Map {
-            heights: arr2(&[['a', 'a', 'a'], ['b', 'z', 'a']]),
-            start: (1, 2),
+            heights: Grid::from(vec![vec!['a', 'a', 'a'], vec!['b', 'z', 'a']]),
+            start: (2, 1),
             end: (1, 1),
         };
         let map = Map::from("aaa\nbES");
@@ -147,11 +131,11 @@ mod tests {
 
     #[test]
     fn part1_sample() {
-        assert_eq!(31, part1("sample.txt"));
+        assert_eq!(31, Day::solve(Part::One, &read("sample.txt")));
     }
 
     #[test]
     fn part2_sample() {
-        assert_eq!(29, part2("sample.txt"));
+        assert_eq!(29, Day::solve(Part::Two, &read("sample.txt")));
     }
 }
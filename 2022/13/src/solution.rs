@@ -1,133 +1,264 @@
-use json::{parse, JsonValue};
+use aoc_core::{Answer, Solution};
 use std::cmp::Ordering;
-use std::fs::read_to_string;
-
-fn compare(left: &JsonValue, right: &JsonValue) -> Ordering {
-    assert!(left.is_array() || left.is_number());
-    assert!(right.is_array() || right.is_number());
-
-    if left.is_number() && right.is_number() {
-        return right.as_i64().unwrap().cmp(&left.as_i64().unwrap());
-    }
-
-    let left_in_array = JsonValue::Array(vec![left.clone()]);
-    let right_in_array = JsonValue::Array(vec![right.clone()]);
-    let left = if left.is_number() {
-        &left_in_array
-    } else {
-        left
-    };
-    let right = if right.is_number() {
-        &right_in_array
-    } else {
-        right
-    };
-
-    match (left, right) {
-        (left, right) if left.is_empty() && right.is_empty() => Ordering::Equal,
-        (empty, _) if empty.is_empty() => Ordering::Greater,
-        (_, empty) if empty.is_empty() => Ordering::Less,
-
-        (left, right) => {
-            let count = left.len().min(right.len());
-            for i in 0..count {
-                let left = &left[i];
-                let right = &right[i];
-                let result = compare(left, right);
-                if result != Ordering::Equal {
-                    return result;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+/// A packet, or a part of one. Packets compare the way the distress signal
+/// wants them ordered, so an integer equals a list holding just that integer.
+#[derive(Debug, Clone)]
+pub enum Packet {
+    Int(u32),
+    List(Vec<Packet>),
+}
+
+impl Ord for Packet {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Packet::Int(left), Packet::Int(right)) => left.cmp(right),
+            (Packet::List(left), Packet::List(right)) => left.cmp(right),
+            (Packet::Int(_), Packet::List(right)) => std::slice::from_ref(self).cmp(right),
+            (Packet::List(left), Packet::Int(_)) => {
+                left.as_slice().cmp(std::slice::from_ref(other))
+            }
+        }
+    }
+}
+
+impl PartialOrd for Packet {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Packet {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Packet {}
+
+impl Display for Packet {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Packet::Int(value) => write!(f, "{value}"),
+            Packet::List(packets) => {
+                write!(f, "[")?;
+                for (i, packet) in packets.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{packet}")?;
                 }
+                write!(f, "]")
             }
-            right.len().cmp(&left.len())
         }
     }
 }
 
-fn order_ok(left: &str, right: &str) -> bool {
-    Ordering::Greater == compare(&parse(left).unwrap(), &parse(right).unwrap())
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsePacketError {
+    position: usize,
+    expected: &'static str,
+}
+
+impl Display for ParsePacketError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "expected {} at byte {}", self.expected, self.position)
+    }
 }
 
-pub fn part1(input: &str) -> usize {
-    read_to_string(input)
-        .unwrap()
-        .split("\n\n")
-        .enumerate()
-        .map(|(index, pair)| (index + 1, pair.split('\n')))
-        .filter_map(|(index, mut s)| {
-            if order_ok(s.next().unwrap(), s.next().unwrap()) {
-                Some(index)
-            } else {
-                None
+impl std::error::Error for ParsePacketError {}
+
+impl FromStr for Packet {
+    type Err = ParsePacketError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser {
+            bytes: s.as_bytes(),
+            position: 0,
+        };
+        let packet = parser.packet()?;
+        if parser.position < s.len() {
+            return Err(parser.error("the end of the packet"));
+        }
+        Ok(packet)
+    }
+}
+
+struct Parser<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl Parser<'_> {
+    fn error(&self, expected: &'static str) -> ParsePacketError {
+        ParsePacketError {
+            position: self.position,
+            expected,
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.position).copied()
+    }
+
+    fn packet(&mut self) -> Result<Packet, ParsePacketError> {
+        match self.peek() {
+            Some(b'[') => self.list(),
+            Some(c) if c.is_ascii_digit() => self.int(),
+            _ => Err(self.error("an integer or '['")),
+        }
+    }
+
+    fn int(&mut self) -> Result<Packet, ParsePacketError> {
+        let start = self.position;
+        while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+            self.position += 1;
+        }
+        std::str::from_utf8(&self.bytes[start..self.position])
+            .unwrap()
+            .parse()
+            .map(Packet::Int)
+            .map_err(|_| ParsePacketError {
+                position: start,
+                expected: "a smaller integer",
+            })
+    }
+
+    fn list(&mut self) -> Result<Packet, ParsePacketError> {
+        self.position += 1;
+        let mut packets = Vec::new();
+        if self.peek() == Some(b']') {
+            self.position += 1;
+            return Ok(Packet::List(packets));
+        }
+        loop {
+            packets.push(self.packet()?);
+            match self.peek() {
+                Some(b',') => self.position += 1,
+                Some(b']') => {
+                    self.position += 1;
+                    return Ok(Packet::List(packets));
+                }
+                _ => return Err(self.error("',' or ']'")),
             }
-        })
-        .sum()
+        }
+    }
 }
 
-pub fn part2(input: &str) -> usize {
-    let mut packets: Vec<JsonValue> = read_to_string(input)
-        .unwrap()
-        .lines()
-        .filter(|s| !s.is_empty())
-        .map(|s| parse(s).unwrap())
-        .collect();
+pub struct Day;
 
-    packets.sort_by(compare);
-    packets.reverse();
+impl Solution for Day {
+    type Input = Vec<Packet>;
 
-    let first = parse("[[2]]").unwrap();
-    let second = parse("[[6]]").unwrap();
+    fn parse(input: &str) -> Self::Input {
+        input
+            .lines()
+            .filter(|s| !s.is_empty())
+            .map(|s| s.parse().unwrap_or_else(|e| panic!("{s}: {e}")))
+            .collect()
+    }
+
+    fn part1(packets: &Self::Input) -> Answer {
+        packets
+            .chunks(2)
+            .enumerate()
+            .filter(|(_, pair)| pair[0] < pair[1])
+            .map(|(index, _)| index + 1)
+            .sum::<usize>()
+            .into()
+    }
+
+    fn part2(packets: &Self::Input) -> Answer {
+        let dividers = ["[[2]]", "[[6]]"].map(|divider| divider.parse::<Packet>().unwrap());
 
-    let first_index = packets
-        .binary_search_by(|i| compare(&first, i))
-        .unwrap_err();
-    let second_index = packets
-        .binary_search_by(|i| compare(&second, i))
-        .unwrap_err();
+        // Tagged, as a packet can compare equal to a divider without being one.
+        let mut packets: Vec<(Packet, bool)> = packets
+            .iter()
+            .cloned()
+            .map(|packet| (packet, false))
+            .chain(dividers.map(|divider| (divider, true)))
+            .collect();
+        packets.sort();
 
-    (first_index.min(second_index) + 1) * (first_index.max(second_index) + 2)
+        packets
+            .iter()
+            .enumerate()
+            .filter(|(_, (_, divider))| *divider)
+            .map(|(index, _)| index + 1)
+            .product::<usize>()
+            .into()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_core::{read, Part};
     use test_case::test_case;
 
-    #[test]
-    fn json_parsing() {
-        let lists = json::parse("[1,[2,[]]]").unwrap();
+    fn packet(s: &str) -> Packet {
+        s.parse().unwrap()
+    }
 
-        dbg!(lists);
+    #[test_case("[1,[2,[]]]"; "nested")]
+    #[test_case("[]"; "empty")]
+    #[test_case("10"; "integer")]
+    #[test_case("[[10],[[]],4,[1,[2,[3,[4,[5,6,0]]]],8,9]]"; "deep")]
+    fn round_trip(s: &str) {
+        assert_eq!(s, packet(s).to_string());
+    }
+
+    #[test_case("" => (0, "an integer or '['"); "empty")]
+    #[test_case("[1,2" => (4, "',' or ']'"); "unclosed")]
+    #[test_case("[1,,2]" => (3, "an integer or '['"); "missing element")]
+    #[test_case("[1;2]" => (2, "',' or ']'"); "separator")]
+    #[test_case("[1]]" => (3, "the end of the packet"); "trailing")]
+    #[test_case("[99999999999]" => (1, "a smaller integer"); "overflow")]
+    fn parse_errors(s: &str) -> (usize, &'static str) {
+        let error = s.parse::<Packet>().unwrap_err();
+        (error.position, error.expected)
     }
 
     #[test_case("[1]", "[1]" => Ordering::Equal; "ones")]
-    #[test_case("[1,1,3,1,1]", "[1,1,5,1,1]" => Ordering::Greater; "s1")]
-    #[test_case("[[1],[2,3,4]]", "[[1],4]" => Ordering::Greater; "s2")]
-    #[test_case("[9]", "[[8,7,6]]" => Ordering::Less; "s3")]
-    #[test_case("[[4,4],4,4]", "[[4,4],4,4,4]" => Ordering::Greater; "s4")]
-    #[test_case("[7,7,7,7]", "[7,7,7]" => Ordering::Less; "s5")]
-    #[test_case("[]", "[3]" => Ordering::Greater; "s6")]
-    #[test_case("[[[]]]", "[[]]" => Ordering::Less; "s7")]
-    #[test_case("[1,[2,[3,[4,[5,6,7]]]],8,9]", "[1,[2,[3,[4,[5,6,0]]]],8,9]" => Ordering::Less; "s8")]
+    #[test_case("[1,1,3,1,1]", "[1,1,5,1,1]" => Ordering::Less; "s1")]
+    #[test_case("[[1],[2,3,4]]", "[[1],4]" => Ordering::Less; "s2")]
+    #[test_case("[9]", "[[8,7,6]]" => Ordering::Greater; "s3")]
+    #[test_case("[[4,4],4,4]", "[[4,4],4,4,4]" => Ordering::Less; "s4")]
+    #[test_case("[7,7,7,7]", "[7,7,7]" => Ordering::Greater; "s5")]
+    #[test_case("[]", "[3]" => Ordering::Less; "s6")]
+    #[test_case("[[[]]]", "[[]]" => Ordering::Greater; "s7")]
+    #[test_case("[1,[2,[3,[4,[5,6,7]]]],8,9]", "[1,[2,[3,[4,[5,6,0]]]],8,9]" => Ordering::Greater; "s8")]
+    #[test_case("3", "[[3]]" => Ordering::Equal; "promoted twice")]
     fn comparing(left: &str, right: &str) -> Ordering {
-        let left = json::parse(left).unwrap();
-        let right = json::parse(right).unwrap();
+        let (left, right) = (packet(left), packet(right));
+        assert_eq!(left.cmp(&right).reverse(), right.cmp(&left));
 
-        compare(&left, &right)
+        left.cmp(&right)
     }
 
     #[test]
     fn ok_ordering() {
+        let order_ok = |left, right| packet(left) < packet(right);
+
         assert!(order_ok("[]", "1"));
         assert!(!order_ok("[2]", "1"));
     }
 
     #[test]
     fn part1_sample() {
-        assert_eq!(13, part1("sample.txt"));
+        assert_eq!(13, Day::solve(Part::One, &read("sample.txt")));
     }
 
     #[test]
     fn part2_sample() {
-        assert_eq!(140, part2("sample.txt"));
+        assert_eq!(140, Day::solve(Part::Two, &read("sample.txt")));
+    }
+
+    #[test]
+    fn input() {
+        assert_eq!(6070, Day::solve(Part::One, &read("input.txt")));
+        assert_eq!(20758, Day::solve(Part::Two, &read("input.txt")));
     }
 }
@@ -1,6 +1,6 @@
+use aoc_core::{Answer, Solution};
 use scan_fmt::scan_fmt;
 use std::collections::HashSet;
-use std::fs::read_to_string;
 use std::ops::RangeInclusive;
 
 type Pos = (i64, i64);
@@ -61,34 +61,34 @@ impl SandPile {
     }
 }
 
-pub fn part1(input: &str) -> usize {
-    let rocks = parse_input(input);
-    let mut sand_pile = SandPile::new(rocks, false);
+pub struct Day;
 
-    while let Some(resting_place) = sand_pile.drop((500, 0)) {
-        sand_pile.pile.insert(resting_place);
+impl Solution for Day {
+    type Input = Vec<Rock>;
+
+    fn parse(input: &str) -> Self::Input {
+        input.lines().flat_map(parse).collect()
     }
 
-    sand_pile.pile.len()
-}
+    fn part1(rocks: &Self::Input) -> Answer {
+        let mut sand_pile = SandPile::new(rocks.clone(), false);
 
-pub fn part2(input: &str) -> usize {
-    let rocks = parse_input(input);
-    let mut sand_pile = SandPile::new(rocks, true);
+        while let Some(resting_place) = sand_pile.drop((500, 0)) {
+            sand_pile.pile.insert(resting_place);
+        }
 
-    while let Some(resting_place) = sand_pile.drop((500, 0)) {
-        sand_pile.pile.insert(resting_place);
+        sand_pile.pile.len().into()
     }
 
-    sand_pile.pile.len() + 1 // the cherry on top also
-}
+    fn part2(rocks: &Self::Input) -> Answer {
+        let mut sand_pile = SandPile::new(rocks.clone(), true);
 
-fn parse_input(input: &str) -> Vec<Rock> {
-    read_to_string(input)
-        .unwrap()
-        .lines()
-        .flat_map(parse)
-        .collect()
+        while let Some(resting_place) = sand_pile.drop((500, 0)) {
+            sand_pile.pile.insert(resting_place);
+        }
+
+        (sand_pile.pile.len() + 1).into() // the cherry on top also
+    }
 }
 
 fn parse(input: &str) -> Vec<Rock> {
@@ -97,7 +97,7 @@ fn parse(input: &str) -> Vec<Rock> {
         .map(|s| scan_fmt!(s, "{d},{d}", i64, i64).unwrap())
         .collect::<Vec<Pos>>()
         .windows(2)
-        .map(|w| (range(w[0], w[1])))
+        .map(|w| range(w[0], w[1]))
         .collect()
 }
 
@@ -111,6 +111,7 @@ fn range(start: Pos, end: Pos) -> Rock {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_core::{read, Part};
 
     #[test]
     fn rock_parsing() {
@@ -151,11 +152,11 @@ mod tests {
 
     #[test]
     fn part1_sample() {
-        assert_eq!(24, part1("sample.txt"));
+        assert_eq!(24, Day::solve(Part::One, &read("sample.txt")));
     }
 
     #[test]
     fn part2_sample() {
-        assert_eq!(93, part2("sample.txt"));
+        assert_eq!(93, Day::solve(Part::Two, &read("sample.txt")));
     }
 }
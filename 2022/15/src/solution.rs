@@ -1,45 +1,50 @@
+use aoc_core::{Answer, Solution};
 use scan_fmt::scan_fmt;
 use std::collections::HashSet;
-use std::fs::read_to_string;
 
 type Pos = (i32, i32);
 
-pub fn part1(input: &str) -> usize {
-    part1_for_y(input, 2000000) as usize
-}
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<(Pos, Pos)>;
 
-pub fn part1_for_y(input: &str, y: i32) -> i32 {
-    let sensors = parse_sensors(input);
+    fn parse(input: &str) -> Self::Input {
+        input.lines().map(parse).collect()
+    }
+
+    fn part1(sensors: &Self::Input) -> Answer {
+        part1_for_y(sensors, 2000000).into()
+    }
 
+    fn part2(sensors: &Self::Input) -> Answer {
+        part2_for_grid(sensors, 4000000, 4000000).into()
+    }
+}
+
+pub fn part1_for_y(sensors: &[(Pos, Pos)], y: i32) -> i32 {
     let known_positions = sensors
         .iter()
         .flat_map(|(s, b)| vec![*s, *b])
         .collect::<HashSet<_>>();
 
     sensors
-        .into_iter()
-        .flat_map(|s| covered_on_y(s, y))
+        .iter()
+        .flat_map(|s| covered_on_y(*s, y))
         .filter(|p| !known_positions.contains(p))
         .collect::<HashSet<_>>()
         .len() as i32
 }
 
-pub fn part2(input: &str) -> usize {
-    part2_for_grid(input, 4000000, 4000000)
-}
-
-pub fn part2_for_grid(input: &str, maxx: i32, maxy: i32) -> usize {
-    let sensors = parse_sensors(input);
-
+pub fn part2_for_grid(sensors: &[(Pos, Pos)], maxx: i32, maxy: i32) -> usize {
     let known_positions = sensors
         .iter()
         .flat_map(|(s, b)| vec![*s, *b])
         .collect::<HashSet<_>>();
 
     let candidates = sensors
-        .clone()
-        .into_iter()
-        .flat_map(candidates)
+        .iter()
+        .flat_map(|s| candidates(*s))
         .filter(|(x, y)| *x >= 0 && *y >= 0 && *x <= maxx && *y <= maxy)
         .filter(|p| {
             sensors
@@ -68,6 +73,7 @@ fn covered_on_y(sensor: (Pos, Pos), y: i32) -> HashSet<Pos> {
         .collect()
 }
 
+#[allow(dead_code)]
 fn covered(sensor: (Pos, Pos)) -> HashSet<Pos> {
     let dist = manhattan(sensor.0, sensor.1);
 
@@ -113,13 +119,10 @@ fn parse(input: &str) -> (Pos, Pos) {
     ((sx, sy), (bx, by))
 }
 
-fn parse_sensors(input: &str) -> Vec<(Pos, Pos)> {
-    read_to_string(input).unwrap().lines().map(parse).collect()
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_core::read;
 
     #[test]
     fn overflowing() {
@@ -193,11 +196,14 @@ mod tests {
 
     #[test]
     fn part1_sample() {
-        assert_eq!(26, part1_for_y("sample.txt", 10));
+        assert_eq!(26, part1_for_y(&Day::parse(&read("sample.txt")), 10));
     }
 
     #[test]
     fn part2_sample() {
-        assert_eq!(56000011, part2_for_grid("sample.txt", 20, 20));
+        assert_eq!(
+            56000011,
+            part2_for_grid(&Day::parse(&read("sample.txt")), 20, 20)
+        );
     }
 }
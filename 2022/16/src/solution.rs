@@ -1,13 +1,13 @@
+use aoc_core::{Answer, Solution};
 use itertools::Itertools;
 use pathfinding::prelude::{dfs_reach, dijkstra_all, Matrix};
 use scan_fmt::scan_fmt;
 use std::collections::HashMap;
 use std::fmt::{Debug, Formatter};
-use std::fs::read_to_string;
 use std::hash::Hash;
 
 #[derive(Debug)]
-struct Cave {
+pub struct Cave {
     reachable: Matrix<usize>,
     flow_rates: Vec<usize>,
 }
@@ -144,6 +144,7 @@ fn int_to_name(name: usize, names: &[&str]) -> String {
     names[name].to_string()
 }
 
+#[allow(dead_code)]
 #[derive(Eq, PartialEq, Clone, Hash, Debug)]
 struct Worker {
     pos: usize,
@@ -172,35 +173,32 @@ impl BitField {
     }
 }
 
-pub fn part1(input: &str) -> usize {
-    let valves = read_to_string(input)
-        .unwrap()
-        .lines()
-        .map(Valve::from)
-        .collect();
+pub struct Day;
 
-    let time_available = 30;
-    let cave = Cave::new(valves);
+impl Solution for Day {
+    type Input = Cave;
 
-    cave.find_max_flow(time_available, 1)
-}
+    fn parse(input: &str) -> Self::Input {
+        Cave::new(input.lines().map(Valve::from).collect())
+    }
 
-pub fn part2(input: &str) -> usize {
-    let valves = read_to_string(input)
-        .unwrap()
-        .lines()
-        .map(Valve::from)
-        .collect();
+    fn part1(cave: &Self::Input) -> Answer {
+        let time_available = 30;
 
-    let time_available = 26;
-    let cave = Cave::new(valves);
+        cave.find_max_flow(time_available, 1).into()
+    }
+
+    fn part2(cave: &Self::Input) -> Answer {
+        let time_available = 26;
 
-    cave.find_max_flow(time_available, 2)
+        cave.find_max_flow(time_available, 2).into()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_core::{read, Part};
 
     #[test]
     fn simple() {
@@ -320,11 +318,11 @@ mod tests {
 
     #[test]
     fn part1_sample() {
-        assert_eq!(1651, part1("sample.txt"));
+        assert_eq!(1651, Day::solve(Part::One, &read("sample.txt")));
     }
 
     #[test]
     fn part2_sample() {
-        assert_eq!(1707, part2("sample.txt"));
+        assert_eq!(1707, Day::solve(Part::Two, &read("sample.txt")));
     }
 }
@@ -1,8 +1,10 @@
+use aoc_core::{read, Part, Solution};
 use criterion::{criterion_group, criterion_main, Criterion};
-use d17::solution::part1;
+use y2022_d17::solution::Day;
 
 pub fn criterion_benchmark(c: &mut Criterion) {
-    c.bench_function("part1", |b| b.iter(|| part1("input.txt")));
+    let input = read("input.txt");
+    c.bench_function("part1", |b| b.iter(|| Day::solve(Part::One, &input)));
 }
 
 criterion_group!(benches, criterion_benchmark);
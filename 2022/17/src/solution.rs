@@ -1,8 +1,7 @@
+use aoc_core::{Answer, CycleDetector, Solution};
 use core::fmt;
-use std::collections::{HashMap, VecDeque};
+use std::collections::VecDeque;
 use std::fmt::Formatter;
-use std::fs::read_to_string;
-use std::ops::Div;
 
 #[derive(Clone, Debug)]
 struct Block {
@@ -241,7 +240,9 @@ impl Pile {
 }
 
 fn drop(count: usize, jets: &str) -> usize {
-    let mut cache = HashMap::new();
+    let mut cycles = CycleDetector::new();
+    // the height of the pile after each block, until a cycle is found
+    let mut tops = vec![0];
     let mut looped = 0;
 
     let mut jets = jets.chars().enumerate().cycle();
@@ -256,14 +257,14 @@ fn drop(count: usize, jets: &str) -> usize {
             if dropped.is_blocked(&pile) {
                 pile.add(b);
                 if looped == 0 {
-                    if let Some(fp) = pile.fingerprint(source.index() as u8, jet_index as u8) {
-                        if let Some((counter, top)) = cache.get(&fp) {
-                            let loop_length = source.counter - counter;
-                            let loops = (count - source.counter).div(loop_length);
-                            looped = loops * (pile.top - top);
-                            source.counter += loops * loop_length;
-                        }
-                        cache.insert(fp, (source.counter, pile.top));
+                    tops.push(pile.top);
+                    if let Some(cycle) = pile
+                        .fingerprint(source.index() as u8, jet_index as u8)
+                        .and_then(|fp| cycles.check(fp, source.counter))
+                    {
+                        let loops = (count - source.counter) / cycle.length;
+                        looped = loops * (pile.top - tops[cycle.start]);
+                        source.counter += loops * cycle.length;
                     }
                 }
                 break;
@@ -274,17 +275,28 @@ fn drop(count: usize, jets: &str) -> usize {
     pile.top + looped
 }
 
-pub fn part1(input: &str) -> usize {
-    drop(2022, read_to_string(input).unwrap().trim())
-}
+pub struct Day;
+
+impl Solution for Day {
+    type Input = String;
+
+    fn parse(input: &str) -> Self::Input {
+        input.trim().to_string()
+    }
 
-pub fn part2(input: &str) -> usize {
-    drop(1000000000000, read_to_string(input).unwrap().trim())
+    fn part1(jets: &Self::Input) -> Answer {
+        drop(2022, jets).into()
+    }
+
+    fn part2(jets: &Self::Input) -> Answer {
+        drop(1000000000000, jets).into()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_core::{read, Part};
 
     #[test]
     fn blocks_itself() {
@@ -472,11 +484,11 @@ mod tests {
 
     #[test]
     fn part1_sample() {
-        assert_eq!(3068, part1("sample.txt"));
+        assert_eq!(3068, Day::solve(Part::One, &read("sample.txt")));
     }
 
     #[test]
     fn part2_sample() {
-        assert_eq!(1514285714288, part2("sample.txt"));
+        assert_eq!(1514285714288, Day::solve(Part::Two, &read("sample.txt")));
     }
 }
@@ -1,9 +1,9 @@
+use aoc_core::{Answer, Solution};
 use scan_fmt::scan_fmt;
 use std::collections::HashSet;
-use std::fs::read_to_string;
 
 #[derive(Debug)]
-struct Cubes {
+pub struct Cubes {
     minx: usize,
     miny: usize,
     minz: usize,
@@ -17,7 +17,6 @@ type Cube = (usize, usize, usize);
 
 impl Cubes {
     fn parse_cubes(input: &str) -> Cubes {
-        let input = read_to_string(input).unwrap();
         let mut min = (usize::MAX, usize::MAX, usize::MAX);
         let mut max = (usize::MIN, usize::MIN, usize::MIN);
         let cubes = input
@@ -158,19 +157,28 @@ impl Cubes {
     }
 }
 
-pub fn part1(input: &str) -> usize {
-    let cubes = Cubes::parse_cubes(input);
-    cubes.count_sides()
-}
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Cubes;
+
+    fn parse(input: &str) -> Self::Input {
+        Cubes::parse_cubes(input)
+    }
 
-pub fn part2(input: &str) -> usize {
-    let cubes = Cubes::parse_cubes(input);
-    cubes.count_sides() - cubes.holes().count_sides()
+    fn part1(cubes: &Self::Input) -> Answer {
+        cubes.count_sides().into()
+    }
+
+    fn part2(cubes: &Self::Input) -> Answer {
+        (cubes.count_sides() - cubes.holes().count_sides()).into()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_core::{read, Part};
 
     #[test]
     fn small_hole() {
@@ -245,7 +253,7 @@ mod tests {
 
     #[test]
     fn holes() {
-        let cubes = Cubes::parse_cubes("sample.txt");
+        let cubes = Cubes::parse_cubes(&read("sample.txt"));
         let holes = cubes.holes();
         assert_eq!(1, holes.cubes.len());
         assert!(holes.cubes.contains(&(2 + 1, 2 + 1, 5 + 1)));
@@ -253,11 +261,11 @@ mod tests {
 
     #[test]
     fn part1_sample() {
-        assert_eq!(64, part1("sample.txt"));
+        assert_eq!(64, Day::solve(Part::One, &read("sample.txt")));
     }
 
     #[test]
     fn part2_sample() {
-        assert_eq!(58, part2("sample.txt"));
+        assert_eq!(58, Day::solve(Part::Two, &read("sample.txt")));
     }
 }
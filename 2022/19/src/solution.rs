@@ -1,7 +1,7 @@
 use crate::solution::Resource::Ore;
+use aoc_core::{Answer, Solution};
 use pathfinding::prelude::dfs_reach;
 use scan_fmt::scan_fmt;
-use std::fs::read_to_string;
 use std::str::FromStr;
 use Resource::{Clay, Geode, Obsidian};
 
@@ -28,7 +28,7 @@ impl FromStr for Resource {
 }
 
 #[derive(Debug)]
-struct Blueprint {
+pub struct Blueprint {
     id: usize,
     costs: [[u8; 4]; 4],
     max_costs: [u8; 4],
@@ -80,7 +80,7 @@ impl Blueprint {
         c
     }
 
-    fn quality(self) -> usize {
+    fn quality(&self) -> usize {
         self.id * self.max_geodes(24)
     }
 
@@ -167,30 +167,41 @@ impl Blueprint {
     }
 }
 
-pub fn part1(input: &str) -> usize {
-    read_to_string(input)
-        .unwrap()
-        .lines()
-        .map(Blueprint::from_str)
-        .filter_map(Result::ok)
-        .map(Blueprint::quality)
-        .sum()
-}
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Blueprint>;
+
+    fn parse(input: &str) -> Self::Input {
+        input
+            .lines()
+            .map(Blueprint::from_str)
+            .filter_map(Result::ok)
+            .collect()
+    }
 
-pub fn part2(input: &str) -> usize {
-    read_to_string(input)
-        .unwrap()
-        .lines()
-        .take(3)
-        .map(Blueprint::from_str)
-        .filter_map(Result::ok)
-        .map(|bp| bp.max_geodes(32))
-        .product()
+    fn part1(blueprints: &Self::Input) -> Answer {
+        blueprints
+            .iter()
+            .map(Blueprint::quality)
+            .sum::<usize>()
+            .into()
+    }
+
+    fn part2(blueprints: &Self::Input) -> Answer {
+        blueprints
+            .iter()
+            .take(3)
+            .map(|bp| bp.max_geodes(32))
+            .product::<usize>()
+            .into()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_core::{read, Part};
 
     #[test]
     fn parsing_a_blueprint() {
@@ -231,7 +242,7 @@ mod tests {
 
     #[test]
     fn maximising_geodes() {
-        let string = read_to_string("sample.txt").expect("sample file");
+        let string = read("sample.txt");
         let mut lines = string.lines();
 
         assert_eq!(
@@ -250,11 +261,11 @@ mod tests {
 
     #[test]
     fn part1_sample() {
-        assert_eq!(33, part1("sample.txt"));
+        assert_eq!(33, Day::solve(Part::One, &read("sample.txt")));
     }
 
     #[test]
     fn part2_sample() {
-        assert_eq!(56 * 62, part2("sample.txt"));
+        assert_eq!(56 * 62, Day::solve(Part::Two, &read("sample.txt")));
     }
 }
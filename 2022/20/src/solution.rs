@@ -1,85 +1,138 @@
-use std::fs::read_to_string;
+use aoc_core::{Answer, Solution};
 use std::str::FromStr;
 
-#[derive(Debug)]
-struct Coords {
-    key: isize,
-    values: Vec<i16>,
-    keyed: Vec<i16>,
-    predecessors: Vec<usize>,
-    successors: Vec<usize>,
+/// The encrypted file: its numbers in their original order, and their
+/// current circular order as a list of blocks of about `√n` indices each,
+/// so that a number is found, removed and reinserted in `O(√n)`.
+#[derive(Debug, Clone)]
+pub struct Coords {
+    key: i64,
+    values: Vec<i64>,
+    blocks: Vec<Vec<usize>>,
+    /// The block each index is currently in.
+    block_of: Vec<usize>,
+    block_size: usize,
 }
 
 impl Coords {
-    fn mix(&mut self, index: usize) {
-        if self.keyed[index] == 0 {
+    fn new(values: Vec<i64>) -> Coords {
+        let block_size = ((values.len() as f64).sqrt() as usize).max(1);
+        let mut coords = Coords {
+            key: 1,
+            blocks: vec![(0..values.len()).collect()],
+            block_of: vec![0; values.len()],
+            values,
+            block_size,
+        };
+        coords.rebalance();
+        coords
+    }
+
+    /// Cuts the current order into blocks of `block_size` again.
+    fn rebalance(&mut self) {
+        let order: Vec<usize> = self.blocks.concat();
+        self.blocks = order
+            .chunks(self.block_size)
+            .map(<[usize]>::to_vec)
+            .collect();
+        for (b, block) in self.blocks.iter().enumerate() {
+            for &index in block {
+                self.block_of[index] = b;
+            }
+        }
+    }
+
+    /// Where the number at `index` currently is, counted from the start of
+    /// the first block.
+    fn position(&self, index: usize) -> usize {
+        let b = self.block_of[index];
+        let before: usize = self.blocks[..b].iter().map(Vec::len).sum();
+        before + self.blocks[b].iter().position(|&i| i == index).unwrap()
+    }
+
+    /// The index of the number at `position`.
+    fn at(&self, mut position: usize) -> usize {
+        for block in &self.blocks {
+            if position < block.len() {
+                return block[position];
+            }
+            position -= block.len();
+        }
+        panic!("position out of range")
+    }
+
+    /// Moves the number at `index` forward or backward by its value.
+    pub fn mix(&mut self, index: usize) {
+        let len = self.values.len();
+        if len < 2 {
             return;
         }
 
-        self.successors[self.predecessors[index]] = self.successors[index];
-        self.predecessors[self.successors[index]] = self.predecessors[index];
-        let steps = self.keyed[index].rem_euclid(self.keyed.len() as i16 - 1);
-        let mut s = self.successors[index];
-        for _ in 0..(steps - 1) {
-            s = self.successors[s];
+        let from = self.position(index);
+        let b = self.block_of[index];
+        self.blocks[b].retain(|&i| i != index);
+
+        // both factors are reduced first, as their product may not fit
+        let cycle = len as i64 - 1;
+        let steps =
+            self.values[index].rem_euclid(cycle) as i128 * self.key.rem_euclid(cycle) as i128;
+        let steps = steps.rem_euclid(cycle as i128) as usize;
+        let mut to = (from + steps) % (len - 1);
+        let mut b = 0;
+        while to > self.blocks[b].len() {
+            to -= self.blocks[b].len();
+            b += 1;
+        }
+        self.blocks[b].insert(to, index);
+        self.block_of[index] = b;
+
+        if self.blocks[b].len() > 2 * self.block_size {
+            self.rebalance();
         }
-        self.successors[index] = self.successors[s];
-        self.predecessors[index] = s;
-        self.predecessors[self.successors[s]] = index;
-        self.successors[s] = index;
     }
 
-    fn mix_completely(&mut self) {
+    /// Moves every number once, in their original order.
+    pub fn mix_completely(&mut self) {
         for i in 0..self.values.len() {
             self.mix(i);
         }
     }
 
-    fn zero(&self) -> usize {
-        for i in 0..self.values.len() {
-            if self.values[i] == 0 {
-                return i;
-            }
+    pub fn mix_rounds(&mut self, rounds: usize) {
+        for _ in 0..rounds {
+            self.mix_completely();
         }
-        panic!("No zero?")
     }
 
-    fn nth(&self, start: usize, n: usize) -> i16 {
-        let mut i = start;
-        for _ in 0..n.rem_euclid(self.values.len()) {
-            i = self.successors[i];
-        }
-        self.values[i]
+    fn zero(&self) -> usize {
+        self.values.iter().position(|&v| v == 0).expect("No zero?")
     }
 
-    fn grove(&self) -> isize {
+    /// The value `n` places after the one at `start`, without the key.
+    fn nth(&self, start: usize, n: usize) -> i64 {
+        let position = (self.position(start) + n) % self.values.len();
+        self.values[self.at(position)]
+    }
+
+    /// The grove coordinates, keyed and summed in `i128` so any values
+    /// fit.
+    fn grove(&self) -> i128 {
         let zero = self.zero();
 
-        self.nth(zero, 1000) as isize * self.key
-            + self.nth(zero, 2000) as isize * self.key
-            + self.nth(zero, 3000) as isize * self.key
+        [1000, 2000, 3000]
+            .iter()
+            .map(|&n| self.nth(zero, n) as i128 * self.key as i128)
+            .sum()
     }
 
-    fn apply_key(&mut self, key: isize) {
+    pub fn apply_key(&mut self, key: i64) {
         self.key = key;
-        self.keyed = self
-            .values
-            .iter()
-            .map(|v| (*v as isize * key).rem_euclid(self.values.len() as isize - 1) as i16)
-            .collect();
     }
 
-    #[allow(dead_code)]
-    fn as_vec(&self) -> Vec<i16> {
-        let mut v = Vec::with_capacity(self.values.capacity());
-
-        let mut i = 0;
-        while v.len() < self.values.len() {
-            v.push(self.values[i]);
-            i = self.successors[i];
-        }
-
-        v
+    /// The values in their current order, starting from the first one of
+    /// the file.
+    pub fn as_vec(&self) -> Vec<i64> {
+        (0..self.values.len()).map(|n| self.nth(0, n)).collect()
     }
 }
 
@@ -87,79 +140,87 @@ impl FromStr for Coords {
     type Err = ();
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let values: Vec<i16> = s
+        let values = s
             .lines()
-            .map(i16::from_str)
-            .filter_map(Result::ok)
-            .collect();
-        let count = values.len();
-        let predecessors = (0..count).map(|v| (v + count - 1) % count).collect();
-        let successors = (0..count).map(|v| (v + 1) % count).collect();
+            .map(i64::from_str)
+            .collect::<Result<_, _>>()
+            .map_err(|_| ())?;
 
-        let keyed = values.clone();
-
-        Ok(Coords {
-            key: 1,
-            values,
-            keyed,
-            predecessors,
-            successors,
-        })
+        Ok(Coords::new(values))
     }
 }
 
-pub fn part1(input: &str) -> isize {
-    let mut coords =
-        Coords::from_str(&read_to_string(input).expect("coordinates")).expect("valid coords");
-
-    coords.apply_key(1);
+pub struct Day;
 
-    coords.mix_completely();
+impl Solution for Day {
+    type Input = Coords;
 
-    coords.grove()
-}
+    fn parse(input: &str) -> Self::Input {
+        Coords::from_str(input).expect("valid coords")
+    }
 
-pub fn part2(input: &str) -> isize {
-    let mut coords =
-        Coords::from_str(&read_to_string(input).expect("coordinates")).expect("valid coords");
+    fn part1(coords: &Self::Input) -> Answer {
+        let mut coords = coords.clone();
 
-    coords.apply_key(811589153);
+        coords.apply_key(1);
 
-    for _ in 0..10 {
         coords.mix_completely();
+
+        i64::try_from(coords.grove())
+            .expect("grove coordinates fit in an i64")
+            .into()
     }
 
-    coords.grove()
+    fn part2(coords: &Self::Input) -> Answer {
+        let mut coords = coords.clone();
+
+        coords.apply_key(811589153);
+
+        coords.mix_rounds(10);
+
+        i64::try_from(coords.grove())
+            .expect("grove coordinates fit in an i64")
+            .into()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_core::{read, Part};
+
+    /// Mixing the plain way, by moving the numbers around in a `Vec`.
+    fn mix_naively(values: &[i64], key: i64, rounds: usize) -> Vec<i64> {
+        let len = values.len();
+        let mut order: Vec<usize> = (0..len).collect();
+        for _ in 0..rounds {
+            for (index, value) in values.iter().enumerate() {
+                let from = order.iter().position(|&i| i == index).unwrap();
+                order.remove(from);
+                let to = (from as i128 + *value as i128 * key as i128).rem_euclid(len as i128 - 1);
+                order.insert(to as usize, index);
+            }
+        }
+        let start = order.iter().position(|&i| i == 0).unwrap();
+        (0..len).map(|n| values[order[(start + n) % len]]).collect()
+    }
 
     #[test]
     fn parsing() {
-        let coords = Coords::from_str(&read_to_string("sample.txt").expect("a sample"))
-            .expect("valid coords");
+        let coords = Coords::from_str(&read("sample.txt")).expect("valid coords");
         assert_eq!(vec![1, 2, -3, 3, -2, 0, 4], coords.values);
-        assert_eq!(vec![6, 0, 1, 2, 3, 4, 5], coords.predecessors);
-        assert_eq!(vec![1, 2, 3, 4, 5, 6, 0], coords.successors);
         assert_eq!(vec![1, 2, -3, 3, -2, 0, 4], coords.as_vec());
+        assert!(Coords::from_str("1\nx\n").is_err());
     }
 
     #[test]
     fn mixing() {
-        let mut coords = Coords::from_str(&read_to_string("sample.txt").expect("a sample"))
-            .expect("valid coords");
+        let mut coords = Coords::from_str(&read("sample.txt")).expect("valid coords");
         coords.mix(0);
         assert_eq!(vec![1, 2, -3, 3, -2, 0, 4], coords.values);
-        assert_eq!(vec![1, 6, 0, 2, 3, 4, 5], coords.predecessors);
-        assert_eq!(vec![2, 0, 3, 4, 5, 6, 1], coords.successors);
         assert_eq!(vec![1, -3, 3, -2, 0, 4, 2], coords.as_vec());
 
         coords.mix(5);
-        assert_eq!(vec![1, 2, -3, 3, -2, 0, 4], coords.values);
-        assert_eq!(vec![1, 6, 0, 2, 3, 4, 5], coords.predecessors);
-        assert_eq!(vec![2, 0, 3, 4, 5, 6, 1], coords.successors);
         assert_eq!(vec![1, -3, 3, -2, 0, 4, 2], coords.as_vec());
 
         coords.mix(2);
@@ -167,28 +228,63 @@ mod tests {
         assert_eq!(vec![1, 3, -2, 0, -3, 4, 2], coords.as_vec());
     }
 
+    #[test]
+    fn mixing_like_a_vec() {
+        let input = Coords::from_str(&read("input.txt")).expect("valid coords");
+        for (key, rounds) in [(1, 1), (811589153, 2)] {
+            let mut coords = input.clone();
+            coords.apply_key(key);
+            coords.mix_rounds(rounds);
+            assert_eq!(mix_naively(&input.values, key, rounds), coords.as_vec());
+        }
+    }
+
+    #[test]
+    fn huge_values() {
+        let big = i64::MAX / 2;
+        let values = vec![3, big, -big, 0, big - 7, -1, i64::MIN / 2 + 5];
+        let mut coords = Coords::new(values.clone());
+        coords.apply_key(811589153);
+        coords.mix_rounds(3);
+
+        let mixed = coords.as_vec();
+        assert_eq!(mix_naively(&values, 811589153, 3), mixed);
+
+        let zero = mixed.iter().position(|&v| v == 0).unwrap();
+        let grove: i128 = [1000, 2000, 3000]
+            .iter()
+            .map(|n| mixed[(zero + n) % mixed.len()] as i128 * 811589153)
+            .sum();
+        assert_eq!(grove, coords.grove());
+    }
+
     #[test]
     fn find_nth() {
-        let coords = Coords::from_str(&read_to_string("sample.txt").expect("a sample"))
-            .expect("valid coords");
+        let coords = Coords::from_str(&read("sample.txt")).expect("valid coords");
         assert_eq!(2, coords.nth(0, 1));
         assert_eq!(-3, coords.nth(0, 2));
+        assert_eq!(1, coords.nth(0, 7));
     }
 
     #[test]
     fn find_zero() {
-        let coords = Coords::from_str(&read_to_string("sample.txt").expect("a sample"))
-            .expect("valid coords");
+        let coords = Coords::from_str(&read("sample.txt")).expect("valid coords");
         assert_eq!(5, coords.zero());
     }
 
     #[test]
     fn part1_sample() {
-        assert_eq!(3, part1("sample.txt"));
+        assert_eq!(3, Day::solve(Part::One, &read("sample.txt")));
     }
 
     #[test]
     fn part2_sample() {
-        assert_eq!(1623178306, part2("sample.txt"));
+        assert_eq!(1623178306, Day::solve(Part::Two, &read("sample.txt")));
+    }
+
+    #[test]
+    fn input() {
+        assert_eq!(17490, Day::solve(Part::One, &read("input.txt")));
+        assert_eq!(1632917375836, Day::solve(Part::Two, &read("input.txt")));
     }
 }
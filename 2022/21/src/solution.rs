@@ -1,13 +1,13 @@
 use crate::solution::Value::{Literal, Variable};
+use aoc_core::{Answer, Solution};
 use std::collections::{HashMap, HashSet};
-use std::fs::read_to_string;
 use std::str::FromStr;
 
 type Expressions = HashMap<String, Op>;
 type Cache = HashMap<String, isize>;
 
 #[derive(Debug, PartialEq, Clone)]
-enum Value {
+pub enum Value {
     Literal(isize),
     Variable(String),
 }
@@ -68,7 +68,7 @@ impl Value {
 }
 
 #[derive(Debug, Clone)]
-enum Op {
+pub enum Op {
     Num(Value),
     Add(Value, Value),
     Sub(Value, Value),
@@ -169,7 +169,6 @@ impl Op {
 }
 
 fn parse_expressions(input: &str) -> (Expressions, Cache) {
-    let input = read_to_string(input).expect("an input file");
     let expressions = input
         .lines()
         .filter_map(|l| l.split_once(": "))
@@ -193,34 +192,49 @@ fn parse_expressions(input: &str) -> (Expressions, Cache) {
     (expressions, cache)
 }
 
-pub fn part1(input: &str) -> isize {
-    let (expressions, mut cache) = parse_expressions(input);
+pub struct Day;
 
-    expressions
-        .get("root")
-        .expect("a root")
-        .eval(&expressions, &mut cache, &mut HashSet::new())
-}
+impl Solution for Day {
+    type Input = (Expressions, Cache);
+
+    fn parse(input: &str) -> Self::Input {
+        parse_expressions(input)
+    }
 
-pub fn part2(input: &str) -> isize {
-    let (mut expressions, mut cache) = parse_expressions(input);
+    fn part1((expressions, cache): &Self::Input) -> Answer {
+        let mut cache = cache.clone();
 
-    if let Op::Add(a, b) = expressions.remove("root").expect("a root") {
-        expressions.insert("root".to_string(), Op::Sub(a, b));
-        expressions.insert("ROOT".to_string(), Op::Num(Literal(0)));
-    } else {
-        panic!("Root should be an addition of two variables");
+        expressions
+            .get("root")
+            .expect("a root")
+            .eval(expressions, &mut cache, &mut HashSet::new())
+            .into()
     }
 
-    expressions.remove("humn").expect("a human");
-    cache.remove("humn").expect("a cached human");
+    fn part2((expressions, cache): &Self::Input) -> Answer {
+        let mut expressions = expressions.clone();
+        let mut cache = cache.clone();
 
-    Op::Num(Variable("humn".to_string())).eval(&expressions, &mut cache, &mut HashSet::new())
+        if let Op::Add(a, b) = expressions.remove("root").expect("a root") {
+            expressions.insert("root".to_string(), Op::Sub(a, b));
+            expressions.insert("ROOT".to_string(), Op::Num(Literal(0)));
+        } else {
+            panic!("Root should be an addition of two variables");
+        }
+
+        expressions.remove("humn").expect("a human");
+        cache.remove("humn").expect("a cached human");
+
+        Op::Num(Variable("humn".to_string()))
+            .eval(&expressions, &mut cache, &mut HashSet::new())
+            .into()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_core::{read, Part};
 
     #[test]
     fn parsing_shouts() {
@@ -264,7 +278,7 @@ mod tests {
 
     #[test]
     fn inversions() {
-        let (expressions, _) = parse_expressions("input.txt");
+        let (expressions, _) = parse_expressions(&read("input.txt"));
 
         let a = "a".to_string();
         let b = "b".to_string();
@@ -304,11 +318,11 @@ mod tests {
 
     #[test]
     fn part1_sample() {
-        assert_eq!(152, part1("sample.txt"));
+        assert_eq!(152, Day::solve(Part::One, &read("sample.txt")));
     }
 
     #[test]
     fn part2_sample() {
-        assert_eq!(301, part2("sample.txt"));
+        assert_eq!(301, Day::solve(Part::Two, &read("sample.txt")));
     }
 }
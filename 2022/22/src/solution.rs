@@ -1,59 +1,53 @@
-use phf::phf_map;
-use std::collections::{HashMap, HashSet};
-use std::fs::read_to_string;
-
-static NETS: [phf::Map<u8, (u8, Facing)>; 2] = [
-    // sample cube
-    phf_map! {
-        13u8 => (1,Facing::Up),
-        21u8 => (2,Facing::Up),
-        22u8 => (3,Facing::Up),
-        23u8 => (4,Facing::Up),
-        33u8 => (5,Facing::Up),
-        34u8 => (6,Facing::Up),
-    },
-    // input cube
-    phf_map! {
-        12u8 => (1,Facing::Up),
-        13u8 => (6,Facing::Down),
-        22u8 => (4,Facing::Up),
-        31u8 => (3,Facing::Left),
-        32u8 => (5,Facing::Up),
-        41u8 => (2,Facing::Left),
-    },
-    // add new nets here
-    // ...
-];
+use aoc_core::{Answer, Solution};
+use aoc_grid::Direction as Facing;
+use aoc_grid::Point;
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+/// Where the edges of the cube lead: leaving `face` facing `facing` (in the
+/// face's own orientation) enters the returned face with the returned facing.
+fn adjacent_face(face: u8, facing: Facing) -> (u8, Facing) {
+    match (face, facing) {
+        (1, Facing::Right) => (6, Facing::Left),
+        (1, Facing::Down) => (4, Facing::Down),
+        (1, Facing::Left) => (3, Facing::Down),
+        (1, Facing::Up) => (2, Facing::Down),
+        (2, Facing::Right) => (3, Facing::Right),
+        (2, Facing::Down) => (5, Facing::Up),
+        (2, Facing::Left) => (6, Facing::Up),
+        (2, Facing::Up) => (1, Facing::Down),
+        (3, Facing::Right) => (4, Facing::Right),
+        (3, Facing::Down) => (5, Facing::Right),
+        (3, Facing::Left) => (2, Facing::Left),
+        (3, Facing::Up) => (1, Facing::Right),
+        (4, Facing::Right) => (6, Facing::Down),
+        (4, Facing::Down) => (5, Facing::Down),
+        (4, Facing::Left) => (3, Facing::Left),
+        (4, Facing::Up) => (1, Facing::Up),
+        (5, Facing::Right) => (6, Facing::Right),
+        (5, Facing::Down) => (2, Facing::Up),
+        (5, Facing::Left) => (3, Facing::Up),
+        (5, Facing::Up) => (4, Facing::Up),
+        (6, Facing::Right) => (1, Facing::Left),
+        (6, Facing::Down) => (2, Facing::Right),
+        (6, Facing::Left) => (5, Facing::Left),
+        (6, Facing::Up) => (4, Facing::Left),
+        (num, _) => panic!("hyper cube face? {num}"),
+    }
+}
 
-#[derive(Eq, PartialEq, Debug, Copy, Clone)]
-enum Facing {
-    Right = 0,
-    Down = 1,
-    Left = 2,
-    Up = 3,
+/// `facing` as seen from a face whose up points to `face_dir`.
+fn unrotate(facing: Facing, face_dir: Facing) -> Facing {
+    Facing::ALL[(facing as usize + 4 - face_dir as usize) % 4]
 }
 
-impl Facing {
-    pub fn right(&self) -> Facing {
-        match self {
-            Facing::Right => Facing::Down,
-            Facing::Down => Facing::Left,
-            Facing::Left => Facing::Up,
-            Facing::Up => Facing::Right,
-        }
-    }
-    pub fn left(&self) -> Facing {
-        match self {
-            Facing::Right => Facing::Up,
-            Facing::Down => Facing::Right,
-            Facing::Left => Facing::Down,
-            Facing::Up => Facing::Left,
-        }
-    }
+/// The password scores facings clockwise from right.
+fn facing_value(facing: Facing) -> usize {
+    (facing as usize + 3) % 4
 }
 
 #[derive(Eq, PartialEq, Debug, Clone)]
-struct Pos {
+pub struct Pos {
     row: usize,
     col: usize,
     facing: Facing,
@@ -62,23 +56,23 @@ struct Pos {
 impl Pos {
     pub(crate) fn facing(&self, face_dir: &Facing) -> Facing {
         match face_dir {
-            Facing::Right => self.facing.right(),
-            Facing::Down => self.facing.left().left(),
-            Facing::Left => self.facing.left(),
+            Facing::Right => self.facing.turn_right(),
+            Facing::Down => self.facing.opposite(),
+            Facing::Left => self.facing.turn_left(),
             Facing::Up => self.facing,
         }
     }
 
     pub(crate) fn turn_right(&mut self) {
-        self.facing = self.facing.right();
+        self.facing = self.facing.turn_right();
     }
     pub(crate) fn turn_left(&mut self) {
-        self.facing = self.facing.left();
+        self.facing = self.facing.turn_left();
     }
 }
 
-#[derive(Eq, PartialEq, Debug)]
-enum Action {
+#[derive(Eq, PartialEq, Debug, Copy, Clone)]
+pub enum Action {
     Move(u32),
     Left,
     Right,
@@ -114,11 +108,11 @@ impl Action {
 }
 
 #[derive(Debug)]
-struct Map {
+pub struct Map {
     tiles: HashMap<(usize, usize), bool>,
     rows: usize,
     cols: usize,
-    net: &'static phf::Map<u8, (u8, Facing)>,
+    net: HashMap<u8, (u8, Facing)>,
     cube_face_size: usize,
     tile_faces: HashMap<(usize, usize), (u8, Facing)>,
 }
@@ -127,7 +121,7 @@ impl Map {
     fn tiles_to_faces(
         tiles: &HashMap<(usize, usize), bool>,
         cube_face_size: usize,
-        net: &'static phf::Map<u8, (u8, Facing)>,
+        net: &HashMap<u8, (u8, Facing)>,
     ) -> HashMap<(usize, usize), (u8, Facing)> {
         tiles
             .iter()
@@ -144,25 +138,56 @@ impl Map {
             .collect()
     }
 
+    /// Folds the net into the cube: the first face in reading order becomes
+    /// face 1 upright, and every other face follows from crossing the edges
+    /// to it.
     pub(crate) fn identify_tiles(
         tiles: &HashMap<(usize, usize), bool>,
         face_size: usize,
-    ) -> &'static phf::Map<u8, (u8, Facing)> {
-        let mut net_points = HashSet::new();
-        for row in 0..4 {
-            for col in 0..4 {
-                if tiles.contains_key(&(1 + row * face_size, 1 + col * face_size)) {
-                    net_points.insert(((row + 1) * 10 + col + 1) as u8);
+    ) -> HashMap<u8, (u8, Facing)> {
+        let net_points: HashSet<Point> = (0..5)
+            .flat_map(|row| (0..5).map(move |col| (col, row)))
+            .filter(|(col, row)| tiles.contains_key(&(1 + row * face_size, 1 + col * face_size)))
+            .collect();
+        let start = *net_points
+            .iter()
+            .min_by_key(|(col, row)| (row, col))
+            .expect("a face");
+
+        let mut faces = HashMap::from([(start, (1, Facing::Up))]);
+        let mut queue = VecDeque::from([start]);
+        while let Some(point) = queue.pop_front() {
+            let (face, face_dir) = faces[&point];
+            for map_dir in Facing::ALL {
+                let Some(next) = map_dir
+                    .checked_step(point)
+                    .filter(|next| net_points.contains(next))
+                else {
+                    continue;
+                };
+                let (next_face, entry_facing) = adjacent_face(face, unrotate(map_dir, face_dir));
+                // walking straight on across the net
+                let folded = (next_face, unrotate(map_dir, entry_facing));
+                match faces.entry(next) {
+                    Entry::Vacant(entry) => {
+                        entry.insert(folded);
+                        queue.push_back(next);
+                    }
+                    Entry::Occupied(entry) => assert_eq!(*entry.get(), folded, "Not a cube net?"),
                 }
             }
         }
 
-        for net in &NETS {
-            if net_points.iter().all(|point| net.contains_key(point)) {
-                return net;
-            }
-        }
-        todo!("Net not found in NETS")
+        let net: HashMap<u8, (u8, Facing)> = faces
+            .into_iter()
+            .map(|((col, row), face)| (((row + 1) * 10 + col + 1) as u8, face))
+            .collect();
+        let distinct_faces: HashSet<u8> = net.values().map(|(face, _)| *face).collect();
+        assert!(
+            net.len() == 6 && distinct_faces.len() == 6,
+            "Not a cube net?"
+        );
+        net
     }
 
     pub(crate) fn find_start(&self) -> Pos {
@@ -203,7 +228,7 @@ impl Map {
         let cube_face_size = Map::cube_face_size(rows, cols);
         let net = Map::identify_tiles(&tiles, cube_face_size);
         let tile_faces: HashMap<(usize, usize), (u8, Facing)> =
-            Map::tiles_to_faces(&tiles, cube_face_size, net);
+            Map::tiles_to_faces(&tiles, cube_face_size, &net);
         (
             Map {
                 tiles,
@@ -221,9 +246,10 @@ impl Map {
         let bigger = rows.max(cols);
         let smaller = rows.min(cols);
 
-        if bigger % 5 == 0 && smaller % 2 == 0 && bigger / 5 == smaller / 2 {
+        if bigger.is_multiple_of(5) && smaller.is_multiple_of(2) && bigger / 5 == smaller / 2 {
             bigger / 5
-        } else if bigger % 4 == 0 && smaller % 3 == 0 && bigger / 4 == smaller / 3 {
+        } else if bigger.is_multiple_of(4) && smaller.is_multiple_of(3) && bigger / 4 == smaller / 3
+        {
             bigger / 4
         } else {
             panic!("Not a cube net?")
@@ -298,68 +324,21 @@ impl Map {
     }
 
     fn next_face_pos(&self, pos: &Pos, face_num: &u8) -> Pos {
+        let size = self.cube_face_size;
         let (tile_row, tile_col) = self.face_coords(pos);
-        let (face, facing, next_tile_row, next_tile_col) = match (face_num, pos.facing) {
-            (1, Facing::Right) => (
-                6,
-                Facing::Left,
-                self.cube_face_size - tile_row + 1,
-                self.cube_face_size,
-            ),
-            (1, Facing::Down) => (4, Facing::Down, 1, tile_col),
-            (1, Facing::Left) => (3, Facing::Down, 1, tile_row),
-            (1, Facing::Up) => (2, Facing::Down, 1, self.cube_face_size - tile_col + 1),
-            (2, Facing::Right) => (3, Facing::Right, tile_row, 1),
-            (2, Facing::Down) => (
-                5,
-                Facing::Up,
-                self.cube_face_size,
-                self.cube_face_size - tile_col + 1,
-            ),
-            (2, Facing::Left) => (
-                6,
-                Facing::Up,
-                self.cube_face_size,
-                self.cube_face_size - tile_row + 1,
-            ),
-            (2, Facing::Up) => (1, Facing::Down, 1, self.cube_face_size - tile_col + 1),
-            (3, Facing::Right) => (4, Facing::Right, tile_row, 1),
-            (3, Facing::Down) => (5, Facing::Right, self.cube_face_size - tile_col + 1, 1),
-            (3, Facing::Left) => (2, Facing::Left, tile_row, self.cube_face_size),
-            (3, Facing::Up) => (1, Facing::Right, tile_col, 1),
-            (4, Facing::Right) => (6, Facing::Down, 1, self.cube_face_size - tile_row + 1),
-            (4, Facing::Down) => (5, Facing::Down, 1, tile_col),
-            (4, Facing::Left) => (3, Facing::Left, tile_row, self.cube_face_size),
-            (4, Facing::Up) => (1, Facing::Up, self.cube_face_size, tile_col),
-            (5, Facing::Right) => (6, Facing::Right, tile_row, 1),
-            (5, Facing::Down) => (
-                2,
-                Facing::Up,
-                self.cube_face_size,
-                self.cube_face_size - tile_col + 1,
-            ),
-            (5, Facing::Left) => (
-                3,
-                Facing::Up,
-                self.cube_face_size,
-                self.cube_face_size - tile_row + 1,
-            ),
-            (5, Facing::Up) => (4, Facing::Up, self.cube_face_size, tile_col),
-            (6, Facing::Right) => (
-                1,
-                Facing::Left,
-                self.cube_face_size - tile_row + 1,
-                self.cube_face_size,
-            ),
-            (6, Facing::Down) => (2, Facing::Right, self.cube_face_size - tile_col + 1, 1),
-            (6, Facing::Left) => (5, Facing::Left, tile_row, self.cube_face_size),
-            (6, Facing::Up) => (
-                4,
-                Facing::Left,
-                self.cube_face_size - tile_col + 1,
-                self.cube_face_size,
-            ),
-            (num, _) => panic!("hyper cube face? {num}"),
+        // crossing an edge keeps the distance to the left hand side
+        let from_left = match pos.facing {
+            Facing::Up => tile_col - 1,
+            Facing::Right => tile_row - 1,
+            Facing::Down => size - tile_col,
+            Facing::Left => size - tile_row,
+        };
+        let (face, facing) = adjacent_face(*face_num, pos.facing);
+        let (next_tile_row, next_tile_col) = match facing {
+            Facing::Up => (size, from_left + 1),
+            Facing::Right => (from_left + 1, 1),
+            Facing::Down => (1, size - from_left),
+            Facing::Left => (size - from_left, size),
         };
 
         let (row, col) = self.row_col_from_face_coords(face, next_tile_row, next_tile_col);
@@ -377,7 +356,7 @@ impl Map {
             .expect("must be on the cube");
 
         match face_dir {
-            Facing::Right => (self.cube_face_size - col + 1, row), // TODO: check this
+            Facing::Right => (self.cube_face_size - col + 1, row),
             Facing::Down => (self.cube_face_size - row + 1, self.cube_face_size - col + 1),
             Facing::Left => (col, self.cube_face_size - row + 1),
             Facing::Up => (row, col),
@@ -392,7 +371,7 @@ impl Map {
     ) -> (usize, usize) {
         let (c, (_, face_dir)) = self
             .net
-            .entries()
+            .iter()
             .find(|(_, (f, _))| *f == face)
             .expect("face must exist");
 
@@ -401,9 +380,7 @@ impl Map {
         let face_top = face_row * self.cube_face_size;
         let face_left = face_col * self.cube_face_size;
         match face_dir {
-            Facing::Right => {
-                todo!("Maybe: (face_top + col, face_left + self.cube_face_size - row + 1)");
-            }
+            Facing::Right => (face_top + col, face_left + self.cube_face_size - row + 1),
             Facing::Down => (
                 face_top + self.cube_face_size - row + 1,
                 face_left + self.cube_face_size - col + 1,
@@ -414,14 +391,11 @@ impl Map {
     }
 }
 
-fn walk(input: &str, cube: bool) -> usize {
-    let input = input;
-    let (map, actions) = Map::parse_map(input);
-
+fn walk(map: &Map, actions: &[Action], cube: bool) -> usize {
     let mut pos = map.find_start();
 
     for a in actions {
-        match a {
+        match *a {
             Action::Move(count) => {
                 for _ in 0..count {
                     if let (new_pos, true) = map.step(&pos, cube) {
@@ -438,27 +412,38 @@ fn walk(input: &str, cube: bool) -> usize {
 
     let map_facing_value = if cube {
         let (_face, facing) = map.tile_faces.get(&(pos.row, pos.col)).expect("some face");
-        let map_facing_value = pos.facing as usize + *facing as usize + 1;
+        let map_facing_value = facing_value(pos.facing) + facing_value(*facing) + 1;
 
         map_facing_value % 4
     } else {
-        pos.facing as usize
+        facing_value(pos.facing)
     };
 
     1000 * pos.row + 4 * pos.col + map_facing_value
 }
 
-pub fn part1(input: &str) -> usize {
-    walk(&read_to_string(input).expect("a file"), false)
-}
+pub struct Day;
+
+impl Solution for Day {
+    type Input = (Map, Vec<Action>);
+
+    fn parse(input: &str) -> Self::Input {
+        Map::parse_map(input)
+    }
+
+    fn part1((map, actions): &Self::Input) -> Answer {
+        walk(map, actions, false).into()
+    }
 
-pub fn part2(input: &str) -> usize {
-    walk(&read_to_string(input).expect("a file"), true)
+    fn part2((map, actions): &Self::Input) -> Answer {
+        walk(map, actions, true).into()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_core::{read, Part};
     use indoc::indoc;
     use test_case::test_case;
 
@@ -511,7 +496,7 @@ mod tests {
 
     #[test]
     fn parsing_sample() {
-        let (map, actions) = Map::parse_map(&read_to_string("sample.txt").expect("sample"));
+        let (map, actions) = Map::parse_map(&read("sample.txt"));
 
         assert_eq!(6 * 16, map.tiles.len());
         assert_eq!(12, map.rows);
@@ -601,10 +586,10 @@ mod tests {
         let (map, _) = Map::parse_map(input);
         assert_eq!(1, map.cube_face_size);
 
-        let (map, _) = Map::parse_map(&read_to_string("sample.txt").expect("sample"));
+        let (map, _) = Map::parse_map(&read("sample.txt"));
         assert_eq!(4, map.cube_face_size);
 
-        let (map, _) = Map::parse_map(&read_to_string("input.txt").expect("sample"));
+        let (map, _) = Map::parse_map(&read("input.txt"));
         assert_eq!(50, map.cube_face_size);
     }
 
@@ -635,11 +620,122 @@ mod tests {
 
     #[test]
     fn identifying_the_net() {
-        let (map, _) = Map::parse_map(&read_to_string("sample.txt").expect("sample"));
-        assert_eq!(format!("{:?}", NETS[0]), format!("{:?}", map.net));
+        let (map, _) = Map::parse_map(&read("sample.txt"));
+        assert_eq!(
+            HashMap::from([
+                (13, (1, Facing::Up)),
+                (21, (2, Facing::Up)),
+                (22, (3, Facing::Up)),
+                (23, (4, Facing::Up)),
+                (33, (5, Facing::Up)),
+                (34, (6, Facing::Up)),
+            ]),
+            map.net
+        );
 
-        let (map, _) = Map::parse_map(&read_to_string("input.txt").expect("sample"));
-        assert_eq!(format!("{:?}", NETS[1]), format!("{:?}", map.net));
+        let (map, _) = Map::parse_map(&read("input.txt"));
+        assert_eq!(
+            HashMap::from([
+                (12, (1, Facing::Up)),
+                (13, (6, Facing::Down)),
+                (22, (4, Facing::Up)),
+                (31, (3, Facing::Left)),
+                (32, (5, Facing::Up)),
+                (41, (2, Facing::Left)),
+            ]),
+            map.net
+        );
+    }
+
+    #[test]
+    fn edges_of_the_cube_lead_back() {
+        for face in 1..=6 {
+            for facing in Facing::ALL {
+                let (next_face, next_facing) = adjacent_face(face, facing);
+                assert_eq!(
+                    (face, facing.opposite()),
+                    adjacent_face(next_face, next_facing.opposite())
+                );
+            }
+        }
+    }
+
+    fn scale(net: &str, size: usize) -> String {
+        net.lines()
+            .flat_map(|line| {
+                let row: String = line
+                    .chars()
+                    .flat_map(|c| std::iter::repeat_n(c, size))
+                    .collect();
+                std::iter::repeat_n(row, size)
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn rotate(net: &str) -> String {
+        let lines: Vec<Vec<char>> = net.lines().map(|line| line.chars().collect()).collect();
+        let width = lines.iter().map(|line| line.len()).max().unwrap_or(0);
+        (0..width)
+            .map(|col| {
+                lines
+                    .iter()
+                    .rev()
+                    .map(|line| line.get(col).copied().unwrap_or(' '))
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn mirror(net: &str) -> String {
+        let width = net.lines().map(str::len).max().unwrap_or(0);
+        net.lines()
+            .map(|line| format!("{line:width$}").chars().rev().collect::<String>())
+            .map(|line| line.trim_end().to_string())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test_case(".\n....\n.")]
+    #[test_case(".\n....\n .")]
+    #[test_case(".\n....\n  .")]
+    #[test_case(".\n....\n   .")]
+    #[test_case(" .\n....\n .")]
+    #[test_case(" .\n....\n  .")]
+    #[test_case("..\n ...\n .")]
+    #[test_case("..\n ...\n  .")]
+    #[test_case("..\n ...\n   .")]
+    #[test_case("..\n ..\n  ..")]
+    #[test_case("...\n  ...")]
+    fn folding_every_net(net: &str) {
+        assert_eq!(net, mirror(&mirror(net)));
+        for mut net in [net.to_string(), mirror(net)] {
+            for _ in 0..4 {
+                for size in [1, 3] {
+                    let (map, _) = Map::parse_map(&(scale(&net, size) + "\n\n1"));
+                    assert_eq!(size, map.cube_face_size);
+                    for &(row, col) in map.tiles.keys() {
+                        for facing in Facing::ALL {
+                            let start = Pos { row, col, facing };
+
+                            let (mut pos, _) = map.step(&start, true);
+                            pos.facing = pos.facing.opposite();
+                            let (mut back, _) = map.step(&pos, true);
+                            back.facing = back.facing.opposite();
+                            assert_eq!(start, back, "there and back again in\n{net}");
+
+                            let mut pos = start.clone();
+                            for _ in 0..4 * size {
+                                pos = map.step(&pos, true).0;
+                            }
+                            assert_eq!(start, pos, "around the cube in\n{net}");
+                        }
+                    }
+                }
+                net = rotate(&net);
+            }
+        }
     }
 
     #[test]
@@ -678,7 +774,7 @@ mod tests {
 
     #[test]
     fn facing_tiles_sample() {
-        let (map, _) = Map::parse_map(&read_to_string("sample.txt").expect("sample"));
+        let (map, _) = Map::parse_map(&read("sample.txt"));
         assert!(matches!(
             map.tile_faces.get(&(12, 16)),
             Some((6, Facing::Up))
@@ -709,7 +805,7 @@ mod tests {
         input: &str,
     ) -> (usize, usize, Facing, bool) {
         let pos = Pos { row, col, facing };
-        let (map, _) = Map::parse_map(&read_to_string(input).expect("valid input"));
+        let (map, _) = Map::parse_map(&read(input));
         let (p, tile) = map.step(&pos, true);
         (p.row, p.col, p.facing, tile)
     }
@@ -721,7 +817,7 @@ mod tests {
     #[test_case(200, 1, "input.txt" => (1, 1))]
     #[test_case(1, 150, "input.txt" => (50, 1))]
     fn finding_row_col_on_face(row: usize, col: usize, input: &str) -> (usize, usize) {
-        let (map, _) = Map::parse_map(&read_to_string(input).expect("valid input"));
+        let (map, _) = Map::parse_map(&read(input));
         let pos = Pos {
             row,
             col,
@@ -742,18 +838,18 @@ mod tests {
         col: usize,
         input: &str,
     ) -> (usize, usize) {
-        let (map, _) = Map::parse_map(&read_to_string(input).expect("valid input"));
+        let (map, _) = Map::parse_map(&read(input));
         map.row_col_from_face_coords(face, row, col)
     }
 
-    #[test_case("1" => 1 * 1000 + 3 * 4 + 0)]
+    #[test_case("1" => (1000 + 3 * 4))]
     #[test_case("2" => 3 * 1000 + 2 * 4 + 2)]
-    #[test_case("3" => 3 * 1000 + 1 * 4 + 2)]
-    #[test_case("4" => 1 * 1000 + 2 * 4 + 0)]
-    #[test_case("R1R1" => 3 * 1000 + 1 * 4 + 1)]
+    #[test_case("3" => 3 * 1000 + 4 + 2)]
+    #[test_case("4" => (1000 + 2 * 4))]
+    #[test_case("R1R1" => 3 * 1000 + 4 + 1)]
     #[test_case("R2" => 3 * 1000 + 2 * 4 + 1)]
-    #[test_case("R2R1" => 3 * 1000 + 1 * 4 + 2)]
-    #[test_case("R1L1" => 1 * 1000 + 3 * 4 + 3)]
+    #[test_case("R2R1" => 3 * 1000 + 4 + 2)]
+    #[test_case("R1L1" => 1000 + 3 * 4 + 3)]
     fn walk_a_really_simple_input_net(actions: &str) -> usize {
         let input = indoc! {"
              ..
@@ -764,27 +860,28 @@ mod tests {
         "};
 
         let input = input.to_string() + actions;
+        let (map, actions) = Map::parse_map(&input);
 
-        walk(&input, true)
+        walk(&map, &actions, true)
     }
 
     #[test]
     fn part1_sample() {
-        assert_eq!(6032, part1("sample.txt"));
+        assert_eq!(6032, Day::solve(Part::One, &read("sample.txt")));
     }
 
     #[test]
     fn part1_input() {
-        assert_eq!(13566, part1("input.txt"));
+        assert_eq!(13566, Day::solve(Part::One, &read("input.txt")));
     }
 
     #[test]
     fn part2_sample() {
-        assert_eq!(5031, part2("sample.txt"));
+        assert_eq!(5031, Day::solve(Part::Two, &read("sample.txt")));
     }
 
     #[test]
     fn part2_input() {
-        assert_eq!(11451, part2("input.txt"));
+        assert_eq!(11451, Day::solve(Part::Two, &read("input.txt")));
     }
 }
@@ -1,47 +1,18 @@
-use crate::solution::Direction::{East, North, South, West};
+use aoc_core::{Answer, Solution};
+use aoc_grid::{Direction, Direction8, Pos};
 use itertools::Itertools;
 use std::collections::{HashMap, HashSet};
-use std::fs::read_to_string;
-use std::ops;
-
-type Pos = (i32, i32);
-enum Direction {
-    North = 0,
-    South = 1,
-    West = 2,
-    East = 3,
-}
-
-impl ops::Add<i32> for Direction {
-    type Output = Direction;
-
-    fn add(self, rhs: i32) -> Self::Output {
-        match (self as i32 + rhs) % 4 {
-            0 => North,
-            1 => South,
-            2 => West,
-            3 => East,
-            _ => unreachable!(),
-        }
-    }
-}
 
-impl ops::Add<Direction> for Pos {
-    type Output = Pos;
-
-    fn add(self, rhs: Direction) -> Self::Output {
-        let (x, y) = self;
-        match rhs {
-            North => (x, y - 1),
-            South => (x, y + 1),
-            West => (x - 1, y),
-            East => (x + 1, y),
-        }
-    }
-}
+/// The directions elves consider moving in, in order; each round starts one
+/// further down the list.
+const PROPOSALS: [Direction; 4] = [
+    Direction::Up,
+    Direction::Down,
+    Direction::Left,
+    Direction::Right,
+];
 
 fn parse_input(input: &str) -> HashSet<Pos> {
-    let input = read_to_string(input).unwrap();
     input
         .lines()
         .enumerate()
@@ -49,7 +20,7 @@ fn parse_input(input: &str) -> HashSet<Pos> {
             line.chars()
                 .enumerate()
                 .filter_map(|(x, c)| match c {
-                    '#' => Some((x as i32, y as i32)),
+                    '#' => Some(Pos::new(x as i64, y as i64)),
                     _ => None,
                 })
                 .collect::<Vec<_>>()
@@ -57,8 +28,8 @@ fn parse_input(input: &str) -> HashSet<Pos> {
         .collect()
 }
 
-fn move_elves(input: &str, rounds: i32) -> (HashSet<Pos>, i32) {
-    let mut elves = parse_input(input);
+fn move_elves(elves: &HashSet<Pos>, rounds: i32) -> (HashSet<Pos>, i32) {
+    let mut elves = elves.clone();
 
     for round in 0..rounds {
         let (proposed, no_need_to_move) = propose(&elves, round % 4);
@@ -89,22 +60,22 @@ fn move_elves(input: &str, rounds: i32) -> (HashSet<Pos>, i32) {
     (elves, -1)
 }
 
-fn count_empty_tiles(input: &str, rounds: i32) -> i32 {
-    let (elves, _) = move_elves(input, rounds);
+fn count_empty_tiles(elves: &HashSet<Pos>, rounds: i32) -> i64 {
+    let (elves, _) = move_elves(elves, rounds);
 
-    let minx = elves.iter().map(|(x, _)| x).min().unwrap();
-    let maxx = elves.iter().map(|(x, _)| x).max().unwrap();
-    let miny = elves.iter().map(|(_, y)| y).min().unwrap();
-    let maxy = elves.iter().map(|(_, y)| y).max().unwrap();
-    (maxx - minx + 1) * (maxy - miny + 1) - elves.len() as i32
+    let minx = elves.iter().map(|pos| pos.x).min().unwrap();
+    let maxx = elves.iter().map(|pos| pos.x).max().unwrap();
+    let miny = elves.iter().map(|pos| pos.y).min().unwrap();
+    let maxy = elves.iter().map(|pos| pos.y).max().unwrap();
+    (maxx - minx + 1) * (maxy - miny + 1) - elves.len() as i64
 }
 
 #[allow(dead_code)]
-fn print_elves(elves: &HashSet<Pos>, round: i32, minx: i32, miny: i32, maxx: i32, maxy: i32) {
+fn print_elves(elves: &HashSet<Pos>, round: i32, minx: i64, miny: i64, maxx: i64, maxy: i64) {
     println!("Round: {round}");
     for y in miny..=maxy {
         for x in minx..=maxx {
-            if elves.contains(&(x, y)) {
+            if elves.contains(&Pos::new(x, y)) {
                 print!("#");
             } else {
                 print!(".");
@@ -124,16 +95,11 @@ fn propose(elves: &HashSet<Pos>, round: i32) -> (HashMap<Pos, Pos>, bool) {
         no_need_to_move &= has_no_neighbours;
         let new_pos = if has_no_neighbours {
             *pos
-        } else if let Some(new_pos) = try_move(elves, pos, North + round) {
-            new_pos
-        } else if let Some(new_pos) = try_move(elves, pos, South + round) {
-            new_pos
-        } else if let Some(new_pos) = try_move(elves, pos, West + round) {
-            new_pos
-        } else if let Some(new_pos) = try_move(elves, pos, East + round) {
-            new_pos
         } else {
-            *pos
+            (0..PROPOSALS.len())
+                .map(|i| PROPOSALS[(round as usize + i) % PROPOSALS.len()])
+                .find_map(|direction| try_move(elves, pos, direction))
+                .unwrap_or(*pos)
         };
         proposed.insert(*pos, new_pos);
     }
@@ -142,153 +108,142 @@ fn propose(elves: &HashSet<Pos>, round: i32) -> (HashMap<Pos, Pos>, bool) {
 }
 
 fn no_neighbours(elves: &HashSet<Pos>, pos: &Pos) -> bool {
-    !elves.contains(&(*pos + East))
-        && !elves.contains(&(*pos + East + South))
-        && !elves.contains(&(*pos + South))
-        && !elves.contains(&(*pos + South + West))
-        && !elves.contains(&(*pos + West))
-        && !elves.contains(&(*pos + West + North))
-        && !elves.contains(&(*pos + North))
-        && !elves.contains(&(*pos + North + East))
+    pos.neighbours8()
+        .all(|neighbour| !elves.contains(&neighbour))
 }
 
+/// Moves towards `direction` if no elf is there or diagonally next to it.
 fn try_move(elves: &HashSet<Pos>, pos: &Pos, direction: Direction) -> Option<Pos> {
-    match direction {
-        North
-            if !elves.contains(&(*pos + North + West))
-                && !elves.contains(&(*pos + North))
-                && !elves.contains(&(*pos + North + East)) =>
-        {
-            Some(*pos + North)
-        }
-        South
-            if !elves.contains(&(*pos + South + West))
-                && !elves.contains(&(*pos + South))
-                && !elves.contains(&(*pos + South + East)) =>
-        {
-            Some(*pos + South)
-        }
-        West if !elves.contains(&(*pos + West + North))
-            && !elves.contains(&(*pos + West))
-            && !elves.contains(&(*pos + West + South)) =>
-        {
-            Some(*pos + West)
-        }
-        East if !elves.contains(&(*pos + East + North))
-            && !elves.contains(&(*pos + East))
-            && !elves.contains(&(*pos + East + South)) =>
-        {
-            Some(*pos + East)
-        }
-        _ => None,
-    }
+    let ahead = Direction8::from(direction);
+    [ahead.turn_left(), ahead, ahead.turn_right()]
+        .into_iter()
+        .all(|d| !elves.contains(&(*pos + d)))
+        .then_some(*pos + direction)
 }
 
-pub fn part1(input: &str) -> i32 {
-    count_empty_tiles(input, 10)
-}
+pub struct Day;
+
+impl Solution for Day {
+    type Input = HashSet<Pos>;
 
-pub fn part2(input: &str) -> i32 {
-    let (_, rounds_done) = move_elves(input, 1000);
-    rounds_done
+    fn parse(input: &str) -> Self::Input {
+        parse_input(input)
+    }
+
+    fn part1(elves: &Self::Input) -> Answer {
+        count_empty_tiles(elves, 10).into()
+    }
+
+    fn part2(elves: &Self::Input) -> Answer {
+        let (_, rounds_done) = move_elves(elves, 1000);
+        rounds_done.into()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_core::{read, Part};
 
     #[test]
     fn small_with_0_rounds() {
-        assert_eq!(3, count_empty_tiles("small.txt", 0));
+        assert_eq!(3, count_empty_tiles(&parse_input(&read("small.txt")), 0));
     }
 
     #[test]
     fn small_with_3_rounds() {
-        assert_eq!(25, count_empty_tiles("small.txt", 3));
+        assert_eq!(25, count_empty_tiles(&parse_input(&read("small.txt")), 3));
     }
 
     #[test]
     fn small_with_10_rounds() {
-        assert_eq!(25, count_empty_tiles("small.txt", 10));
+        assert_eq!(25, count_empty_tiles(&parse_input(&read("small.txt")), 10));
     }
 
     #[test]
     fn propose_small_first_round() {
-        let elves = parse_input("small.txt");
+        let elves = parse_input(&read("small.txt"));
         let (proposed, _) = propose(&elves, 0);
-        let mut proposed_positions = proposed.into_iter().map(|(_, pos)| pos).collect::<Vec<_>>();
+        let mut proposed_positions = proposed.into_values().collect::<Vec<_>>();
         proposed_positions.sort();
         assert_eq!(
-            vec![(2, 0), (2, 3), (2, 3), (3, 0), (3, 3)],
+            vec![
+                Pos::new(2, 0),
+                Pos::new(2, 3),
+                Pos::new(2, 3),
+                Pos::new(3, 0),
+                Pos::new(3, 3)
+            ],
             proposed_positions
         );
     }
 
     #[test]
     fn propose_nothing_for_lone_elf() {
-        let elves = vec![(0, 0)].into_iter().collect();
+        let elves = vec![Pos::new(0, 0)].into_iter().collect();
         let (proposed, _) = propose(&elves, 0);
         assert_eq!(1, proposed.len());
-        assert!(proposed.contains_key(&(0, 0)));
-        assert_eq!(Some(&(0, 0)), proposed.get(&(0, 0)));
+        assert!(proposed.contains_key(&Pos::new(0, 0)));
+        assert_eq!(Some(&Pos::new(0, 0)), proposed.get(&Pos::new(0, 0)));
     }
 
     #[test]
     fn propose_north_for_two_elves_side_by_side() {
-        let elves = vec![(0, 0), (1, 0)].into_iter().collect();
+        let elves = vec![Pos::new(0, 0), Pos::new(1, 0)].into_iter().collect();
         let (proposed, _) = propose(&elves, 0);
         assert_eq!(2, proposed.len());
         let proposed = proposed.into_values().sorted().collect::<Vec<_>>();
-        assert!(proposed.contains(&(1, -1)));
-        assert!(proposed.contains(&(0, -1)));
+        assert!(proposed.contains(&Pos::new(1, -1)));
+        assert!(proposed.contains(&Pos::new(0, -1)));
     }
 
     #[test]
     fn propose_south_and_west_for_two_elves_diagonally_side_by_side() {
-        let elves = vec![(0, 0), (1, 1)].into_iter().collect();
-        let (proposed, _) = propose(&elves, South as i32);
+        let elves = vec![Pos::new(0, 0), Pos::new(1, 1)].into_iter().collect();
+        // the second round, which considers moving south first
+        let (proposed, _) = propose(&elves, 1);
         dbg!(&proposed);
         assert_eq!(2, proposed.len());
         let proposed = proposed.into_values().sorted().collect::<Vec<_>>();
-        assert!(proposed.contains(&(-1, 0)));
-        assert!(proposed.contains(&(1, 2)));
+        assert!(proposed.contains(&Pos::new(-1, 0)));
+        assert!(proposed.contains(&Pos::new(1, 2)));
     }
 
     #[test]
     fn all_elves_have_neighbours_in_small() {
-        let elves = parse_input("small.txt");
+        let elves = parse_input(&read("small.txt"));
         assert!(elves.iter().all(|pos| !no_neighbours(&elves, pos)));
     }
 
     #[test]
     fn all_elves_have_neighbours_in_sample() {
-        let elves = parse_input("sample.txt");
+        let elves = parse_input(&read("sample.txt"));
         assert!(elves.iter().all(|pos| !no_neighbours(&elves, pos)));
     }
 
     #[test]
     fn lone_elf_has_no_neighbours() {
-        let elves = vec![(0, 0)].into_iter().collect();
-        assert!(no_neighbours(&elves, &(0, 0)));
+        let elves = vec![Pos::new(0, 0)].into_iter().collect();
+        assert!(no_neighbours(&elves, &Pos::new(0, 0)));
     }
 
     #[test]
     fn part1_sample() {
-        assert_eq!(110, part1("sample.txt"));
+        assert_eq!(110, Day::solve(Part::One, &read("sample.txt")));
     }
 
     #[test]
     fn part1_input() {
-        assert_eq!(4045, part1("input.txt"));
+        assert_eq!(4045, Day::solve(Part::One, &read("input.txt")));
     }
 
     #[test]
     fn part2_sample() {
-        assert_eq!(20, part2("sample.txt"));
+        assert_eq!(20, Day::solve(Part::Two, &read("sample.txt")));
     }
 
     #[test]
     fn part2_input() {
-        assert_eq!(963, part2("input.txt"));
+        assert_eq!(963, Day::solve(Part::Two, &read("input.txt")));
     }
 }
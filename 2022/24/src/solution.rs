@@ -1,4 +1,4 @@
-use std::fs::read_to_string;
+use aoc_core::{Answer, Solution};
 use std::str::FromStr;
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
@@ -10,7 +10,7 @@ enum Direction {
 }
 
 #[derive(Clone, Eq, PartialEq, Hash)]
-struct Valley {
+pub struct Valley {
     width: usize,
     height: usize,
     blizzards: Vec<(usize, usize, Direction)>,
@@ -18,11 +18,7 @@ struct Valley {
 }
 
 fn usize_abs_diff(a: usize, b: usize) -> usize {
-    if a > b {
-        a - b
-    } else {
-        b - a
-    }
+    a.abs_diff(b)
 }
 
 impl FromStr for Valley {
@@ -161,42 +157,51 @@ impl Valley {
     }
 }
 
-pub fn part1(input: &str) -> usize {
-    let mut valley = read_to_string(input).unwrap().parse::<Valley>().unwrap();
+pub struct Day;
 
-    1 + valley.find_shortest_path((0, 0), (valley.width - 1, valley.height - 1))
-}
+impl Solution for Day {
+    type Input = Valley;
+
+    fn parse(input: &str) -> Self::Input {
+        input.parse().unwrap()
+    }
 
-pub fn part2(input: &str) -> usize {
-    let mut valley = read_to_string(input).unwrap().parse::<Valley>().unwrap();
+    fn part1(valley: &Self::Input) -> Answer {
+        let mut valley = valley.clone();
 
-    let there = valley.find_shortest_path((0, 0), (valley.width - 1, valley.height - 1));
+        (1 + valley.find_shortest_path((0, 0), (valley.width - 1, valley.height - 1))).into()
+    }
+
+    fn part2(valley: &Self::Input) -> Answer {
+        let mut valley = valley.clone();
 
-    let and_back = valley.find_shortest_path((valley.width - 1, valley.height - 1), (0, 0));
+        let there = valley.find_shortest_path((0, 0), (valley.width - 1, valley.height - 1));
 
-    let and_there_again = valley.find_shortest_path((0, 0), (valley.width - 1, valley.height - 1));
+        let and_back = valley.find_shortest_path((valley.width - 1, valley.height - 1), (0, 0));
 
-    1 + there + and_back + and_there_again
+        let and_there_again =
+            valley.find_shortest_path((0, 0), (valley.width - 1, valley.height - 1));
+
+        (1 + there + and_back + and_there_again).into()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_core::{read, Part};
     use test_case::test_case;
 
     #[test_case("simple.txt" => (6, 2, 2))]
     #[test_case("sample.txt" => (6, 4, 19))]
     fn parsing(input: &str) -> (usize, usize, usize) {
-        let valley = read_to_string(input).unwrap().parse::<Valley>().unwrap();
+        let valley = read(input).parse::<Valley>().unwrap();
         (valley.width, valley.height, valley.blizzards.len())
     }
 
     #[test]
     fn moving_blizzards() {
-        let valley = read_to_string("simple.txt")
-            .unwrap()
-            .parse::<Valley>()
-            .unwrap();
+        let valley = read("simple.txt").parse::<Valley>().unwrap();
         let blizzards = valley.move_blizzards().blizzards;
         assert_eq!(2, blizzards.len());
         assert!(blizzards.contains(&(0, 0, Direction::Left)));
@@ -205,10 +210,7 @@ mod tests {
 
     #[test]
     fn is_free() {
-        let mut valley = read_to_string("simple.txt")
-            .unwrap()
-            .parse::<Valley>()
-            .unwrap();
+        let mut valley = read("simple.txt").parse::<Valley>().unwrap();
         assert!(!valley.is_free(0, 0));
         valley.blizzards = valley.move_blizzards().blizzards;
         assert!(!valley.is_free(0, 0));
@@ -218,26 +220,26 @@ mod tests {
 
     #[test]
     fn part1_simple() {
-        assert_eq!(9, part1("simple.txt"));
+        assert_eq!(9, Day::solve(Part::One, &read("simple.txt")));
     }
 
     #[test]
     fn part1_sample() {
-        assert_eq!(18, part1("sample.txt"));
+        assert_eq!(18, Day::solve(Part::One, &read("sample.txt")));
     }
 
     #[test]
     fn part1_input() {
-        assert_eq!(266, part1("input.txt"));
+        assert_eq!(266, Day::solve(Part::One, &read("input.txt")));
     }
 
     #[test]
     fn part2_sample() {
-        assert_eq!(54, part2("sample.txt"));
+        assert_eq!(54, Day::solve(Part::Two, &read("sample.txt")));
     }
 
     #[test]
     fn part2_input() {
-        assert_eq!(853, part2("input.txt"));
+        assert_eq!(853, Day::solve(Part::Two, &read("input.txt")));
     }
 }
@@ -1,6 +1,6 @@
 use anyhow::bail;
+use aoc_core::{Answer, Solution};
 use itertools::Itertools;
-use std::fs::read_to_string;
 use std::ops::Add;
 
 #[derive(Debug, Clone, Copy)]
@@ -105,20 +105,31 @@ fn sum(a: &str, b: &str) -> String {
     result.chars().rev().collect()
 }
 
-pub fn part1(input: &str) -> String {
-    let input = read_to_string(input).expect("input");
-    input
-        .lines()
-        .fold("0".to_string(), |acc, line| sum(acc.as_str(), line))
-}
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Self::Input {
+        input.lines().map(String::from).collect()
+    }
 
-pub fn part2(_: &str) -> String {
-    "Merry X-mas!".to_string()
+    fn part1(numbers: &Self::Input) -> Answer {
+        numbers
+            .iter()
+            .fold("0".to_string(), |acc, line| sum(acc.as_str(), line))
+            .into()
+    }
+
+    fn part2(_: &Self::Input) -> Answer {
+        "Merry X-mas!".into()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_core::{read, Part};
     use test_case::test_case;
 
     #[test_case("0", "0" => "0"; "0+0=0")]
@@ -141,11 +152,14 @@ mod tests {
 
     #[test]
     fn part1_sample() {
-        assert_eq!("2=-1=0", part1("sample.txt"));
+        assert_eq!("2=-1=0", Day::solve(Part::One, &read("sample.txt")));
     }
 
     #[test]
     fn part1_input() {
-        assert_eq!("20-1-0=-2=-2220=0011", part1("input.txt"));
+        assert_eq!(
+            "20-1-0=-2=-2220=0011",
+            Day::solve(Part::One, &read("input.txt"))
+        );
     }
 }
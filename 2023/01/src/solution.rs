@@ -1,32 +1,42 @@
-use std::fs::read_to_string;
+use aoc_core::{Answer, Solution};
 
-pub fn part1(input: &str) -> u32 {
-    let input = read_to_string(input).unwrap();
-    let lines = input.trim().lines();
-    lines
-        .map(|line| {
-            let nums = line
-                .chars()
-                .filter_map(|c| c.to_digit(10).and(Some(c)))
-                .collect::<Vec<_>>();
-            let f = nums.first().unwrap();
-            let l = nums.last().unwrap();
-            format!("{f}{l}").parse::<u32>().unwrap()
-        })
-        .sum()
-}
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Self::Input {
+        input.trim().lines().map(String::from).collect()
+    }
 
-pub fn part2(input: &str) -> u32 {
-    let input = read_to_string(input).unwrap();
-    let input = input.trim().to_lowercase();
-    let lines = input.lines();
-    lines
-        .map(|line| {
-            let f: char = find_first(line);
-            let l: char = find_last(line);
-            format!("{f}{l}").parse::<u32>().unwrap()
-        })
-        .sum()
+    fn part1(lines: &Self::Input) -> Answer {
+        lines
+            .iter()
+            .map(|line| {
+                let nums = line
+                    .chars()
+                    .filter_map(|c| c.to_digit(10).and(Some(c)))
+                    .collect::<Vec<_>>();
+                let f = nums.first().unwrap();
+                let l = nums.last().unwrap();
+                format!("{f}{l}").parse::<u32>().unwrap()
+            })
+            .sum::<u32>()
+            .into()
+    }
+
+    fn part2(lines: &Self::Input) -> Answer {
+        lines
+            .iter()
+            .map(|line| line.to_lowercase())
+            .map(|line| {
+                let f: char = find_first(&line);
+                let l: char = find_last(&line);
+                format!("{f}{l}").parse::<u32>().unwrap()
+            })
+            .sum::<u32>()
+            .into()
+    }
 }
 
 fn find_last(line: &str) -> char {
@@ -93,24 +103,25 @@ fn find_first(line: &str) -> char {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_core::{read, Part};
 
     #[test]
     fn part1_sample() {
-        assert_eq!(142, part1("sample.txt"));
+        assert_eq!(142, Day::solve(Part::One, &read("sample.txt")));
     }
 
     #[test]
     fn part1_input() {
-        assert_eq!(54450, part1("input.txt"));
+        assert_eq!(54450, Day::solve(Part::One, &read("input.txt")));
     }
 
     #[test]
     fn part2_sample2() {
-        assert_eq!(281, part2("sample2.txt"));
+        assert_eq!(281, Day::solve(Part::Two, &read("sample2.txt")));
     }
 
     #[test]
     fn part2_input() {
-        assert_eq!(54265, part2("input.txt"));
+        assert_eq!(54265, Day::solve(Part::Two, &read("input.txt")));
     }
 }
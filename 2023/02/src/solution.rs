@@ -1,12 +1,11 @@
 use std::collections::HashMap;
-use std::fs::read_to_string;
 
+use aoc_core::{Answer, Solution};
 use scan_fmt::scan_fmt;
 
 type Game = Vec<Vec<(i32, String)>>;
 
 fn parse_games(input: &str) -> Vec<(i32, Game)> {
-    let input = read_to_string(input).unwrap();
     let lines = input.lines();
 
     lines
@@ -28,35 +27,47 @@ fn parse_games(input: &str) -> Vec<(i32, Game)> {
         .collect::<Vec<_>>()
 }
 
-pub fn part1(input: &str) -> i32 {
-    let games = parse_games(input);
-
-    games
-        .into_iter()
-        .filter(|(_, game)| {
-            game.iter().all(|round| {
-                round.iter().all(|(count, colour)| match colour.as_str() {
-                    "red" => *count <= 12,
-                    "green" => *count <= 13,
-                    "blue" => *count <= 14,
-                    _ => panic!("Unknown colour: {}", colour),
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<(i32, Game)>;
+
+    fn parse(input: &str) -> Self::Input {
+        parse_games(input)
+    }
+
+    fn part1(games: &Self::Input) -> Answer {
+        games
+            .iter()
+            .filter(|(_, game)| {
+                game.iter().all(|round| {
+                    round.iter().all(|(count, colour)| match colour.as_str() {
+                        "red" => *count <= 12,
+                        "green" => *count <= 13,
+                        "blue" => *count <= 14,
+                        _ => panic!("Unknown colour: {}", colour),
+                    })
                 })
             })
-        })
-        .map(|(id, _)| id)
-        .sum()
-}
-
-pub fn part2(input: &str) -> i32 {
-    let games = parse_games(input);
+            .map(|(id, _)| id)
+            .sum::<i32>()
+            .into()
+    }
 
-    games.into_iter().map(|(_, game)| solve_game(game)).sum()
+    fn part2(games: &Self::Input) -> Answer {
+        games
+            .iter()
+            .map(|(_, game)| solve_game(game))
+            .sum::<i32>()
+            .into()
+    }
 }
 
-fn solve_game(game: Game) -> i32 {
-    game.into_iter()
+fn solve_game(game: &Game) -> i32 {
+    game.iter()
         .flatten()
         .fold(HashMap::new(), |mut acc, (count, colour)| {
+            let count = *count;
             acc.entry(colour)
                 .and_modify(|c: &mut i32| *c = (*c).max(count))
                 .or_insert(count);
@@ -69,6 +80,7 @@ fn solve_game(game: Game) -> i32 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_core::{read, Part};
 
     #[test]
     fn solving_sample_first_game() {
@@ -77,7 +89,7 @@ mod tests {
             vec![(1, "red".to_string()), (2, "green".to_string())],
             vec![(6, "blue".to_string()), (2, "green".to_string())],
         ];
-        assert_eq!(48, solve_game(game));
+        assert_eq!(48, solve_game(&game));
     }
 
     #[test]
@@ -91,26 +103,26 @@ mod tests {
             ],
             vec![(1, "green".to_string()), (1, "blue".to_string())],
         ];
-        assert_eq!(12, solve_game(game));
+        assert_eq!(12, solve_game(&game));
     }
 
     #[test]
     fn part1_sample() {
-        assert_eq!(8, part1("sample.txt"));
+        assert_eq!(8, Day::solve(Part::One, &read("sample.txt")));
     }
 
     #[test]
     fn part1_input() {
-        assert_eq!(2285, part1("input.txt"));
+        assert_eq!(2285, Day::solve(Part::One, &read("input.txt")));
     }
 
     #[test]
     fn part2_sample() {
-        assert_eq!(2286, part2("sample.txt"));
+        assert_eq!(2286, Day::solve(Part::Two, &read("sample.txt")));
     }
 
     #[test]
     fn part2_input() {
-        assert_eq!(77021, part2("input.txt"));
+        assert_eq!(77021, Day::solve(Part::Two, &read("input.txt")));
     }
 }
@@ -1,8 +1,8 @@
 
 
 
+use aoc_core::{Answer, Solution};
 use std::collections::{HashMap, HashSet};
-use std::fs::read_to_string;
 use std::str::FromStr;
 
 #[derive(Debug, PartialEq, Eq, Hash)]
@@ -18,7 +18,7 @@ struct Number {
     end: Pos,
 }
 
-struct Schematic {
+pub struct Schematic {
     numbers: Vec<Number>,
     parts: HashMap<Pos, char>,
 }
@@ -37,7 +37,7 @@ impl Schematic {
                 let maxy = n.end.y + 1;
                 for y in miny..=maxy {
                     for x in minx..=maxx {
-                        if self.parts.get(&Pos { x, y }).is_some() {
+                        if self.parts.contains_key(&Pos { x, y }) {
                             return true;
                         }
                     }
@@ -144,38 +144,45 @@ impl FromStr for Schematic {
     }
 }
 
-pub fn part1(input: &str) -> usize {
-    let input = read_to_string(input).unwrap();
-    let schematic = Schematic::from_str(&input).unwrap();
-    schematic.parts_sum()
-}
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Schematic;
+
+    fn parse(input: &str) -> Self::Input {
+        Schematic::from_str(input).unwrap()
+    }
 
-pub fn part2(input: &str) -> usize {
-    let input = read_to_string(input).unwrap();
-    let schematic = Schematic::from_str(&input).unwrap();
-    schematic.gears_sum()
+    fn part1(schematic: &Self::Input) -> Answer {
+        schematic.parts_sum().into()
+    }
+
+    fn part2(schematic: &Self::Input) -> Answer {
+        schematic.gears_sum().into()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_core::{read, Part};
 
     #[test]
     fn part1_sample() {
-        assert_eq!(4361, part1("sample.txt"));
+        assert_eq!(4361, Day::solve(Part::One, &read("sample.txt")));
     }
 
     #[test]
     fn part1_input() {
-        assert_eq!(531932, part1("input.txt"));
+        assert_eq!(531932, Day::solve(Part::One, &read("input.txt")));
     }
     #[test]
     fn part2_sample() {
-        assert_eq!(467835, part2("sample.txt"));
+        assert_eq!(467835, Day::solve(Part::Two, &read("sample.txt")));
     }
 
     #[test]
     fn part2_input() {
-        assert_eq!(73646890, part2("input.txt"));
+        assert_eq!(73646890, Day::solve(Part::Two, &read("input.txt")));
     }
 }
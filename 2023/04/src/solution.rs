@@ -1,11 +1,11 @@
 
+use aoc_core::{Answer, Solution};
 use scan_fmt::scan_fmt;
 use std::collections::{HashMap, HashSet};
-use std::fs::read_to_string;
 use std::str::FromStr;
 
 #[derive(Debug)]
-struct Card {
+pub struct Card {
     id: usize,
     winners: HashSet<usize>,
     my: Vec<usize>,
@@ -33,7 +33,7 @@ impl FromStr for Card {
 }
 
 impl Card {
-    fn worth(self) -> usize {
+    fn worth(&self) -> usize {
         let count = self.count_winners();
         if count > 0 {
             2usize.pow(count - 1)
@@ -42,64 +42,71 @@ impl Card {
         }
     }
 
-    fn count_winners(self) -> u32 {
+    fn count_winners(&self) -> u32 {
         self.my.iter().filter(|n| self.winners.contains(n)).count() as u32
     }
 }
 
-pub fn part1(input: &str) -> usize {
-    let input = read_to_string(input).expect("input file");
-    input
-        .trim()
-        .lines()
-        .map(|l| l.parse::<Card>().expect("a card"))
-        .map(Card::worth)
-        .sum()
-}
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Card>;
+
+    fn parse(input: &str) -> Self::Input {
+        input
+            .trim()
+            .lines()
+            .map(|l| l.parse::<Card>().expect("a card"))
+            .collect()
+    }
 
-pub fn part2(input: &str) -> usize {
-    let input = read_to_string(input).expect("input file");
-    let mut wins: HashMap<usize, usize> = HashMap::new();
-    input
-        .trim()
-        .lines()
-        .map(|l| l.parse::<Card>().expect("a card"))
-        .rev()
-        .map(|c| {
-            let id = c.id;
-            let count_winners = c.count_winners() as usize;
-            let value: usize = ((id + 1)..=(id + count_winners))
-                .map(|w| (wins.get(&w).expect("card has been processed")))
-                .sum();
-            let value = value + 1;
-
-            wins.insert(id, value);
-            value
-        })
-        .sum()
+    fn part1(cards: &Self::Input) -> Answer {
+        cards.iter().map(Card::worth).sum::<usize>().into()
+    }
+
+    fn part2(cards: &Self::Input) -> Answer {
+        let mut wins: HashMap<usize, usize> = HashMap::new();
+        cards
+            .iter()
+            .rev()
+            .map(|c| {
+                let id = c.id;
+                let count_winners = c.count_winners() as usize;
+                let value: usize = ((id + 1)..=(id + count_winners))
+                    .map(|w| wins.get(&w).expect("card has been processed"))
+                    .sum();
+                let value = value + 1;
+
+                wins.insert(id, value);
+                value
+            })
+            .sum::<usize>()
+            .into()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_core::{read, Part};
 
     #[test]
     fn part1_sample() {
-        assert_eq!(13, part1("sample.txt"));
+        assert_eq!(13, Day::solve(Part::One, &read("sample.txt")));
     }
 
     #[test]
     fn part1_input() {
-        assert_eq!(15205, part1("input.txt"));
+        assert_eq!(15205, Day::solve(Part::One, &read("input.txt")));
     }
 
     #[test]
     fn part2_sample() {
-        assert_eq!(30, part2("sample.txt"));
+        assert_eq!(30, Day::solve(Part::Two, &read("sample.txt")));
     }
 
     #[test]
     fn part2_input() {
-        assert_eq!(6189740, part2("input.txt"));
+        assert_eq!(6189740, Day::solve(Part::Two, &read("input.txt")));
     }
 }
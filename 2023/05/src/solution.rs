@@ -1,6 +1,6 @@
-
+use aoc_core::{Answer, Solution};
 use scan_fmt::scan_fmt;
-use std::fs::read_to_string;
+use std::ops::Range;
 use std::str::FromStr;
 
 struct MapperEntry {
@@ -9,7 +9,7 @@ struct MapperEntry {
     length: usize,
 }
 
-struct Mapper {
+pub struct Mapper {
     entries: Vec<MapperEntry>,
 }
 
@@ -43,68 +43,142 @@ impl Mapper {
         }
         value
     }
+
+    /// Maps whole ranges at once: each range is split at the edges of the
+    /// entries, and the parts no entry covers map to themselves.
+    fn map_ranges(&self, ranges: &[Range<usize>]) -> Vec<Range<usize>> {
+        let mut unmapped = ranges.to_vec();
+        let mut mapped = Vec::new();
+        for entry in &self.entries {
+            let source = entry.source_start..entry.source_start + entry.length;
+            let mut rest = Vec::new();
+            for range in unmapped {
+                let start = range.start.max(source.start);
+                let end = range.end.min(source.end);
+                if start < end {
+                    mapped.push(
+                        entry.destination_start + start - source.start
+                            ..entry.destination_start + end - source.start,
+                    );
+                    if range.start < start {
+                        rest.push(range.start..start);
+                    }
+                    if end < range.end {
+                        rest.push(end..range.end);
+                    }
+                } else {
+                    rest.push(range);
+                }
+            }
+            unmapped = rest;
+        }
+        mapped.extend(unmapped);
+        mapped
+    }
 }
 
-pub fn part1(input: &str) -> usize {
-    let input = read_to_string(input).unwrap();
-    let mut blocks = input.split("\n\n");
-    let seeds = blocks.next().unwrap();
-    let seeds = seeds[7..]
-        .split(' ')
-        .map(str::parse::<usize>)
-        .map(Result::unwrap);
-    let mapppers = blocks
-        .map(|b| b.parse::<Mapper>().unwrap())
-        .collect::<Vec<_>>();
-
-    seeds
-        .map(|s| mapppers.iter().fold(s, |s, m| m.map(s)))
-        .min()
-        .expect("a min value")
+pub struct Almanac {
+    seeds: Vec<usize>,
+    mappers: Vec<Mapper>,
 }
 
-pub fn part2(input: &str) -> usize {
-    let input = read_to_string(input).unwrap();
-    let mut blocks = input.split("\n\n");
-    let seeds = blocks.next().unwrap();
-    let seeds = seeds[7..]
-        .split(' ')
-        .map(str::parse::<usize>)
-        .map(Result::unwrap)
-        .collect::<Vec<_>>();
-    let seeds = seeds.chunks(2).flat_map(|w| w[0]..(w[0] + w[1]));
-
-    let mapppers = blocks
-        .map(|b| b.parse::<Mapper>().unwrap())
-        .collect::<Vec<_>>();
-
-    seeds
-        .map(|s| mapppers.iter().fold(s, |s, m| m.map(s)))
-        .min()
-        .expect("a min value")
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Almanac;
+
+    fn parse(input: &str) -> Self::Input {
+        let mut blocks = input.split("\n\n");
+        let seeds = blocks.next().unwrap();
+        let seeds = seeds[7..]
+            .split(' ')
+            .map(str::parse::<usize>)
+            .map(Result::unwrap)
+            .collect();
+        let mappers = blocks.map(|b| b.parse::<Mapper>().unwrap()).collect();
+
+        Almanac { seeds, mappers }
+    }
+
+    fn part1(almanac: &Self::Input) -> Answer {
+        almanac
+            .seeds
+            .iter()
+            .map(|s| almanac.mappers.iter().fold(*s, |s, m| m.map(s)))
+            .min()
+            .expect("a min value")
+            .into()
+    }
+
+    fn part2(almanac: &Self::Input) -> Answer {
+        let seeds: Vec<_> = almanac
+            .seeds
+            .chunks(2)
+            .map(|w| w[0]..(w[0] + w[1]))
+            .collect();
+
+        almanac
+            .mappers
+            .iter()
+            .fold(seeds, |ranges, m| m.map_ranges(&ranges))
+            .iter()
+            .map(|range| range.start)
+            .min()
+            .expect("a min value")
+            .into()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_core::{read, Part};
+
+    #[test]
+    fn mapping_ranges() {
+        let mapper: Mapper = "map:\n50 98 2\n52 50 48".parse().unwrap();
+        assert_eq!(
+            vec![50..52, 52..54, 45..50],
+            mapper.map_ranges(&[45..52, 98..100])
+        );
+    }
+
+    #[test]
+    fn mapping_ranges_like_values() {
+        let almanac = Day::parse(&read("sample.txt"));
+        for pair in almanac.seeds.chunks(2) {
+            let seeds = pair[0]..pair[0] + pair[1];
+            let ranges = almanac
+                .mappers
+                .iter()
+                .fold(vec![seeds.clone()], |ranges, m| m.map_ranges(&ranges));
+            let mut from_ranges: Vec<usize> = ranges.into_iter().flatten().collect();
+            let mut from_values: Vec<usize> = seeds
+                .map(|s| almanac.mappers.iter().fold(s, |s, m| m.map(s)))
+                .collect();
+            from_ranges.sort_unstable();
+            from_values.sort_unstable();
+            assert_eq!(from_values, from_ranges);
+        }
+    }
 
     #[test]
     fn part1_sample() {
-        assert_eq!(35, part1("sample.txt"));
+        assert_eq!(35, Day::solve(Part::One, &read("sample.txt")));
     }
 
     #[test]
     fn part1_input() {
-        assert_eq!(486613012, part1("input.txt"));
+        assert_eq!(486613012, Day::solve(Part::One, &read("input.txt")));
     }
 
     #[test]
     fn part2_sample() {
-        assert_eq!(46, part2("sample.txt"));
+        assert_eq!(46, Day::solve(Part::Two, &read("sample.txt")));
     }
 
     #[test]
     fn part2_input() {
-        assert_eq!(56931769, part2("input.txt"));
+        assert_eq!(56931769, Day::solve(Part::Two, &read("input.txt")));
     }
 }
@@ -1,6 +1,6 @@
 
+use aoc_core::{Answer, Solution};
 use scan_fmt::scan_fmt;
-use std::fs::read_to_string;
 
 type Race = (usize, usize);
 
@@ -42,38 +42,49 @@ fn wins(race: Race) -> usize {
         .count()
 }
 
-pub fn part1(input: &str) -> usize {
-    let input = read_to_string(input).unwrap();
-    let races = parse(input.trim());
-    races.into_iter().map(wins).product()
-}
+pub struct Day;
+
+impl Solution for Day {
+    // Part 2 reads the numbers with the spaces taken out, so each part
+    // parses the sheet its own way
+    type Input = String;
+
+    fn parse(input: &str) -> Self::Input {
+        input.trim().to_string()
+    }
 
-pub fn part2(input: &str) -> usize {
-    let input = read_to_string(input).unwrap();
-    wins(parse2(input.trim()))
+    fn part1(input: &Self::Input) -> Answer {
+        let races = parse(input);
+        races.into_iter().map(wins).product::<usize>().into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        wins(parse2(input)).into()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_core::{read, Part};
 
     #[test]
     fn part1_sample() {
-        assert_eq!(288, part1("sample.txt"));
+        assert_eq!(288, Day::solve(Part::One, &read("sample.txt")));
     }
 
     #[test]
     fn part1_input() {
-        assert_eq!(2374848, part1("input.txt"));
+        assert_eq!(2374848, Day::solve(Part::One, &read("input.txt")));
     }
 
     #[test]
     fn part2_sample() {
-        assert_eq!(71503, part2("sample.txt"));
+        assert_eq!(71503, Day::solve(Part::Two, &read("sample.txt")));
     }
 
     #[test]
     fn part2_input() {
-        assert_eq!(39132886, part2("input.txt"));
+        assert_eq!(39132886, Day::solve(Part::Two, &read("input.txt")));
     }
 }
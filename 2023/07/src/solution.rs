@@ -1,9 +1,9 @@
+use aoc_core::{Answer, Solution};
 use itertools::Itertools;
 use std::collections::HashMap;
-use std::fs::read_to_string;
 
 #[derive(Debug, Ord, PartialOrd, Eq, PartialEq, Copy, Clone)]
-enum Card {
+pub enum Card {
     Joker,
     Two,
     Three,
@@ -116,6 +116,12 @@ impl EvaluatedHand {
 
 impl PartialOrd for EvaluatedHand {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for EvaluatedHand {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
         let value_cmp = self.value.cmp(&other.value);
         match value_cmp {
             std::cmp::Ordering::Equal => self
@@ -124,32 +130,23 @@ impl PartialOrd for EvaluatedHand {
                 .zip(other.hand.iter())
                 .map(|(self_card, other_card)| self_card.cmp(other_card))
                 .find(|&cmp| cmp != std::cmp::Ordering::Equal)
-                .or(Some(std::cmp::Ordering::Equal)),
-            _ => Some(value_cmp),
+                .unwrap_or(std::cmp::Ordering::Equal),
+            _ => value_cmp,
         }
     }
 }
 
-impl Ord for EvaluatedHand {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        self.partial_cmp(other).unwrap()
-    }
-}
-
-pub fn evaluate(input: &str, jacks_wild: bool) -> usize {
-    let input = read_to_string(input).unwrap();
-    input
-        .trim()
-        .lines()
-        .map(|line| {
-            let (hand, bid) = line.split_once(' ').expect("a valid line");
+pub fn evaluate(hands: &[(Hand, usize)], jacks_wild: bool) -> usize {
+    hands
+        .iter()
+        .map(|(hand, bid)| {
             (
                 if jacks_wild {
-                    EvaluatedHand::from_wild_jacks(parse_hand(hand))
+                    EvaluatedHand::from_wild_jacks(hand.clone())
                 } else {
-                    EvaluatedHand::from(parse_hand(hand))
+                    EvaluatedHand::from(hand.clone())
                 },
-                bid.parse::<usize>().expect("a valid bid"),
+                *bid,
             )
         })
         .sorted()
@@ -157,16 +154,36 @@ pub fn evaluate(input: &str, jacks_wild: bool) -> usize {
         .map(|(i, (_hand, bid))| (i + 1) * bid)
         .sum()
 }
-pub fn part1(input: &str) -> usize {
-    evaluate(input, false)
-}
-pub fn part2(input: &str) -> usize {
-    evaluate(input, true)
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<(Hand, usize)>;
+
+    fn parse(input: &str) -> Self::Input {
+        input
+            .trim()
+            .lines()
+            .map(|line| {
+                let (hand, bid) = line.split_once(' ').expect("a valid line");
+                (parse_hand(hand), bid.parse().expect("a valid bid"))
+            })
+            .collect()
+    }
+
+    fn part1(hands: &Self::Input) -> Answer {
+        evaluate(hands, false).into()
+    }
+
+    fn part2(hands: &Self::Input) -> Answer {
+        evaluate(hands, true).into()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_core::{read, Part};
     use test_case::test_case;
 
     #[test_case("AAAAA" => (1, 0, 0, 0, 0))]
@@ -188,21 +205,21 @@ mod tests {
 
     #[test]
     fn part1_sample() {
-        assert_eq!(6440, part1("sample.txt"));
+        assert_eq!(6440, Day::solve(Part::One, &read("sample.txt")));
     }
 
     #[test]
     fn part1_input() {
-        assert_eq!(248836197, part1("input.txt"));
+        assert_eq!(248836197, Day::solve(Part::One, &read("input.txt")));
     }
 
     #[test]
     fn part2_sample() {
-        assert_eq!(5905, part2("sample.txt"));
+        assert_eq!(5905, Day::solve(Part::Two, &read("sample.txt")));
     }
 
     #[test]
     fn part2_input() {
-        assert_eq!(251195607, part2("input.txt"));
+        assert_eq!(251195607, Day::solve(Part::Two, &read("input.txt")));
     }
 }
@@ -1,11 +1,12 @@
 use num::Integer;
 use std::collections::HashMap;
-use std::fs::read_to_string;
 
+use aoc_core::{Answer, Solution};
 use scan_fmt::scan_fmt;
 
-pub fn parse(input: &str) -> (String, HashMap<String, (String, String)>) {
-    let input = read_to_string(input).unwrap();
+type Network = HashMap<String, (String, String)>;
+
+pub fn parse(input: &str) -> (String, Network) {
     let mut lines = input.lines();
     let instructions = lines.next().expect("a string").to_string();
     let lines = lines.skip(1);
@@ -19,7 +20,7 @@ pub fn parse(input: &str) -> (String, HashMap<String, (String, String)>) {
     (instructions, map)
 }
 
-pub fn solve(instructions: &str, map: &HashMap<String, (String, String)>, start: &str) -> usize {
+pub fn solve(instructions: &str, map: &Network, start: &str) -> usize {
     let instructions = instructions.chars().cycle();
     let mut loc = start;
     instructions
@@ -40,53 +41,63 @@ pub fn solve(instructions: &str, map: &HashMap<String, (String, String)>, start:
         .expect("to find a path")
         + 1
 }
-pub fn part1(input: &str) -> usize {
-    let (instructions, map) = parse(input);
-    solve(&instructions, &map, "AAA")
-}
 
-pub fn part2(input: &str) -> usize {
-    let (instructions, map) = parse(input);
+pub struct Day;
+
+impl Solution for Day {
+    type Input = (String, Network);
+
+    fn parse(input: &str) -> Self::Input {
+        parse(input)
+    }
+
+    fn part1((instructions, map): &Self::Input) -> Answer {
+        solve(instructions, map, "AAA").into()
+    }
 
-    let starts = map.keys().filter(|k| k.ends_with('A')).collect::<Vec<_>>();
+    fn part2((instructions, map): &Self::Input) -> Answer {
+        let starts = map.keys().filter(|k| k.ends_with('A')).collect::<Vec<_>>();
 
-    starts
-        .into_iter()
-        .map(|start| solve(&instructions, &map, start))
-        .fold(1, |acc, steps| acc.lcm(&steps))
+        starts
+            .into_iter()
+            .map(|start| solve(instructions, map, start))
+            .fold(1, |acc, steps| acc.lcm(&steps))
+            .into()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_core::{read, Part};
 
     #[test]
     fn part1_sample() {
-        assert_eq!(2, part1("sample.txt"));
+        assert_eq!(2, Day::solve(Part::One, &read("sample.txt")));
     }
 
     #[test]
     fn part1_sample2() {
-        assert_eq!(6, part1("sample2.txt"));
+        assert_eq!(6, Day::solve(Part::One, &read("sample2.txt")));
     }
 
     #[test]
     fn part1_input() {
-        assert_eq!(13019, part1("input.txt"));
+        assert_eq!(13019, Day::solve(Part::One, &read("input.txt")));
     }
 
     #[test]
     fn part2_sample3() {
-        assert_eq!(6, part2("sample3.txt"));
+        assert_eq!(6, Day::solve(Part::Two, &read("sample3.txt")));
     }
 
     #[test]
     fn part2_nasty_sample() {
-        assert_eq!(0, part2("nasty_sample.txt"));
+        assert_eq!(0, Day::solve(Part::Two, &read("nasty_sample.txt")));
     }
 
     #[test]
     fn part2_input() {
-        assert_eq!(13524038372771, part2("input.txt"));
+        assert_eq!(13524038372771, Day::solve(Part::Two, &read("input.txt")));
     }
 }
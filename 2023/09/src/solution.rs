@@ -1,4 +1,4 @@
-use std::fs::read_to_string;
+use aoc_core::{Answer, Solution};
 
 fn next(seq: &[i32]) -> i32 {
     let next_seq = seq.windows(2).map(|w| w[1] - w[0]).collect::<Vec<_>>();
@@ -10,38 +10,41 @@ fn next(seq: &[i32]) -> i32 {
     }
 }
 
-pub fn part1(input: &str) -> i32 {
-    let input = read_to_string(input).unwrap();
-    input
-        .trim()
-        .lines()
-        .map(|l| {
-            l.split(' ')
-                .map(|n| n.parse::<i32>().unwrap())
-                .collect::<Vec<_>>()
-        })
-        .map(|seq| next(&seq))
-        .sum()
-}
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Vec<i32>>;
+
+    fn parse(input: &str) -> Self::Input {
+        input
+            .trim()
+            .lines()
+            .map(|l| {
+                l.split(' ')
+                    .map(|n| n.parse::<i32>().unwrap())
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
 
-pub fn part2(input: &str) -> i32 {
-    let input = read_to_string(input).unwrap();
-    input
-        .trim()
-        .lines()
-        .map(|l| {
-            l.split(' ')
-                .map(|n| n.parse::<i32>().unwrap())
-                .collect::<Vec<_>>()
-        })
-        .map(|seq| seq.into_iter().rev().collect::<Vec<_>>())
-        .map(|seq| next(&seq))
-        .sum()
+    fn part1(sequences: &Self::Input) -> Answer {
+        sequences.iter().map(|seq| next(seq)).sum::<i32>().into()
+    }
+
+    fn part2(sequences: &Self::Input) -> Answer {
+        sequences
+            .iter()
+            .map(|seq| seq.iter().rev().copied().collect::<Vec<_>>())
+            .map(|seq| next(&seq))
+            .sum::<i32>()
+            .into()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_core::{read, Part};
 
     #[test]
     fn one_sequence_prev() {
@@ -71,21 +74,21 @@ mod tests {
 
     #[test]
     fn part1_sample() {
-        assert_eq!(114, part1("sample.txt"));
+        assert_eq!(114, Day::solve(Part::One, &read("sample.txt")));
     }
 
     #[test]
     fn part1_input() {
-        assert_eq!(1953784198, part1("input.txt"));
+        assert_eq!(1953784198, Day::solve(Part::One, &read("input.txt")));
     }
 
     #[test]
     fn part2_sample() {
-        assert_eq!(2, part2("sample.txt"));
+        assert_eq!(2, Day::solve(Part::Two, &read("sample.txt")));
     }
 
     #[test]
     fn part2_input() {
-        assert_eq!(957, part2("input.txt"));
+        assert_eq!(957, Day::solve(Part::Two, &read("input.txt")));
     }
 }
@@ -1,27 +1,9 @@
+use aoc_core::{Answer, Solution};
+use aoc_grid::Direction;
 use std::collections::{HashMap, HashSet};
-use std::fs::read_to_string;
-
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
-enum Direction {
-    North,
-    East,
-    South,
-    West,
-}
-
-impl Direction {
-    fn opposite(&self) -> Direction {
-        match self {
-            &Direction::North => Direction::South,
-            Direction::East => Direction::West,
-            Direction::South => Direction::North,
-            Direction::West => Direction::East,
-        }
-    }
-}
 
 #[derive(Debug, PartialEq, Eq)]
-enum Pipe {
+pub enum Pipe {
     Empty,
     NS,
     EW,
@@ -47,20 +29,33 @@ impl Pipe {
         }
     }
 
-    fn connects_from(&self, dir: Direction) -> bool {
+    fn openings(&self) -> &'static [Direction] {
         match self {
-            Pipe::Empty => false,
-            Pipe::NS => dir == Direction::North || dir == Direction::South,
-            Pipe::EW => dir == Direction::East || dir == Direction::West,
-            Pipe::SW => dir == Direction::North || dir == Direction::East,
-            Pipe::SE => dir == Direction::North || dir == Direction::West,
-            Pipe::NW => dir == Direction::South || dir == Direction::East,
-            Pipe::NE => dir == Direction::South || dir == Direction::West,
-            Pipe::Start => true,
+            Pipe::Empty => &[],
+            Pipe::NS => &[Direction::Up, Direction::Down],
+            Pipe::EW => &[Direction::Right, Direction::Left],
+            Pipe::NE => &[Direction::Up, Direction::Right],
+            Pipe::NW => &[Direction::Up, Direction::Left],
+            Pipe::SE => &[Direction::Down, Direction::Right],
+            Pipe::SW => &[Direction::Down, Direction::Left],
+            Pipe::Start => &Direction::ALL,
         }
     }
+
+    /// Whether something moving in `dir` can enter this pipe.
+    fn connects_from(&self, dir: Direction) -> bool {
+        self.openings().contains(&dir.opposite())
+    }
+
+    /// Whether this is the pipe joining exactly `a` and `b`.
+    fn joins(&self, a: Direction, b: Direction) -> bool {
+        let openings = self.openings();
+        openings.len() == 2 && openings.contains(&a) && openings.contains(&b)
+    }
 }
 
+type Pipes = HashMap<(usize, usize), Pipe>;
+
 type Loop = (
     usize,
     usize,
@@ -70,39 +65,27 @@ type Loop = (
     HashSet<(usize, usize)>,
 );
 
-fn find_loop(input: &str) -> Loop {
-    let input = read_to_string(input).unwrap();
-    let map = input
-        .trim()
-        .lines()
-        .enumerate()
-        .flat_map(|(y, line)| {
-            line.chars()
-                .enumerate()
-                .map(move |(x, c)| ((x + 1, y + 1), Pipe::from_char(c)))
-        })
-        .collect::<HashMap<_, _>>();
-
+fn find_loop(map: &Pipes) -> Loop {
     let &start = map
         .iter()
         .find(|(_, pipe)| **pipe == Pipe::Start)
         .unwrap()
         .0;
     let mut steps = 1;
-    let (mut creature1, mut creature2) = find(start, &map);
+    let (mut creature1, mut creature2) = find(start, map);
     let mut tunnel = HashSet::from([start, creature1.0, creature2.0]);
     let mut left = HashSet::new();
     let mut right = HashSet::new();
 
     while creature1.0 != creature2.0 {
-        left.extend(on_left(creature1, &map));
-        right.extend(on_right(creature1, &map));
-        left.extend(on_right(creature2, &map));
-        right.extend(on_left(creature2, &map));
+        left.extend(on_left(creature1, map));
+        right.extend(on_right(creature1, map));
+        left.extend(on_right(creature2, map));
+        right.extend(on_left(creature2, map));
 
         steps += 1;
-        creature1 = find_for(creature1, &map);
-        creature2 = find_for(creature2, &map);
+        creature1 = find_for(creature1, map);
+        creature2 = find_for(creature2, map);
         tunnel.insert(creature1.0);
         tunnel.insert(creature2.0);
     }
@@ -117,92 +100,34 @@ fn find_loop(input: &str) -> Loop {
     (steps, maxx, maxy, tunnel, left, right)
 }
 
-fn on_left(
-    creature: ((usize, usize), Direction),
-    map: &HashMap<(usize, usize), Pipe>,
+/// The tiles on one side of a creature, `turn` being the way to face that side.
+/// A bend away from that side has two of them.
+fn on_side(
+    (pos, dir): ((usize, usize), Direction),
+    map: &Pipes,
+    turn: fn(Direction) -> Direction,
 ) -> Vec<(usize, usize)> {
-    let pipe = map.get(&creature.0).unwrap();
-    match creature.1 {
-        Direction::North => match pipe {
-            Pipe::NS => vec![(creature.0 .0 - 1, creature.0 .1)],
-            Pipe::SE => vec![
-                (creature.0 .0 - 1, creature.0 .1),
-                (creature.0 .0, creature.0 .1 - 1),
-            ],
-            _ => vec![],
-        },
-        Direction::East => match pipe {
-            Pipe::EW => vec![(creature.0 .0, creature.0 .1 - 1)],
-            Pipe::SW => vec![
-                (creature.0 .0, creature.0 .1 - 1),
-                (creature.0 .0 + 1, creature.0 .1),
-            ],
-            _ => vec![],
-        },
-        Direction::South => match pipe {
-            Pipe::NS => vec![(creature.0 .0 + 1, creature.0 .1)],
-            Pipe::NW => vec![
-                (creature.0 .0 + 1, creature.0 .1),
-                (creature.0 .0, creature.0 .1 + 1),
-            ],
-            _ => vec![],
-        },
-        Direction::West => match pipe {
-            Pipe::EW => vec![(creature.0 .0, creature.0 .1 + 1)],
-            Pipe::NE => vec![
-                (creature.0 .0, creature.0 .1 + 1),
-                (creature.0 .0 - 1, creature.0 .1),
-            ],
-            _ => vec![],
-        },
+    let pipe = map.get(&pos).unwrap();
+    let side = turn(dir);
+    let step = |dir: Direction| dir.checked_step(pos).expect("the map is padded");
+    if pipe.joins(dir.opposite(), dir) {
+        vec![step(side)]
+    } else if pipe.joins(dir.opposite(), side.opposite()) {
+        vec![step(side), step(dir)]
+    } else {
+        vec![]
     }
 }
 
-fn on_right(
-    creature: ((usize, usize), Direction),
-    map: &HashMap<(usize, usize), Pipe>,
-) -> Vec<(usize, usize)> {
-    let pipe = map.get(&creature.0).unwrap();
-    match creature.1 {
-        Direction::North => match pipe {
-            Pipe::NS => vec![(creature.0 .0 + 1, creature.0 .1)],
-            Pipe::SW => vec![
-                (creature.0 .0 + 1, creature.0 .1),
-                (creature.0 .0, creature.0 .1 - 1),
-            ],
-            _ => vec![],
-        },
-        Direction::East => match pipe {
-            Pipe::EW => vec![(creature.0 .0, creature.0 .1 + 1)],
-            Pipe::NW => vec![
-                (creature.0 .0, creature.0 .1 + 1),
-                (creature.0 .0 + 1, creature.0 .1),
-            ],
-            _ => vec![],
-        },
-        Direction::South => match pipe {
-            Pipe::NS => vec![(creature.0 .0 - 1, creature.0 .1)],
-            Pipe::NE => vec![
-                (creature.0 .0 - 1, creature.0 .1),
-                (creature.0 .0, creature.0 .1 + 1),
-            ],
-            _ => vec![],
-        },
-        Direction::West => match pipe {
-            Pipe::EW => vec![(creature.0 .0, creature.0 .1 - 1)],
-            Pipe::SE => vec![
-                (creature.0 .0, creature.0 .1 - 1),
-                (creature.0 .0 - 1, creature.0 .1),
-            ],
-            _ => vec![],
-        },
-    }
+fn on_left(creature: ((usize, usize), Direction), map: &Pipes) -> Vec<(usize, usize)> {
+    on_side(creature, map, Direction::turn_left)
 }
 
-fn find_for(
-    creature: ((usize, usize), Direction),
-    map: &HashMap<(usize, usize), Pipe>,
-) -> ((usize, usize), Direction) {
+fn on_right(creature: ((usize, usize), Direction), map: &Pipes) -> Vec<(usize, usize)> {
+    on_side(creature, map, Direction::turn_right)
+}
+
+fn find_for(creature: ((usize, usize), Direction), map: &Pipes) -> ((usize, usize), Direction) {
     let (c1, c2) = find(creature.0, map);
     if c1.1 != creature.1.opposite() {
         c1
@@ -213,46 +138,58 @@ fn find_for(
 
 type Neighbours = (((usize, usize), Direction), ((usize, usize), Direction));
 
-fn find(pos: (usize, usize), map: &HashMap<(usize, usize), Pipe>) -> Neighbours {
+fn find(pos: (usize, usize), map: &Pipes) -> Neighbours {
     let current = map.get(&pos).expect("current");
-    let mut neighbours = vec![];
-    if let Some(pipe) = map.get(&(pos.0, pos.1 - 1)) {
-        if pipe.connects_from(Direction::North) && current.connects_from(Direction::South) {
-            neighbours.push(((pos.0, pos.1 - 1), Direction::North));
-        }
-    }
-    if let Some(pipe) = map.get(&(pos.0, pos.1 + 1)) {
-        if pipe.connects_from(Direction::South) && current.connects_from(Direction::North) {
-            neighbours.push(((pos.0, pos.1 + 1), Direction::South));
-        }
-    }
-    if let Some(pipe) = map.get(&(pos.0 - 1, pos.1)) {
-        if pipe.connects_from(Direction::West) && current.connects_from(Direction::East) {
-            neighbours.push(((pos.0 - 1, pos.1), Direction::West));
-        }
-    }
-    if let Some(pipe) = map.get(&(pos.0 + 1, pos.1)) {
-        if pipe.connects_from(Direction::East) && current.connects_from(Direction::West) {
-            neighbours.push(((pos.0 + 1, pos.1), Direction::East));
-        }
-    }
+    let neighbours = [
+        Direction::Up,
+        Direction::Down,
+        Direction::Left,
+        Direction::Right,
+    ]
+    .into_iter()
+    .filter_map(|dir| {
+        let next = dir.checked_step(pos)?;
+        let pipe = map.get(&next)?;
+        (pipe.connects_from(dir) && current.openings().contains(&dir)).then_some((next, dir))
+    })
+    .collect::<Vec<_>>();
     assert_eq!(neighbours.len(), 2);
     (neighbours[0], neighbours[1])
 }
 
-pub fn part1(input: &str) -> usize {
-    find_loop(input).0
-}
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Pipes;
+
+    fn parse(input: &str) -> Self::Input {
+        input
+            .trim()
+            .lines()
+            .enumerate()
+            .flat_map(|(y, line)| {
+                line.chars()
+                    .enumerate()
+                    .map(move |(x, c)| ((x + 1, y + 1), Pipe::from_char(c)))
+            })
+            .collect()
+    }
+
+    fn part1(map: &Self::Input) -> Answer {
+        find_loop(map).0.into()
+    }
 
-pub fn part2(input: &str) -> usize {
-    let (_steps, maxx, maxy, tunnel, left, right) = find_loop(input);
-    assert!(left.is_disjoint(&right));
-    assert!(tunnel.is_disjoint(&left));
-    assert!(tunnel.is_disjoint(&right));
+    fn part2(map: &Self::Input) -> Answer {
+        let (_steps, maxx, maxy, tunnel, left, right) = find_loop(map);
+        assert!(left.is_disjoint(&right));
+        assert!(tunnel.is_disjoint(&left));
+        assert!(tunnel.is_disjoint(&right));
 
-    fill(&left, maxx, maxy, &tunnel)
-        .or_else(|| fill(&right, maxx, maxy, &tunnel))
-        .expect("left or right is the inside")
+        fill(&left, maxx, maxy, &tunnel)
+            .or_else(|| fill(&right, maxx, maxy, &tunnel))
+            .expect("left or right is the inside")
+            .into()
+    }
 }
 
 fn fill(
@@ -262,13 +199,11 @@ fn fill(
     tunnel: &HashSet<(usize, usize)>,
 ) -> Option<usize> {
     let mut fill_from = fill_from.iter().cloned().collect::<Vec<_>>();
-    let offsets = [(0, 1), (1, 0), (0, -1_i32), (-1_i32, 0)];
     let mut seen = HashSet::new();
-    while let Some((x, y)) = fill_from.pop() {
-        seen.insert((x, y));
-        for (x_off, y_off) in offsets.iter() {
-            let x = (x as i32 + x_off) as usize;
-            let y = (y as i32 + y_off) as usize;
+    while let Some(pos) = fill_from.pop() {
+        seen.insert(pos);
+        for dir in Direction::ALL {
+            let (x, y) = dir.checked_step(pos)?;
             if x == 0 || x > maxx || y == 0 || y > maxy {
                 return None;
             }
@@ -283,39 +218,40 @@ fn fill(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_core::{read, Part};
 
     #[test]
     fn part1_sample() {
-        assert_eq!(4, part1("sample.txt"));
+        assert_eq!(4, Day::solve(Part::One, &read("sample.txt")));
     }
 
     #[test]
     fn part1_input() {
-        assert_eq!(6931, part1("input.txt"));
+        assert_eq!(6931, Day::solve(Part::One, &read("input.txt")));
     }
 
     #[test]
     fn part2_sample() {
-        assert_eq!(1, part2("sample.txt"));
+        assert_eq!(1, Day::solve(Part::Two, &read("sample.txt")));
     }
 
     #[test]
     fn part2_sample2() {
-        assert_eq!(10, part2("sample2.txt"));
+        assert_eq!(10, Day::solve(Part::Two, &read("sample2.txt")));
     }
 
     #[test]
     fn part2_sample3() {
-        assert_eq!(4, part2("sample3.txt"));
+        assert_eq!(4, Day::solve(Part::Two, &read("sample3.txt")));
     }
 
     #[test]
     fn part2_sample4() {
-        assert_eq!(8, part2("sample4.txt"));
+        assert_eq!(8, Day::solve(Part::Two, &read("sample4.txt")));
     }
 
     #[test]
     fn part2_input() {
-        assert_eq!(357, part2("input.txt"));
+        assert_eq!(357, Day::solve(Part::Two, &read("input.txt")));
     }
 }
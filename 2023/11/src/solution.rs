@@ -1,21 +1,9 @@
+use aoc_core::{Answer, Solution};
 use itertools::Itertools;
 
 use std::collections::HashSet;
-use std::fs::read_to_string;
-
-pub fn solve(input: &str, expanding_universe: usize) -> usize {
-    let input = read_to_string(input).unwrap();
-    let galaxies = input
-        .trim()
-        .lines()
-        .enumerate()
-        .flat_map(|(y, line)| {
-            line.chars().enumerate().filter_map(move |(x, c)| match c {
-                '#' => Some((x, y)),
-                _ => None,
-            })
-        })
-        .collect::<HashSet<_>>();
+
+pub fn solve(galaxies: &HashSet<(usize, usize)>, expanding_universe: usize) -> usize {
     let xs = galaxies.iter().map(|(x, _)| *x).collect::<HashSet<_>>();
     let ys = galaxies.iter().map(|(_, y)| *y).collect::<HashSet<_>>();
     let &maxx = xs.iter().max().unwrap();
@@ -25,8 +13,8 @@ pub fn solve(input: &str, expanding_universe: usize) -> usize {
     let empty_rows = (0..=maxy).filter(|&y| !ys.contains(&y)).collect::<Vec<_>>();
 
     let expanded = galaxies
-        .into_iter()
-        .map(|(x, y)| {
+        .iter()
+        .map(|&(x, y)| {
             (
                 x + empty_columns.iter().filter(|&c| x > *c).count() * expanding_universe,
                 y + empty_rows.iter().filter(|&r| y > *r).count() * expanding_universe,
@@ -44,40 +32,62 @@ pub fn solve(input: &str, expanding_universe: usize) -> usize {
         })
         .sum()
 }
-pub fn part1(input: &str) -> usize {
-    solve(input, 1)
-}
 
-pub fn part2(input: &str) -> usize {
-    solve(input, 999999)
+pub struct Day;
+
+impl Solution for Day {
+    type Input = HashSet<(usize, usize)>;
+
+    fn parse(input: &str) -> Self::Input {
+        input
+            .trim()
+            .lines()
+            .enumerate()
+            .flat_map(|(y, line)| {
+                line.chars().enumerate().filter_map(move |(x, c)| match c {
+                    '#' => Some((x, y)),
+                    _ => None,
+                })
+            })
+            .collect()
+    }
+
+    fn part1(galaxies: &Self::Input) -> Answer {
+        solve(galaxies, 1).into()
+    }
+
+    fn part2(galaxies: &Self::Input) -> Answer {
+        solve(galaxies, 999999).into()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_core::{read, Part};
 
     #[test]
     fn part1_sample() {
-        assert_eq!(374, part1("sample.txt"));
+        assert_eq!(374, Day::solve(Part::One, &read("sample.txt")));
     }
 
     #[test]
     fn part1_input() {
-        assert_eq!(10033566, part1("input.txt"));
+        assert_eq!(10033566, Day::solve(Part::One, &read("input.txt")));
     }
 
     #[test]
     fn part2_sample_10() {
-        assert_eq!(1030, solve("sample.txt", 9));
+        assert_eq!(1030, solve(&Day::parse(&read("sample.txt")), 9));
     }
 
     #[test]
     fn part2_sample_100() {
-        assert_eq!(8410, solve("sample.txt", 99));
+        assert_eq!(8410, solve(&Day::parse(&read("sample.txt")), 99));
     }
 
     #[test]
     fn part2_input() {
-        assert_eq!(560822911938, part2("input.txt"));
+        assert_eq!(560822911938, Day::solve(Part::Two, &read("input.txt")));
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::bail;
-use scan_fmt::scan_fmt;
-use std::fs::read_to_string;
+use aoc_core::{Answer, Solution};
 
 fn start_count(springs: &str, groups: &[usize]) -> usize {
     let springs = springs.chars().collect::<Vec<_>>();
@@ -65,50 +63,51 @@ fn cull(spring: Option<char>, springs: &[char], groups: &[usize]) -> bool {
         || (sum + groups.len()) > springs.len() + spring.map_or(0, |_| 1) + 1
 }
 
-pub fn part1(input: &str) -> usize {
-    let input = read_to_string(input).unwrap();
-    input
-        .trim()
-        .lines()
-        .map(|line| {
-            let (springs, groups) = line.split_once(' ').expect("a valid line");
-            let groups = groups
-                .split(',')
-                .map(|g| g.parse().expect("a number"))
-                .collect::<Vec<usize>>();
-            start_count(springs, &groups)
-        })
-        .sum()
-}
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<(String, Vec<usize>)>;
+
+    fn parse(input: &str) -> Self::Input {
+        input
+            .trim()
+            .lines()
+            .map(|line| {
+                let (springs, groups) = line.split_once(' ').expect("a valid line");
+                let groups = groups
+                    .split(',')
+                    .map(|g| g.parse().expect("a number"))
+                    .collect::<Vec<usize>>();
+                (springs.to_string(), groups)
+            })
+            .collect()
+    }
 
-pub fn part2(input: &str) -> usize {
-    let input = read_to_string(input).unwrap();
-    input
-        .trim()
-        .lines()
-        .map(|line| {
-            let (springs, groups) = line.split_once(' ').expect("a valid line");
-            let springs = std::iter::repeat(springs)
-                .take(5)
-                .collect::<Vec<_>>()
-                .join("?");
-            let groups = std::iter::repeat(groups)
-                .take(5)
-                .collect::<Vec<_>>()
-                .join(",");
+    fn part1(rows: &Self::Input) -> Answer {
+        rows.iter()
+            .map(|(springs, groups)| start_count(springs, groups))
+            .sum::<usize>()
+            .into()
+    }
 
-            let groups = groups
-                .split(',')
-                .map(|g| g.parse().expect("a number"))
-                .collect::<Vec<usize>>();
-            start_count(&springs, &groups)
-        })
-        .sum()
+    fn part2(rows: &Self::Input) -> Answer {
+        rows.iter()
+            .map(|(springs, groups)| {
+                let springs = std::iter::repeat_n(springs.as_str(), 5)
+                    .collect::<Vec<_>>()
+                    .join("?");
+                let groups = groups.repeat(5);
+                start_count(&springs, &groups)
+            })
+            .sum::<usize>()
+            .into()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_core::{read, Part};
     use test_case::test_case;
 
     #[test_case("#", &[1] => 1; "one spring")]
@@ -135,21 +134,21 @@ mod tests {
 
     #[test]
     fn part1_sample() {
-        assert_eq!(21, part1("sample.txt"));
+        assert_eq!(21, Day::solve(Part::One, &read("sample.txt")));
     }
 
     #[test]
     fn part1_input() {
-        assert_eq!(7670, part1("input.txt"));
+        assert_eq!(7670, Day::solve(Part::One, &read("input.txt")));
     }
 
     #[test]
     fn part2_sample() {
-        assert_eq!(525152, part2("sample.txt"));
+        assert_eq!(525152, Day::solve(Part::Two, &read("sample.txt")));
     }
 
     #[test]
     fn part2_input() {
-        assert_eq!(0, part2("input.txt"));
+        assert_eq!(0, Day::solve(Part::Two, &read("input.txt")));
     }
 }
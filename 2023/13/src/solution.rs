@@ -1,7 +1,7 @@
-use std::fs::read_to_string;
+use aoc_core::{Answer, Solution};
 
 #[derive(Debug)]
-struct Pattern {
+pub struct Pattern {
     pattern: Vec<u64>,
     transposed: Vec<u64>,
     maxx: usize,
@@ -75,27 +75,36 @@ fn parse(input: &str) -> Vec<Pattern> {
     patterns
 }
 
-pub fn solve(input: &str, with_smudge: bool) -> usize {
-    let input = read_to_string(input).unwrap();
-    let patterns = parse(&input);
-
+pub fn solve(patterns: &[Pattern], with_smudge: bool) -> usize {
     patterns
-        .into_iter()
+        .iter()
         .map(|p| p.mirror(with_smudge))
         .map(|(c, r)| c + 100 * r)
         .sum()
 }
-pub fn part1(input: &str) -> usize {
-    solve(input, false)
-}
 
-pub fn part2(input: &str) -> usize {
-    solve(input, true)
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Pattern>;
+
+    fn parse(input: &str) -> Self::Input {
+        parse(input)
+    }
+
+    fn part1(patterns: &Self::Input) -> Answer {
+        solve(patterns, false).into()
+    }
+
+    fn part2(patterns: &Self::Input) -> Answer {
+        solve(patterns, true).into()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_core::{read, Part};
 
     #[test]
     fn parsing() {
@@ -125,21 +134,21 @@ mod tests {
 
     #[test]
     fn part1_sample() {
-        assert_eq!(405, part1("sample.txt"));
+        assert_eq!(405, Day::solve(Part::One, &read("sample.txt")));
     }
 
     #[test]
     fn part1_input() {
-        assert_eq!(27505, part1("input.txt"));
+        assert_eq!(27505, Day::solve(Part::One, &read("input.txt")));
     }
 
     #[test]
     fn part2_sample() {
-        assert_eq!(400, part2("sample.txt"));
+        assert_eq!(400, Day::solve(Part::Two, &read("sample.txt")));
     }
 
     #[test]
     fn part2_input() {
-        assert_eq!(22906, part2("input.txt"));
+        assert_eq!(22906, Day::solve(Part::Two, &read("input.txt")));
     }
 }
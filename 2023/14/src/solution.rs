@@ -1,80 +1,167 @@
-use anyhow::bail;
-use scan_fmt::scan_fmt;
-use std::collections::HashMap;
-use std::fs::read_to_string;
+use aoc_core::{state_after, Answer, Solution};
+use aoc_grid::{Direction, Grid, Point};
 
-enum Rock {
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Rock {
     Round,
     Cube,
 }
 
-pub fn part1(input: &str) -> usize {
-    let input = read_to_string(input).unwrap();
-    let mut height = 0;
-    let mut width = 0;
-    let rocks = input
-        .trim()
-        .lines()
-        .enumerate()
-        .flat_map(|(y, line)| {
-            height += 1;
-            width = line.len();
-            line.chars().enumerate().filter_map(move |(x, c)| match c {
-                '.' => None,
-                '#' => Some(((x, y), Rock::Cube)),
-                'O' => Some(((x, y), Rock::Round)),
-                _ => panic!("invalid input"),
-            })
-        })
-        .collect::<HashMap<(usize, usize), Rock>>();
-
-    let mut total_load = 0;
-    let mut columns = vec![0; width];
-    for y in 0..height {
-        for x in 0..width {
-            let rock = rocks.get(&(x, y));
-            match rock {
-                Some(Rock::Cube) => {
-                    columns[x] = y + 1;
-                }
+pub type Platform = Grid<Option<Rock>>;
+
+fn parse(input: &str) -> Platform {
+    Grid::parse_with(input.trim(), |c| match c {
+        '.' => None,
+        '#' => Some(Rock::Cube),
+        'O' => Some(Rock::Round),
+        _ => panic!("invalid input"),
+    })
+}
+
+/// Rolls all round rocks as far as they go towards `dir`.
+fn tilt(platform: &mut Platform, dir: Direction) {
+    let (width, height) = (platform.width(), platform.height());
+    let lines: Vec<Vec<Point>> = if dir.is_vertical() {
+        (0..width)
+            .map(|x| (0..height).map(|y| (x, y)).collect())
+            .collect()
+    } else {
+        (0..height)
+            .map(|y| (0..width).map(|x| (x, y)).collect())
+            .collect()
+    };
+
+    for mut line in lines {
+        // start from the side the rocks roll to
+        if matches!(dir, Direction::Down | Direction::Right) {
+            line.reverse();
+        }
+        let mut free = 0;
+        for i in 0..line.len() {
+            match platform[line[i]] {
+                Some(Rock::Cube) => free = i + 1,
                 Some(Rock::Round) => {
-                    total_load += height - columns[x];
-                    columns[x] += 1;
+                    platform[line[i]] = None;
+                    platform[line[free]] = Some(Rock::Round);
+                    free += 1;
                 }
                 None => {}
             }
         }
     }
+}
+
+fn spin_cycle(platform: &mut Platform) {
+    for dir in [
+        Direction::Up,
+        Direction::Left,
+        Direction::Down,
+        Direction::Right,
+    ] {
+        tilt(platform, dir);
+    }
+}
 
-    total_load
+/// The load on the north support beams.
+fn load(platform: &Platform) -> usize {
+    platform
+        .find_all(|&rock| rock == Some(Rock::Round))
+        .map(|(_, y)| platform.height() - y)
+        .sum()
 }
 
-pub fn part2(input: &str) -> usize {
-    //todo!()
-    0
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Platform;
+
+    fn parse(input: &str) -> Self::Input {
+        parse(input)
+    }
+
+    fn part1(platform: &Self::Input) -> Answer {
+        let mut platform = platform.clone();
+        tilt(&mut platform, Direction::Up);
+        load(&platform).into()
+    }
+
+    fn part2(platform: &Self::Input) -> Answer {
+        let platform = state_after(platform.clone(), 1_000_000_000, |platform| {
+            let mut platform = platform.clone();
+            spin_cycle(&mut platform);
+            platform
+        });
+        load(&platform).into()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_core::{read, Part};
+    use test_case::test_case;
+
+    #[test_case(Direction::Up => "O.O\n.#.\n...")]
+    #[test_case(Direction::Down => "...\n.#.\nO.O")]
+    #[test_case(Direction::Left => "O..\n.#.\nO..")]
+    #[test_case(Direction::Right => "..O\n.#.\n..O")]
+    fn tilting(dir: Direction) -> String {
+        let mut platform = parse("..O\n.#.\nO..");
+        tilt(&mut platform, dir);
+        platform
+            .rows()
+            .map(|row| {
+                row.iter()
+                    .map(|rock| match rock {
+                        None => '.',
+                        Some(Rock::Cube) => '#',
+                        Some(Rock::Round) => 'O',
+                    })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn spinning() {
+        let mut platform = parse(&read("sample.txt"));
+        spin_cycle(&mut platform);
+        assert_eq!(
+            parse(
+                "
+.....#....
+....#...O#
+...OO##...
+.OO#......
+.....OOO#.
+.O#...O#.#
+....O#....
+......OOOO
+#...O###..
+#..OO#...."
+            ),
+            platform
+        );
+    }
 
     #[test]
     fn part1_sample() {
-        assert_eq!(136, part1("sample.txt"));
+        assert_eq!(136, Day::solve(Part::One, &read("sample.txt")));
     }
 
     #[test]
     fn part1_input() {
-        assert_eq!(110821, part1("input.txt"));
+        assert_eq!(110821, Day::solve(Part::One, &read("input.txt")));
     }
 
     #[test]
     fn part2_sample() {
-        assert_eq!(64, part2("sample.txt"));
+        assert_eq!(64, Day::solve(Part::Two, &read("sample.txt")));
     }
 
     #[test]
     fn part2_input() {
-        assert_eq!(0, part2("input.txt"));
+        assert_eq!(83516, Day::solve(Part::Two, &read("input.txt")));
     }
 }
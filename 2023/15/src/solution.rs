@@ -1,43 +1,52 @@
+use aoc_core::{Answer, Solution};
 use linked_hash_map::LinkedHashMap;
 
-use std::fs::read_to_string;
+pub struct Day;
 
-pub fn part1(input: &str) -> usize {
-    let input = read_to_string(input).unwrap();
-    input.trim().split(',').map(hash).sum()
-}
+impl Solution for Day {
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Self::Input {
+        input.trim().split(',').map(String::from).collect()
+    }
 
-pub fn part2(input: &str) -> usize {
-    let input = read_to_string(input).unwrap();
+    fn part1(steps: &Self::Input) -> Answer {
+        steps.iter().map(|s| hash(s)).sum::<usize>().into()
+    }
 
-    let mut boxes = vec![LinkedHashMap::<&str, usize>::new(); 256];
-    input.trim().split(',').for_each(|s| {
-        if let Some(label) = s.strip_suffix('-') {
-            let box_id = hash(label);
-            boxes[box_id].remove(label);
-        } else {
-            let (label, lens) = s.split_once('=').expect("an assignment");
-            let lens = lens.parse::<usize>().expect("a number");
-            let box_id = hash(label);
-            let lenses = &mut boxes[box_id];
-            if lenses.contains_key(label) {
-                lenses.entry(label).and_modify(|l| *l = lens);
+    fn part2(steps: &Self::Input) -> Answer {
+        let mut boxes = vec![LinkedHashMap::<&str, usize>::new(); 256];
+        steps.iter().for_each(|s| {
+            if let Some(label) = s.strip_suffix('-') {
+                let box_id = hash(label);
+                boxes[box_id].remove(label);
             } else {
-                lenses.insert(label, lens);
+                let (label, lens) = s.split_once('=').expect("an assignment");
+                let lens = lens.parse::<usize>().expect("a number");
+                let box_id = hash(label);
+                let lenses = &mut boxes[box_id];
+                if lenses.contains_key(label) {
+                    lenses.entry(label).and_modify(|l| *l = lens);
+                } else {
+                    lenses.insert(label, lens);
+                }
             }
-        }
-    });
+        });
 
-    boxes
-        .into_iter()
-        .enumerate()
-        .flat_map(|(box_index, lenses)| {
-            lenses
-                .into_iter()
-                .enumerate()
-                .map(move |(lens_index, (_label, lens))| (box_index + 1) * (lens_index + 1) * lens)
-        })
-        .sum()
+        boxes
+            .into_iter()
+            .enumerate()
+            .flat_map(|(box_index, lenses)| {
+                lenses
+                    .into_iter()
+                    .enumerate()
+                    .map(move |(lens_index, (_label, lens))| {
+                        (box_index + 1) * (lens_index + 1) * lens
+                    })
+            })
+            .sum::<usize>()
+            .into()
+    }
 }
 
 fn hash(input: &str) -> usize {
@@ -53,6 +62,7 @@ fn hash(input: &str) -> usize {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_core::{read, Part};
 
     #[test]
     fn hashing() {
@@ -65,21 +75,21 @@ mod tests {
 
     #[test]
     fn part1_sample() {
-        assert_eq!(1320, part1("sample.txt"));
+        assert_eq!(1320, Day::solve(Part::One, &read("sample.txt")));
     }
 
     #[test]
     fn part1_input() {
-        assert_eq!(515974, part1("input.txt"));
+        assert_eq!(515974, Day::solve(Part::One, &read("input.txt")));
     }
 
     #[test]
     fn part2_sample() {
-        assert_eq!(145, part2("sample.txt"));
+        assert_eq!(145, Day::solve(Part::Two, &read("sample.txt")));
     }
 
     #[test]
     fn part2_input() {
-        assert_eq!(265894, part2("input.txt"));
+        assert_eq!(265894, Day::solve(Part::Two, &read("input.txt")));
     }
 }
@@ -1,184 +1,105 @@
+use aoc_core::{Answer, Solution};
+use aoc_grid::{Direction, Grid, Point};
 
-
-use std::collections::{HashMap};
-use std::fs::read_to_string;
-use std::ops::Add;
-
-struct Grid {
-    cells: HashMap<Position, Cell>,
-    width: usize,
-    height: usize,
-}
-
-#[derive(Clone, Copy)]
-enum Direction {
-    Up = 0b0001,
-    Down = 0b0010,
-    Left = 0b0100,
-    Right = 0b1000,
-}
-
-enum Cell {
+pub enum Cell {
     MirrorUp,
     MirrorDown,
     SplitterUp,
     SplitterRight,
 }
 
-#[derive(PartialEq, Eq, Hash, Clone, Copy)]
-struct Position {
-    x: usize,
-    y: usize,
+fn parse(input: &str) -> Grid<Option<Cell>> {
+    Grid::parse_with(input.trim(), |c| match c {
+        '.' => None,
+        '/' => Some(Cell::MirrorUp),
+        '\\' => Some(Cell::MirrorDown),
+        '|' => Some(Cell::SplitterUp),
+        '-' => Some(Cell::SplitterRight),
+        _ => panic!("Unknown cell: {}", c),
+    })
 }
 
-impl Add<Direction> for Position {
-    type Output = Self;
-
-    fn add(self, rhs: Direction) -> Self::Output {
-        match rhs {
-            Direction::Up => Position {
-                x: self.x,
-                y: self.y - 1,
-            },
-            Direction::Down => Position {
-                x: self.x,
-                y: self.y + 1,
-            },
-            Direction::Left => Position {
-                x: self.x - 1,
-                y: self.y,
-            },
-            Direction::Right => Position {
-                x: self.x + 1,
-                y: self.y,
-            },
+fn walk(grid: &Grid<Option<Cell>>, pos: Point, dir: Direction, visited: &mut Grid<u8>) {
+    let bit = 1 << dir as u8;
+    if visited[pos] & bit != 0 {
+        return;
+    }
+    visited[pos] |= bit;
+    let mut go = |dir: Direction| {
+        if let Some(next) = grid.step(pos, dir) {
+            walk(grid, next, dir, visited);
+        }
+    };
+    match grid[pos] {
+        None => go(dir),
+        Some(Cell::MirrorUp) if dir.is_vertical() => go(dir.turn_right()),
+        Some(Cell::MirrorUp) => go(dir.turn_left()),
+        Some(Cell::MirrorDown) if dir.is_vertical() => go(dir.turn_left()),
+        Some(Cell::MirrorDown) => go(dir.turn_right()),
+        Some(Cell::SplitterUp) if dir.is_vertical() => go(dir),
+        Some(Cell::SplitterRight) if !dir.is_vertical() => go(dir),
+        Some(Cell::SplitterUp | Cell::SplitterRight) => {
+            go(dir.turn_left());
+            go(dir.turn_right());
         }
     }
 }
 
-fn parse(input: &str) -> Grid {
-    let mut width = 0;
-    let mut height = 0;
-    let cells = input
-        .trim()
-        .lines()
-        .enumerate()
-        .flat_map(|(y, line)| {
-            height += 1;
-            width = line.len();
-            line.chars().enumerate().filter_map(move |(x, c)| {
-                let cell = match c {
-                    '.' => None,
-                    '/' => Some(Cell::MirrorUp),
-                    '\\' => Some(Cell::MirrorDown),
-                    '|' => Some(Cell::SplitterUp),
-                    '-' => Some(Cell::SplitterRight),
-                    _ => panic!("Unknown cell: {}", c),
-                };
-                cell.map(|cell| (Position { x: x + 1, y: y + 1 }, cell))
-            })
-        })
-        .collect();
-    Grid {
-        cells,
-        width,
-        height,
-    }
+fn energized(grid: &Grid<Option<Cell>>, pos: Point, dir: Direction) -> usize {
+    let mut visited = Grid::new(grid.width(), grid.height(), 0);
+    walk(grid, pos, dir, &mut visited);
+    visited.find_all(|&v| v != 0).count()
 }
 
-fn walk(
-    grid: &Grid,
-    pos: Position,
-    dir: Direction,
-    mut visited: HashMap<Position, u8>,
-) -> HashMap<Position, u8> {
-    if pos.x == 0 || pos.y == 0 || pos.x == grid.width + 1 || pos.y == grid.height + 1 {
-        return visited;
-    }
-    if let Some(&v) = visited.get(&pos) {
-        if v & (dir as u8) != 0 {
-            return visited;
-        }
-    }
-    *visited.entry(pos).or_insert(0) |= dir as u8;
-    match grid.cells.get(&pos) {
-        None => walk(grid, pos + dir, dir, visited),
-        Some(Cell::MirrorUp) => match dir {
-            Direction::Up => walk(grid, pos + Direction::Right, Direction::Right, visited),
-            Direction::Down => walk(grid, pos + Direction::Left, Direction::Left, visited),
-            Direction::Left => walk(grid, pos + Direction::Down, Direction::Down, visited),
-            Direction::Right => walk(grid, pos + Direction::Up, Direction::Up, visited),
-        },
-        Some(Cell::MirrorDown) => match dir {
-            Direction::Up => walk(grid, pos + Direction::Left, Direction::Left, visited),
-            Direction::Down => walk(grid, pos + Direction::Right, Direction::Right, visited),
-            Direction::Left => walk(grid, pos + Direction::Up, Direction::Up, visited),
-            Direction::Right => walk(grid, pos + Direction::Down, Direction::Down, visited),
-        },
-        Some(Cell::SplitterUp) => match dir {
-            Direction::Up | Direction::Down => walk(grid, pos + dir, dir, visited),
-            Direction::Left | Direction::Right => {
-                let visited = walk(grid, pos + Direction::Up, Direction::Up, visited);
-                walk(grid, pos + Direction::Down, Direction::Down, visited)
-            }
-        },
-        Some(Cell::SplitterRight) => match dir {
-            Direction::Up | Direction::Down => {
-                let visited = walk(grid, pos + Direction::Left, Direction::Left, visited);
-                walk(grid, pos + Direction::Right, Direction::Right, visited)
-            }
-            Direction::Left | Direction::Right => walk(grid, pos + dir, dir, visited),
-        },
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Grid<Option<Cell>>;
+
+    fn parse(input: &str) -> Self::Input {
+        parse(input)
     }
-}
 
-pub fn part1(input: &str) -> usize {
-    let input = read_to_string(input).unwrap();
-    let cells = parse(&input);
-    walk(
-        &cells,
-        Position { x: 1, y: 1 },
-        Direction::Right,
-        HashMap::new(),
-    )
-    .len()
-}
+    fn part1(grid: &Self::Input) -> Answer {
+        energized(grid, (0, 0), Direction::Right).into()
+    }
 
-pub fn part2(input: &str) -> usize {
-    let input = read_to_string(input).unwrap();
-    let grid = parse(&input);
-    (1..=grid.width)
-        .map(|x| ((x, 1), Direction::Down))
-        .chain((1..=grid.width).map(|x| ((x, grid.height), Direction::Up)))
-        .chain((1..=grid.height).map(|y| ((1, y), Direction::Right)))
-        .chain((1..=grid.height).map(|y| ((grid.width, y), Direction::Left)))
-        .map(|(pos, dir)| walk(&grid, Position { x: pos.0, y: pos.1 }, dir, HashMap::new()))
-        .map(|visited| visited.len())
-        .max()
-        .expect("a solution exists")
+    fn part2(grid: &Self::Input) -> Answer {
+        let (width, height) = (grid.width(), grid.height());
+        (0..width)
+            .map(|x| ((x, 0), Direction::Down))
+            .chain((0..width).map(|x| ((x, height - 1), Direction::Up)))
+            .chain((0..height).map(|y| ((0, y), Direction::Right)))
+            .chain((0..height).map(|y| ((width - 1, y), Direction::Left)))
+            .map(|(pos, dir)| energized(grid, pos, dir))
+            .max()
+            .expect("a solution exists")
+            .into()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_core::{read, Part};
 
     #[test]
     fn part1_sample() {
-        assert_eq!(46, part1("sample.txt"));
+        assert_eq!(46, Day::solve(Part::One, &read("sample.txt")));
     }
 
     #[test]
     fn part1_input() {
-        assert_eq!(7543, part1("input.txt"));
+        assert_eq!(7543, Day::solve(Part::One, &read("input.txt")));
     }
 
     #[test]
     fn part2_sample() {
-        assert_eq!(51, part2("sample.txt"));
+        assert_eq!(51, Day::solve(Part::Two, &read("sample.txt")));
     }
 
     #[test]
     fn part2_input() {
-        assert_eq!(8231, part2("input.txt"));
+        assert_eq!(8231, Day::solve(Part::Two, &read("input.txt")));
     }
 }
@@ -1,56 +1,15 @@
-use std::fs::read_to_string;
-
+use aoc_core::{Answer, Solution};
+use aoc_grid::{Direction, Grid};
 use pathfinding::prelude::astar;
 
-struct City {
-    blocks: Vec<Vec<u8>>,
+struct City<'a> {
+    blocks: &'a Grid<u8>,
     goal_col: i32,
     goal_row: i32,
     max_straight: usize,
     min_straight: usize,
 }
 
-#[derive(Debug, Clone, Eq, PartialEq, Hash)]
-enum Direction {
-    Up,
-    Down,
-    Left,
-    Right,
-}
-
-impl Direction {
-    fn offset(&self) -> (i32, i32) {
-        match self {
-            Direction::Up => (-1, 0),
-            Direction::Down => (1, 0),
-            Direction::Left => (0, -1),
-            Direction::Right => (0, 1),
-        }
-    }
-
-    fn straight(&self) -> Self {
-        self.clone()
-    }
-
-    fn left(&self) -> Self {
-        match self {
-            Direction::Up => Direction::Left,
-            Direction::Down => Direction::Right,
-            Direction::Left => Direction::Down,
-            Direction::Right => Direction::Up,
-        }
-    }
-
-    fn right(&self) -> Self {
-        match self {
-            Direction::Up => Direction::Right,
-            Direction::Down => Direction::Left,
-            Direction::Left => Direction::Up,
-            Direction::Right => Direction::Down,
-        }
-    }
-}
-
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
 struct Node {
     row: i32,
@@ -59,22 +18,18 @@ struct Node {
     straight: usize,
 }
 
-impl City {
+impl City<'_> {
     fn successors(&self, node: &Node) -> Vec<(Node, usize)> {
         [
-            (
-                node.direction.straight(),
-                self.min_straight,
-                node.straight + 1,
-            ),
-            (node.direction.left(), node.straight, 1),
-            (node.direction.right(), node.straight, 1),
+            (node.direction, self.min_straight, node.straight + 1),
+            (node.direction.turn_left(), node.straight, 1),
+            (node.direction.turn_right(), node.straight, 1),
         ]
         .into_iter()
         .filter_map(|(dir, prev_straight, straight)| {
-            let off = dir.offset();
-            let row = node.row + off.0;
-            let col = node.col + off.1;
+            let (dx, dy) = dir.offset();
+            let row = node.row + dy as i32;
+            let col = node.col + dx as i32;
             if straight <= self.max_straight
                 && (prev_straight >= self.min_straight || (node.row == 0 && node.col == 0))
                 && row >= 0
@@ -90,7 +45,7 @@ impl City {
                         direction: dir,
                         straight,
                     },
-                    self.blocks[row as usize][col as usize] as usize,
+                    self.blocks[(col as usize, row as usize)] as usize,
                 ))
             } else {
                 None
@@ -100,14 +55,9 @@ impl City {
     }
 }
 
-fn solve(input: &str, min_straight: usize, max_straight: usize) -> usize {
-    let blocks = input
-        .trim()
-        .lines()
-        .map(|line| line.chars().map(|c| c as u8 - 48).collect())
-        .collect::<Vec<Vec<u8>>>();
-    let goal_row = blocks.len() as i32 - 1;
-    let goal_col = blocks[0].len() as i32 - 1;
+fn solve(blocks: &Grid<u8>, min_straight: usize, max_straight: usize) -> usize {
+    let goal_row = blocks.height() as i32 - 1;
+    let goal_col = blocks.width() as i32 - 1;
     let city = City {
         blocks,
         goal_row,
@@ -133,31 +83,42 @@ fn solve(input: &str, min_straight: usize, max_straight: usize) -> usize {
     result.expect("a path").1
 }
 
-pub fn part1(input: &str) -> usize {
-    let input = read_to_string(input).unwrap();
-    solve(&input, 0, 3)
-}
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Grid<u8>;
 
-pub fn part2(input: &str) -> usize {
-    let input = read_to_string(input).unwrap();
-    solve(&input, 4, 10)
+    fn parse(input: &str) -> Self::Input {
+        Grid::parse_digits(input.trim())
+    }
+
+    fn part1(blocks: &Self::Input) -> Answer {
+        solve(blocks, 0, 3).into()
+    }
+
+    fn part2(blocks: &Self::Input) -> Answer {
+        solve(blocks, 4, 10).into()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_core::{read, Part};
 
     #[test]
     fn simple() {
         assert_eq!(
             7,
             solve(
-                r#"
+                &Day::parse(
+                    r#"
 136
 134
 124
 124
-111"#,
+111"#
+                ),
                 0,
                 3,
             )
@@ -169,12 +130,14 @@ mod tests {
         assert_eq!(
             71,
             solve(
-                r#"
+                &Day::parse(
+                    r#"
 111111111111
 999999999991
 999999999991
 999999999991
-999999999991"#,
+999999999991"#
+                ),
                 4,
                 10,
             )
@@ -183,21 +146,21 @@ mod tests {
 
     #[test]
     fn part1_sample() {
-        assert_eq!(102, part1("sample.txt"));
+        assert_eq!(102, Day::solve(Part::One, &read("sample.txt")));
     }
 
     #[test]
     fn part1_input() {
-        assert_eq!(861, part1("input.txt"));
+        assert_eq!(861, Day::solve(Part::One, &read("input.txt")));
     }
 
     #[test]
     fn part2_sample() {
-        assert_eq!(94, part2("sample.txt"));
+        assert_eq!(94, Day::solve(Part::Two, &read("sample.txt")));
     }
 
     #[test]
     fn part2_input() {
-        assert_eq!(1037, part2("input.txt"));
+        assert_eq!(1037, Day::solve(Part::Two, &read("input.txt")));
     }
 }
@@ -1,8 +1,10 @@
+use aoc_core::{Answer, Solution};
+#[cfg(test)]
 use std::collections::HashSet;
-use std::fs::read_to_string;
 
 use scan_fmt::scan_fmt;
 
+#[cfg(test)]
 fn flood_fill(x: usize, y: usize, map: &mut Vec<Vec<bool>>) {
     if !map[y][x] {
         map[y][x] = true;
@@ -22,8 +24,9 @@ fn flood_fill(x: usize, y: usize, map: &mut Vec<Vec<bool>>) {
     }
 }
 
-fn parse(input: &str) -> Vec<((i32, i32), usize, u32)> {
-    let input = read_to_string(input).unwrap();
+type Plan = Vec<((i32, i32), usize, u32)>;
+
+fn parse(input: &str) -> Plan {
     input
         .trim()
         .lines()
@@ -42,10 +45,12 @@ fn parse(input: &str) -> Vec<((i32, i32), usize, u32)> {
         .collect::<Vec<_>>()
 }
 
-fn solve(plan: Vec<((i32, i32), usize, u32)>) -> usize {
+/// The dug out area by filling in a bitmap, only fit for small plans.
+#[cfg(test)]
+fn flood_fill_area(plan: &[((i32, i32), usize, u32)]) -> usize {
     let trench = plan
-        .into_iter()
-        .fold(vec![(0, 0)], |mut trench, (offset, count, _colour)| {
+        .iter()
+        .fold(vec![(0, 0)], |mut trench, &(offset, count, _colour)| {
             let mut pos = *trench.last().expect("trench not empty");
             for _ in 0..count {
                 pos.0 += offset.0;
@@ -77,6 +82,24 @@ fn solve(plan: Vec<((i32, i32), usize, u32)>) -> usize {
         .expect("max")
 }
 
+/// The dug out area straight from the corners of the trench: the shoelace
+/// formula gives the area enclosed by the middle of the trench, and Pick's
+/// theorem adds the half of the trench outside of it.
+fn lagoon_area(plan: &[((i32, i32), usize, u32)]) -> i64 {
+    let mut corner = (0i64, 0i64);
+    let mut twice_area = 0;
+    let mut boundary = 0;
+    for &((dx, dy), count, _colour) in plan {
+        let count = count as i64;
+        let next = (corner.0 + dx as i64 * count, corner.1 + dy as i64 * count);
+        twice_area += corner.0 * next.1 - next.0 * corner.1;
+        boundary += count;
+        corner = next;
+    }
+    // interior points (Pick's theorem) plus the trench itself
+    (twice_area.abs() - boundary) / 2 + 1 + boundary
+}
+
 fn dehexify(colour: u32) -> ((i32, i32), usize) {
     let count = colour >> 4;
     let offset = match colour & 0xf {
@@ -89,27 +112,36 @@ fn dehexify(colour: u32) -> ((i32, i32), usize) {
     (offset, count as usize)
 }
 
-pub fn part1(input: &str) -> usize {
-    let plan = parse(input);
-    solve(plan)
-}
+pub struct Day;
 
-pub fn part2(input: &str) -> usize {
-    let plan = parse(input);
-    let fixed_plan = plan
-        .into_iter()
-        .map(|(_offset, _count, colour)| {
-            let (offset, count) = dehexify(colour);
-            (offset, count, 0)
-        })
-        .collect();
-    todo!("brute forcing doesn't cut it");
-    solve(fixed_plan)
+impl Solution for Day {
+    type Input = Plan;
+
+    fn parse(input: &str) -> Self::Input {
+        parse(input)
+    }
+
+    fn part1(plan: &Self::Input) -> Answer {
+        lagoon_area(plan).into()
+    }
+
+    fn part2(plan: &Self::Input) -> Answer {
+        let fixed_plan: Vec<_> = plan
+            .iter()
+            .map(|&(_offset, _count, colour)| {
+                let (offset, count) = dehexify(colour);
+                (offset, count, 0)
+            })
+            .collect();
+        lagoon_area(&fixed_plan).into()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_core::{read, Part};
+    use test_case::test_case;
 
     #[test]
     fn dehexifying() {
@@ -123,33 +155,53 @@ mod tests {
         );
     }
 
+    #[test_case("sample.txt")]
+    #[test_case("sample2.txt")]
+    #[test_case("sample3.txt")]
+    #[test_case("R 2 (#000000)\nD 2 (#000000)\nL 2 (#000000)\nU 2 (#000000)"; "square")]
+    #[test_case("R 4 (#000000)\nD 4 (#000000)\nL 2 (#000000)\nU 2 (#000000)\nL 2 (#000000)\nU 2 (#000000)"; "notch")]
+    fn area_matches_flood_fill(plan: &str) {
+        let plan = if plan.ends_with(".txt") {
+            parse(&read(plan))
+        } else {
+            parse(plan)
+        };
+        assert_eq!(flood_fill_area(&plan) as i64, lagoon_area(&plan));
+    }
+
     #[test]
     fn part1_sample() {
-        assert_eq!(19 + 19 + 24, part1("sample.txt"));
+        assert_eq!(19 + 19 + 24, Day::solve(Part::One, &read("sample.txt")));
     }
 
     #[test]
     fn part1_sample2() {
-        assert_eq!(2 * (19 + 19) + 131, part1("sample2.txt"));
+        assert_eq!(
+            2 * (19 + 19) + 131,
+            Day::solve(Part::One, &read("sample2.txt"))
+        );
     }
 
     #[test]
     fn part1_sample3() {
-        assert_eq!(3 * (19 + 19) + 322, part1("sample3.txt"));
+        assert_eq!(
+            3 * (19 + 19) + 322,
+            Day::solve(Part::One, &read("sample3.txt"))
+        );
     }
 
     #[test]
     fn part1_input() {
-        assert_eq!(61661, part1("input.txt"));
+        assert_eq!(61661, Day::solve(Part::One, &read("input.txt")));
     }
 
     #[test]
     fn part2_sample() {
-        assert_eq!(952408144115, part2("sample.txt"));
+        assert_eq!(952408144115, Day::solve(Part::Two, &read("sample.txt")));
     }
 
     #[test]
     fn part2_input() {
-        assert_eq!(0, part2("input.txt"));
+        assert_eq!(111131796939729, Day::solve(Part::Two, &read("input.txt")));
     }
 }
@@ -1,10 +1,10 @@
+use aoc_core::{Answer, Solution};
 use std::collections::HashMap;
-use std::fs::read_to_string;
 use std::ops::RangeInclusive;
 
 use scan_fmt::scan_fmt;
 
-enum Rule {
+pub enum Rule {
     Accept,
     Reject,
     Noop(String),
@@ -12,10 +12,9 @@ enum Rule {
     LessThan(char, usize, String),
 }
 
-type Part = HashMap<char, usize>;
+pub type Part = HashMap<char, usize>;
 
 fn parse_input(input: &str) -> (HashMap<String, Vec<Rule>>, Vec<Part>) {
-    let input = read_to_string(input).unwrap();
     let (rules, parts) = input.trim().split_once("\n\n").expect("a blank line");
     let rules = rules
         .lines()
@@ -214,29 +213,37 @@ fn count_accepted(
     sum
 }
 
-pub fn part1(input: &str) -> usize {
-    let (rules, parts) = parse_input(input);
+pub struct Day;
 
-    parts
-        .into_iter()
-        .filter(|part| apply(&rules, part, "in"))
-        .map(|part| part[&'x'] + part[&'m'] + part[&'a'] + part[&'s'])
-        .sum()
-}
+impl Solution for Day {
+    type Input = (HashMap<String, Vec<Rule>>, Vec<Part>);
 
-pub fn part2(input: &str) -> usize {
-    let (rules, _parts) = parse_input(input);
+    fn parse(input: &str) -> Self::Input {
+        parse_input(input)
+    }
 
-    count_accepted(&rules, "in", 1..=4000, 1..=4000, 1..=4000, 1..=4000)
+    fn part1((rules, parts): &Self::Input) -> Answer {
+        parts
+            .iter()
+            .filter(|part| apply(rules, part, "in"))
+            .map(|part| part[&'x'] + part[&'m'] + part[&'a'] + part[&'s'])
+            .sum::<usize>()
+            .into()
+    }
+
+    fn part2((rules, _parts): &Self::Input) -> Answer {
+        count_accepted(rules, "in", 1..=4000, 1..=4000, 1..=4000, 1..=4000).into()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_core::{read, Part};
 
     #[test]
     fn one_rule_pv() {
-        let (rules, _parts) = parse_input("sample.txt");
+        let (rules, _parts) = parse_input(&read("sample.txt"));
         assert_eq!(
             1716 * 4000 * 4000 * 4000,
             count_accepted(&rules, "pv", 1..=4000, 1..=4000, 1..=4000, 1..=4000)
@@ -245,7 +252,7 @@ mod tests {
 
     #[test]
     fn one_rule_lnx() {
-        let (rules, _parts) = parse_input("sample.txt");
+        let (rules, _parts) = parse_input(&read("sample.txt"));
         assert_eq!(
             256000000000000,
             count_accepted(&rules, "lnx", 1..=4000, 1..=4000, 1..=4000, 1..=4000)
@@ -254,7 +261,7 @@ mod tests {
 
     #[test]
     fn one_rule_crn() {
-        let (rules, _parts) = parse_input("sample.txt");
+        let (rules, _parts) = parse_input(&read("sample.txt"));
         assert_eq!(
             (4000 - 2662) * 4000 * 4000 * 4000,
             count_accepted(&rules, "crn", 1..=4000, 1..=4000, 1..=4000, 1..=4000)
@@ -263,7 +270,7 @@ mod tests {
 
     #[test]
     fn one_rule_gd() {
-        let (rules, _parts) = parse_input("sample.txt");
+        let (rules, _parts) = parse_input(&read("sample.txt"));
         assert_eq!(
             0,
             count_accepted(&rules, "gd", 1..=4000, 1..=4000, 1..=4000, 1..=4000)
@@ -272,7 +279,7 @@ mod tests {
 
     #[test]
     fn one_rule_rfg() {
-        let (rules, _parts) = parse_input("sample.txt");
+        let (rules, _parts) = parse_input(&read("sample.txt"));
         assert_eq!(
             (4000 - 536) * 2440 * 4000 * 4000,
             count_accepted(&rules, "rfg", 1..=4000, 1..=4000, 1..=4000, 1..=4000)
@@ -281,7 +288,7 @@ mod tests {
 
     #[test]
     fn one_rule_hdj() {
-        let (rules, _parts) = parse_input("sample.txt");
+        let (rules, _parts) = parse_input(&read("sample.txt"));
         assert_eq!(
             (4000 - 838) * 4000 * 4000 * 4000 + 838 * 1716 * 4000 * 4000,
             count_accepted(&rules, "hdj", 1..=4000, 1..=4000, 1..=4000, 1..=4000)
@@ -290,21 +297,21 @@ mod tests {
 
     #[test]
     fn part1_sample() {
-        assert_eq!(19114, part1("sample.txt"));
+        assert_eq!(19114, Day::solve(Part::One, &read("sample.txt")));
     }
 
     #[test]
     fn part1_input() {
-        assert_eq!(495298, part1("input.txt"));
+        assert_eq!(495298, Day::solve(Part::One, &read("input.txt")));
     }
 
     #[test]
     fn part2_sample() {
-        assert_eq!(167409079868000, part2("sample.txt"));
+        assert_eq!(167409079868000, Day::solve(Part::Two, &read("sample.txt")));
     }
 
     #[test]
     fn part2_input() {
-        assert_eq!(132186256794011, part2("input.txt"));
+        assert_eq!(132186256794011, Day::solve(Part::Two, &read("input.txt")));
     }
 }
@@ -1,5 +1,6 @@
+use aoc_core::{Answer, Solution};
+use num::Integer;
 use std::collections::{HashMap, VecDeque};
-use std::fs::read_to_string;
 
 type Targets<'a> = Vec<&'a str>;
 
@@ -72,7 +73,7 @@ impl<'a> Module<'a> {
     }
 }
 
-fn parse(input: &str) -> HashMap<&str, Module> {
+fn parse(input: &str) -> HashMap<&str, Module<'_>> {
     let mut modules = input
         .trim()
         .lines()
@@ -96,10 +97,8 @@ fn parse(input: &str) -> HashMap<&str, Module> {
     modules
 }
 
-pub fn part1(input: &str) -> usize {
-    let input = read_to_string(input).unwrap();
-
-    let mut modules = parse(&input);
+fn press_button_1000_times(input: &str) -> usize {
+    let mut modules = parse(input);
 
     let mut highs = 0;
     let mut lows = 0;
@@ -125,61 +124,122 @@ pub fn part1(input: &str) -> usize {
     highs * lows
 }
 
-pub fn part2(input: &str) -> usize {
-    let input = read_to_string(input).unwrap();
+fn feeding<'a>(modules: &HashMap<&'a str, Module<'a>>, label: &str) -> Vec<&'a str> {
+    let mut feeding = modules
+        .iter()
+        .filter(|(_, module)| module.outputs().contains(&label))
+        .map(|(&source, _)| source)
+        .collect::<Vec<_>>();
+    feeding.sort_unstable();
+    feeding
+}
 
-    let mut modules = parse(&input);
+/// The modules feeding the conjunction in front of `rx`: `rx` gets its low
+/// pulse on the press where all of them send a high pulse.
+fn counters_for_rx<'a>(modules: &HashMap<&'a str, Module<'a>>) -> Vec<&'a str> {
+    let [last] = feeding(modules, "rx")[..] else {
+        panic!("rx must be fed by a single module");
+    };
+    assert!(
+        matches!(modules.get(last), Some(Module::Conjunction(..))),
+        "{last} in front of rx must be a conjunction"
+    );
+    feeding(modules, last)
+}
 
-    // TODO: figure these out programmatically from the input
-    let mut antepenultimate_targets = vec!["kv", "jg", "rz", "mr"];
+const MAX_PRESSES: usize = 100_000;
 
-    let mut button_presses = 1;
+fn presses_until_rx(input: &str) -> usize {
+    let mut modules = parse(input);
+    let counters = counters_for_rx(&modules);
 
-    for i in 0..10000 {
+    // the presses on which each counter first sent a high pulse, and its period
+    let mut first_high: HashMap<&str, usize> = HashMap::new();
+    let mut periods: HashMap<&str, usize> = HashMap::new();
+
+    for press in 1..=MAX_PRESSES {
         let start = modules
             .get_mut("broadcaster")
             .unwrap()
             .apply(false, "button", "broadcaster");
         let mut queue = VecDeque::from(start);
         while let Some((signal, source, target)) = queue.pop_front() {
-            if signal && antepenultimate_targets.contains(&source) {
-                button_presses *= i + 1;
-                antepenultimate_targets.retain(|&t| t != source);
-            }
-            if antepenultimate_targets.is_empty() {
-                return button_presses;
+            if signal && counters.contains(&source) && !periods.contains_key(source) {
+                match first_high.get(source) {
+                    Some(&first) if first != press => {
+                        let period = press - first;
+                        assert_eq!(first, period, "{source} must cycle from the start");
+                        periods.insert(source, period);
+                    }
+                    Some(_) => {}
+                    None => {
+                        first_high.insert(source, press);
+                    }
+                }
             }
 
             if let Some(module) = modules.get_mut(target) {
                 queue.extend(module.apply(signal, source, target));
             }
         }
+
+        if periods.len() == counters.len() {
+            return periods.values().fold(1, |acc, period| acc.lcm(period));
+        }
+    }
+
+    panic!("no cycles found in {MAX_PRESSES} presses")
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    // Modules borrow their labels from the configuration and get mutated as
+    // pulses flow, so each part builds its own set from the text
+    type Input = String;
+
+    fn parse(input: &str) -> Self::Input {
+        input.trim().to_string()
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        press_button_1000_times(input).into()
     }
 
-    todo!("didn't find the answer in 10000 iterations")
+    fn part2(input: &Self::Input) -> Answer {
+        presses_until_rx(input).into()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_core::{read, Part};
 
     #[test]
     fn part1_sample() {
-        assert_eq!(32000000, part1("sample.txt"));
+        assert_eq!(32000000, Day::solve(Part::One, &read("sample.txt")));
     }
 
     #[test]
     fn part1_sample2() {
-        assert_eq!(11687500, part1("sample2.txt"));
+        assert_eq!(11687500, Day::solve(Part::One, &read("sample2.txt")));
     }
 
     #[test]
     fn part1_input() {
-        assert_eq!(898557000, part1("input.txt"));
+        assert_eq!(898557000, Day::solve(Part::One, &read("input.txt")));
+    }
+
+    #[test]
+    fn finding_the_counters() {
+        let input = read("input.txt");
+        let modules = parse(&input);
+        assert_eq!(vec!["jg", "kv", "mr", "rz"], counters_for_rx(&modules));
     }
 
     #[test]
     fn part2_input() {
-        assert_eq!(238420328103151, part2("input.txt"));
+        assert_eq!(238420328103151, Day::solve(Part::Two, &read("input.txt")));
     }
 }
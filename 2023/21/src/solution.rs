@@ -1,35 +1,35 @@
+use aoc_core::{Answer, Solution};
 use std::collections::HashSet;
-use std::fs::read_to_string;
-
-pub fn part1_do(input: &str, mut steps: usize) -> usize {
-    let input = read_to_string(input).unwrap();
-    let mut positions = HashSet::new();
-    let plots = input
-        .trim()
-        .lines()
-        .enumerate()
-        .flat_map(|(y, line)| {
-            let mut plots_from_row = HashSet::new();
-            for (x, c) in line.chars().enumerate() {
-                plots_from_row.insert(match c {
-                    '.' => (x + 1, y + 1),
-                    'S' => {
-                        positions.insert((x + 1, y + 1));
-                        (x + 1, y + 1)
-                    }
-                    '#' => continue,
-                    _ => panic!("invalid char: {}", c),
-                });
-            }
-            plots_from_row
-        })
-        .collect::<HashSet<_>>();
+
+pub struct Garden {
+    plots: HashSet<(usize, usize)>,
+    start: (usize, usize),
+    width: usize,
+    height: usize,
+}
+
+impl Garden {
+    /// Whether `(x, y)` is a plot on the infinitely repeated garden, with
+    /// the top left corner of the original at `(0, 0)`.
+    fn is_tiled_plot(&self, (x, y): (i64, i64)) -> bool {
+        let x = x.rem_euclid(self.width as i64) as usize + 1;
+        let y = y.rem_euclid(self.height as i64) as usize + 1;
+        self.plots.contains(&(x, y))
+    }
+
+    fn tiled_start(&self) -> (i64, i64) {
+        (self.start.0 as i64 - 1, self.start.1 as i64 - 1)
+    }
+}
+
+pub fn part1_do(garden: &Garden, mut steps: usize) -> usize {
+    let mut positions = HashSet::from([garden.start]);
 
     while steps > 0 {
         let mut new_positions = HashSet::new();
         for &(x, y) in &positions {
             for &(x, y) in &[(x, y - 1), (x, y + 1), (x - 1, y), (x + 1, y)] {
-                if plots.contains(&(x, y)) {
+                if garden.plots.contains(&(x, y)) {
                     new_positions.insert((x, y));
                 }
             }
@@ -41,26 +41,203 @@ pub fn part1_do(input: &str, mut steps: usize) -> usize {
     positions.len()
 }
 
-pub fn part1(input: &str) -> usize {
-    part1_do(input, 64)
+/// Steps through every position on the infinite garden, only fit for
+/// checking [`infinite_reachable`].
+pub fn tiled_brute_force(garden: &Garden, steps: usize) -> usize {
+    // nothing can get further than `steps` from the start
+    let reach = steps as i64 + 1;
+    let side = 2 * reach + 1;
+    let (start_x, start_y) = garden.tiled_start();
+    let index = |(x, y): (i64, i64)| ((y - start_y + reach) * side + x - start_x + reach) as usize;
+
+    let (width, height) = (garden.width as i64, garden.height as i64);
+    let tile: Vec<bool> = (0..width * height)
+        .map(|i| garden.is_tiled_plot((i % width, i / width)))
+        .collect();
+    let is_plot =
+        |(x, y): (i64, i64)| tile[(y.rem_euclid(height) * width + x.rem_euclid(width)) as usize];
+
+    let mut positions = vec![garden.tiled_start()];
+    // the step each position was last reached in
+    let mut reached = vec![0; (side * side) as usize];
+    for step in 1..=steps {
+        let mut next = Vec::new();
+        for (x, y) in positions {
+            for pos in [(x, y - 1), (x, y + 1), (x - 1, y), (x + 1, y)] {
+                if reached[index(pos)] != step && is_plot(pos) {
+                    reached[index(pos)] = step;
+                    next.push(pos);
+                }
+            }
+        }
+        positions = next;
+    }
+    positions.len()
+}
+
+/// How many plots of the infinite garden are first reached after each number
+/// of steps up to `max_steps`.
+fn first_reached(garden: &Garden, max_steps: usize) -> Vec<usize> {
+    // nothing further than `max_steps` from the start is ever looked at
+    let side = 2 * max_steps + 3;
+    let corner = garden.tiled_start().0 - max_steps as i64 - 1;
+    let top = garden.tiled_start().1 - max_steps as i64 - 1;
+    let tile: Vec<Vec<bool>> = (0..garden.height as i64)
+        .map(|y| {
+            (0..garden.width as i64)
+                .map(|x| garden.is_tiled_plot((x, y)))
+                .collect()
+        })
+        .collect();
+    let mut open: Vec<bool> = (0..side * side)
+        .map(|i| {
+            let x = (corner + (i % side) as i64).rem_euclid(garden.width as i64);
+            let y = (top + (i / side) as i64).rem_euclid(garden.height as i64);
+            tile[y as usize][x as usize]
+        })
+        .collect();
+
+    let start = (max_steps + 1) * side + max_steps + 1;
+    open[start] = false;
+    let mut frontier = vec![start];
+    let mut counts = Vec::with_capacity(max_steps + 1);
+    for _ in 0..=max_steps {
+        counts.push(frontier.len());
+        let mut next = Vec::new();
+        for i in frontier {
+            for neighbour in [i - side, i + side, i - 1, i + 1] {
+                if open[neighbour] {
+                    open[neighbour] = false;
+                    next.push(neighbour);
+                }
+            }
+        }
+        frontier = next;
+    }
+    counts
+}
+
+/// Plots reachable in exactly `steps` on the infinite garden. Once the
+/// reachable area covers whole copies of the garden, it grows as a diamond, so
+/// the counts for every `period` steps follow a quadratic: the plots are
+/// counted by BFS until that shows in the second differences, and
+/// extrapolated from there.
+pub fn infinite_reachable(garden: &Garden, steps: usize) -> usize {
+    // copies of the garden alternate parity when the width is odd
+    let period = 2 * garden.width.max(garden.height);
+    let offset = steps % period;
+    let target = steps / period;
+
+    let mut samples = 4;
+    loop {
+        let max_steps = offset + samples * period;
+        // plots reached in `n` or fewer steps, with the same parity as `n`
+        let mut reachable = first_reached(garden, max_steps.min(steps));
+        for n in 2..reachable.len() {
+            reachable[n] += reachable[n - 2];
+        }
+        if steps <= max_steps {
+            return reachable[steps];
+        }
+
+        let values: Vec<i64> = (0..=samples)
+            .map(|k| reachable[offset + k * period] as i64)
+            .collect();
+        let second: Vec<i64> = values.windows(3).map(|w| w[2] - 2 * w[1] + w[0]).collect();
+        if let Some(k) = second.windows(3).position(|w| w[0] == w[1] && w[1] == w[2]) {
+            let first = values[k + 1] - values[k];
+            let m = (target - k) as i64;
+            return (values[k] + m * first + m * (m - 1) / 2 * second[k]) as usize;
+        }
+        samples *= 2;
+    }
 }
 
-pub fn part2(input: &str) -> usize {
-    //todo!()
-    0
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Garden;
+
+    fn parse(input: &str) -> Self::Input {
+        let mut start = (0, 0);
+        let plots = input
+            .trim()
+            .lines()
+            .enumerate()
+            .flat_map(|(y, line)| {
+                let mut plots_from_row = HashSet::new();
+                for (x, c) in line.chars().enumerate() {
+                    plots_from_row.insert(match c {
+                        '.' => (x + 1, y + 1),
+                        'S' => {
+                            start = (x + 1, y + 1);
+                            (x + 1, y + 1)
+                        }
+                        '#' => continue,
+                        _ => panic!("invalid char: {}", c),
+                    });
+                }
+                plots_from_row
+            })
+            .collect::<HashSet<_>>();
+        let height = input.trim().lines().count();
+        let width = input.trim().lines().next().map_or(0, str::len);
+        Garden {
+            plots,
+            start,
+            width,
+            height,
+        }
+    }
+
+    fn part1(garden: &Self::Input) -> Answer {
+        part1_do(garden, 64).into()
+    }
+
+    fn part2(garden: &Self::Input) -> Answer {
+        infinite_reachable(garden, 26501365).into()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_core::{read, Part};
+    use test_case::test_case;
 
     #[test]
     fn part1_sample() {
-        assert_eq!(16, part1_do("sample.txt", 6));
+        assert_eq!(16, part1_do(&Day::parse(&read("sample.txt")), 6));
     }
 
     #[test]
     fn part1_input() {
-        assert_eq!(3646, part1("input.txt"));
+        assert_eq!(3646, Day::solve(Part::One, &read("input.txt")));
+    }
+
+    #[test_case(6 => 16)]
+    #[test_case(10 => 50)]
+    #[test_case(50 => 1594)]
+    #[test_case(100 => 6536)]
+    #[test_case(150 => 14919)]
+    #[test_case(200 => 26538)]
+    #[test_case(327 => 71435)]
+    fn brute_forcing_the_sample(steps: usize) -> usize {
+        let garden = Day::parse(&read("sample.txt"));
+        let brute_force = tiled_brute_force(&garden, steps);
+        assert_eq!(brute_force, infinite_reachable(&garden, steps));
+        brute_force
+    }
+
+    #[test_case(500 => 167004)]
+    #[test_case(1000 => 668697)]
+    #[test_case(5000 => 16733044)]
+    fn extrapolating_the_sample(steps: usize) -> usize {
+        infinite_reachable(&Day::parse(&read("sample.txt")), steps)
+    }
+
+    #[test]
+    fn part2_input() {
+        assert_eq!(606188414811259, Day::solve(Part::Two, &read("input.txt")));
     }
 }
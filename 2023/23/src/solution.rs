@@ -1,28 +1,175 @@
-use anyhow::bail;
-use scan_fmt::scan_fmt;
-use std::fs::read_to_string;
+use aoc_core::{Answer, Solution};
+use aoc_grid::{Direction, Grid, Point};
+use std::collections::HashMap;
 
-pub fn part1(input: &str) -> usize {
-    //todo!()
-    0
+/// The trails reduced to their junctions: `edges[j]` lists the junctions
+/// reachable from junction `j` and the length of the trail to each.
+struct Graph {
+    edges: Vec<Vec<(usize, usize)>>,
+    start: usize,
+    end: usize,
 }
 
-pub fn part2(input: &str) -> usize {
-    //todo!()
-    0
+fn slope(dir: Direction) -> char {
+    match dir {
+        Direction::Up => '^',
+        Direction::Right => '>',
+        Direction::Down => 'v',
+        Direction::Left => '<',
+    }
+}
+
+/// Whether leaving `point` towards `dir` is allowed: on a slope only downhill.
+fn can_leave(map: &Grid<char>, point: Point, dir: Direction, slippery: bool) -> bool {
+    match map[point] {
+        '.' => true,
+        c if !slippery => c != '#',
+        c => c == slope(dir),
+    }
+}
+
+fn open(map: &Grid<char>, point: Point, dir: Direction) -> Option<Point> {
+    map.step(point, dir).filter(|&next| map[next] != '#')
+}
+
+fn compress(map: &Grid<char>, slippery: bool) -> Graph {
+    let start = map.find(|&c| c == '.').expect("a start");
+    let end = map.find_all(|&c| c == '.').last().expect("an end");
+    let junctions: HashMap<Point, usize> = map
+        .find_all(|&c| c != '#')
+        .filter(|&point| {
+            point == start
+                || point == end
+                || Direction::ALL
+                    .into_iter()
+                    .filter(|&dir| open(map, point, dir).is_some())
+                    .count()
+                    > 2
+        })
+        .enumerate()
+        .map(|(id, point)| (point, id))
+        .collect();
+
+    let mut edges = vec![Vec::new(); junctions.len()];
+    for (&junction, &id) in &junctions {
+        for dir in Direction::ALL {
+            let mut dir = dir;
+            let mut point = junction;
+            let mut length = 0;
+            // follow the trail until the next junction, or a dead end
+            let target = loop {
+                if !can_leave(map, point, dir, slippery) {
+                    break None;
+                }
+                let Some(next) = open(map, point, dir) else {
+                    break None;
+                };
+                point = next;
+                length += 1;
+                if let Some(&target) = junctions.get(&point) {
+                    break Some(target);
+                }
+                let Some(next_dir) = [dir, dir.turn_left(), dir.turn_right()]
+                    .into_iter()
+                    .find(|&turn| open(map, point, turn).is_some())
+                else {
+                    break None;
+                };
+                dir = next_dir;
+            };
+            if let Some(target) = target {
+                edges[id].push((target, length));
+            }
+        }
+    }
+
+    Graph {
+        edges,
+        start: junctions[&start],
+        end: junctions[&end],
+    }
+}
+
+impl Graph {
+    /// The longest path from `from` to the end that avoids the junctions in
+    /// `visited`, or `None` if the end can't be reached.
+    fn longest_hike(&self, from: usize, visited: u64) -> Option<usize> {
+        if from == self.end {
+            return Some(0);
+        }
+        let visited = visited | 1 << from;
+        let edges = &self.edges[from];
+        // the end is behind a single junction: turning away from it there
+        // would close off the only way to it
+        if let Some(&(_, length)) = edges.iter().find(|&&(to, _)| to == self.end) {
+            return Some(length);
+        }
+        edges
+            .iter()
+            .filter(|&&(to, _)| visited & 1 << to == 0)
+            .filter_map(|&(to, length)| Some(length + self.longest_hike(to, visited)?))
+            .max()
+    }
+}
+
+fn longest_hike(map: &Grid<char>, slippery: bool) -> usize {
+    let graph = compress(map, slippery);
+    assert!(graph.edges.len() <= 64, "too many junctions for a u64");
+    graph
+        .longest_hike(graph.start, 0)
+        .expect("a way to the end")
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Self::Input {
+        Grid::parse(input.trim())
+    }
+
+    fn part1(map: &Self::Input) -> Answer {
+        longest_hike(map, true).into()
+    }
+
+    fn part2(map: &Self::Input) -> Answer {
+        longest_hike(map, false).into()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_core::{read, Part};
+
+    #[test]
+    fn compressing_the_sample() {
+        let map = Day::parse(&read("sample.txt"));
+        // start, end and seven junctions in between
+        assert_eq!(9, compress(&map, true).edges.len());
+        let graph = compress(&map, false);
+        let edges = graph.edges.iter().map(Vec::len).sum::<usize>();
+        assert_eq!(2 * 12, edges);
+    }
 
     #[test]
     fn part1_sample() {
-        assert_eq!(94, part1("sample.txt"));
+        assert_eq!(94, Day::solve(Part::One, &read("sample.txt")));
     }
 
     #[test]
     fn part1_input() {
-        assert_eq!(0, part1("input.txt"));
+        assert_eq!(1966, Day::solve(Part::One, &read("input.txt")));
+    }
+
+    #[test]
+    fn part2_sample() {
+        assert_eq!(154, Day::solve(Part::Two, &read("sample.txt")));
+    }
+
+    #[test]
+    fn part2_input() {
+        assert_eq!(6286, Day::solve(Part::Two, &read("input.txt")));
     }
 }
@@ -1,17 +1,16 @@
+use aoc_core::{Answer, Solution};
 use pathfinding::prelude::*;
 
 use std::collections::HashSet;
-use std::fs::read_to_string;
-
-pub fn part1(input: &str) -> usize {
-    let input = read_to_string(input).unwrap();
-    let edges = input
-        .trim()
-        .lines()
-        .flat_map(|line| {
-            let (from, tos) = line.split_once(": ").expect("semicolon");
-            tos.split(' ')
-                .flat_map(move |to| vec![((from, to), 1), ((to, from), 1)])
+
+fn cut_in_two(wires: &[(String, String)]) -> usize {
+    let edges = wires
+        .iter()
+        .flat_map(|(from, to)| {
+            vec![
+                ((from.as_str(), to.as_str()), 1),
+                ((to.as_str(), from.as_str()), 1),
+            ]
         })
         .collect::<Vec<Edge<&str, i32>>>();
 
@@ -61,22 +60,45 @@ pub fn part1(input: &str) -> usize {
     (segment1.len() + 1) * (segment2.len() + 1)
 }
 
-pub fn part2(_input: &str) -> usize {
-    //todo!()
-    0
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<(String, String)>;
+
+    fn parse(input: &str) -> Self::Input {
+        input
+            .trim()
+            .lines()
+            .flat_map(|line| {
+                let (from, tos) = line.split_once(": ").expect("semicolon");
+                tos.split(' ')
+                    .map(move |to| (from.to_string(), to.to_string()))
+            })
+            .collect()
+    }
+
+    fn part1(wires: &Self::Input) -> Answer {
+        cut_in_two(wires).into()
+    }
+
+    fn part2(_wires: &Self::Input) -> Answer {
+        //todo!()
+        0.into()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_core::{read, Part};
 
     #[test]
     fn part1_sample() {
-        assert_eq!(54, part1("sample.txt"));
+        assert_eq!(54, Day::solve(Part::One, &read("sample.txt")));
     }
 
     #[test]
     fn part1_input() {
-        assert_eq!(543564, part1("input.txt"));
+        assert_eq!(543564, Day::solve(Part::One, &read("input.txt")));
     }
 }
@@ -1,145 +1,93 @@
-use anyhow::bail;
-use scan_fmt::scan_fmt;
-use std::collections::HashMap;
-use std::fs::read_to_string;
-
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
-enum Direction {
-    N,
-    NE,
-    E,
-    SE,
-    S,
-    SW,
-    W,
-    NW,
-}
-
-pub fn part1(input: &str) -> usize {
-    let map = parse(input);
-
-    map.iter()
-        .filter(|(_, &c)| c == 'X')
-        .map(|(pos, _)| count_xmases(&map, *pos))
-        .sum()
-}
-
-fn parse(input: &str) -> HashMap<(usize, usize), char> {
-    let input = read_to_string(input).unwrap();
-    let map: HashMap<_, _> = input
-        .trim()
-        .lines()
-        .enumerate()
-        .flat_map(|(y, line)| {
-            line.chars()
-                .enumerate()
-                .map(move |(x, c)| ((x + 1, y + 1), c))
+use aoc_core::{Answer, Solution};
+use aoc_grid::{Direction8, Grid, Point};
+
+fn count_xmases(map: &Grid<char>, pos: Point) -> usize {
+    Direction8::ALL
+        .into_iter()
+        .filter(|&dir| {
+            map.ray(pos, dir)
+                .map(|pos| map[pos])
+                .take(3)
+                .eq("MAS".chars())
         })
-        .collect();
-    map
+        .count()
 }
 
-fn count_xmases(map: &HashMap<(usize, usize), char>, pos: (usize, usize)) -> usize {
-    let mut count = 0;
-    for &dir in &[
-        Direction::N,
-        Direction::NE,
-        Direction::E,
-        Direction::SE,
-        Direction::S,
-        Direction::SW,
-        Direction::W,
-        Direction::NW,
-    ] {
-        let mut pos = pos;
-        for l in "MAS".chars() {
-            pos = match dir {
-                Direction::N => (pos.0, pos.1 - 1),
-                Direction::NE => (pos.0 + 1, pos.1 - 1),
-                Direction::E => (pos.0 + 1, pos.1),
-                Direction::SE => (pos.0 + 1, pos.1 + 1),
-                Direction::S => (pos.0, pos.1 + 1),
-                Direction::SW => (pos.0 - 1, pos.1 + 1),
-                Direction::W => (pos.0 - 1, pos.1),
-                Direction::NW => (pos.0 - 1, pos.1 - 1),
-            };
-            if pos.0 == 0 || pos.1 == 0 {
-                break;
-            }
-            match map.get(&pos) {
-                Some(&c) if c == l => {
-                    if l == 'S' {
-                        count += 1
-                    }
-                }
-                _ => break,
-            }
-        }
-    }
-    count
+fn count_x_mases(map: &Grid<char>, pos: Point) -> usize {
+    let corner = |dir: Direction8| map.step(pos, dir).map(|pos| map[pos]);
+
+    // the two Ms are in neighbouring corners, a quarter turn apart
+    let found = Direction8::ALL
+        .into_iter()
+        .filter(|dir| dir.is_diagonal())
+        .any(|first| {
+            let second = first.turn_right().turn_right();
+            corner(first) == Some('M')
+                && corner(second) == Some('M')
+                && corner(first.opposite()) == Some('S')
+                && corner(second.opposite()) == Some('S')
+        });
+    found as usize
 }
 
-fn count_x_mases(map: &HashMap<(usize, usize), char>, pos: (usize, usize)) -> usize {
-    let ne = map.get(&(pos.0 + 1, pos.1 - 1));
-    let se = map.get(&(pos.0 + 1, pos.1 + 1));
-    let sw = map.get(&(pos.0 - 1, pos.1 + 1));
-    let nw = map.get(&(pos.0 - 1, pos.1 - 1));
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Grid<char>;
 
-    if ne == Some(&'M') && se == Some(&'M') && sw == Some(&'S') && nw == Some(&'S') {
-        1
-    } else if se == Some(&'M') && sw == Some(&'M') && nw == Some(&'S') && ne == Some(&'S') {
-        1
-    } else if sw == Some(&'M') && nw == Some(&'M') && ne == Some(&'S') && se == Some(&'S') {
-        1
-    } else if nw == Some(&'M') && ne == Some(&'M') && se == Some(&'S') && sw == Some(&'S') {
-        1
-    } else {
-        0
+    fn parse(input: &str) -> Self::Input {
+        Grid::parse(input.trim())
     }
-}
 
-pub fn part2(input: &str) -> usize {
-    let map = parse(input);
+    fn part1(map: &Self::Input) -> Answer {
+        map.find_all(|&c| c == 'X')
+            .map(|pos| count_xmases(map, pos))
+            .sum::<usize>()
+            .into()
+    }
 
-    map.iter()
-        .filter(|(_, &c)| c == 'A')
-        .map(|(pos, _)| count_x_mases(&map, *pos))
-        .sum()
+    fn part2(map: &Self::Input) -> Answer {
+        map.find_all(|&c| c == 'A')
+            .map(|pos| count_x_mases(map, pos))
+            .sum::<usize>()
+            .into()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_core::{read, Part};
 
     #[test]
     fn part1_sample() {
-        assert_eq!(18, part1("sample.txt"));
+        assert_eq!(18, Day::solve(Part::One, &read("sample.txt")));
     }
 
     #[test]
     fn part1_input() {
-        assert_eq!(2496, part1("input.txt"));
+        assert_eq!(2496, Day::solve(Part::One, &read("input.txt")));
     }
 
     #[test]
     fn part2_sample() {
-        assert_eq!(9, part2("sample.txt"));
+        assert_eq!(9, Day::solve(Part::Two, &read("sample.txt")));
     }
 
     #[test]
     fn part2_input() {
-        assert_eq!(1967, part2("input.txt"));
+        assert_eq!(1967, Day::solve(Part::Two, &read("input.txt")));
     }
 
     #[test]
     fn counting_xmases() {
-        let map = parse("sample.txt");
-        assert_eq!(1, count_xmases(&map, (5, 1)));
+        let map = Day::parse(&read("sample.txt"));
+        assert_eq!(1, count_xmases(&map, (4, 0)));
     }
 
     #[test]
     fn counting_x_mases() {
-        let map = parse("sample.txt");
-        assert_eq!(1, count_x_mases(&map, (3, 2)));
+        let map = Day::parse(&read("sample.txt"));
+        assert_eq!(1, count_x_mases(&map, (2, 1)));
     }
 }
@@ -1,24 +1,23 @@
-use anyhow::bail;
+use aoc_core::{Answer, Solution};
 use scan_fmt::scan_fmt;
-use std::collections::{HashMap, HashSet};
-use std::fs::read_to_string;
+use std::collections::HashSet;
 
-pub fn part1(input: &str) -> usize {
-    let (lt, gt, pages) = parse(input);
+pub type Rules = HashSet<(usize, usize)>;
 
+fn sum_ordered_middles((lt, gt, pages): &(Rules, Rules, Vec<Vec<usize>>)) -> usize {
     let mut sum = 0;
     for p in pages {
         let mut sorted = p.clone();
         sorted.sort_by(|a, b| {
             if lt.contains(&(*a, *b)) {
-                return std::cmp::Ordering::Less;
+                std::cmp::Ordering::Less
             } else if gt.contains(&(*a, *b)) {
-                return std::cmp::Ordering::Greater;
+                std::cmp::Ordering::Greater
             } else {
                 panic!("no rule for {} and {}", a, b);
             }
         });
-        if sorted == p {
+        if sorted == *p {
             // find middle item in p
             let middle = p.len() / 2;
             sum += p[middle];
@@ -28,15 +27,8 @@ pub fn part1(input: &str) -> usize {
     sum
 }
 
-fn parse(
-    input: &str,
-) -> (
-    HashSet<(usize, usize)>,
-    HashSet<(usize, usize)>,
-    Vec<Vec<usize>>,
-) {
-    let input = read_to_string(input).unwrap();
-    let mut lines = input.lines();
+fn parse(input: &str) -> (Rules, Rules, Vec<Vec<usize>>) {
+    let lines = input.lines();
     let mut rules = true;
     let mut lt = HashSet::new();
     let mut gt = HashSet::new();
@@ -63,22 +55,20 @@ fn parse(
     (lt, gt, pages)
 }
 
-pub fn part2(input: &str) -> usize {
-    let (lt, gt, pages) = parse(input);
-
+fn sum_reordered_middles((lt, gt, pages): &(Rules, Rules, Vec<Vec<usize>>)) -> usize {
     let mut sum = 0;
     for p in pages {
         let mut sorted = p.clone();
         sorted.sort_by(|a, b| {
             if lt.contains(&(*a, *b)) {
-                return std::cmp::Ordering::Less;
+                std::cmp::Ordering::Less
             } else if gt.contains(&(*a, *b)) {
-                return std::cmp::Ordering::Greater;
+                std::cmp::Ordering::Greater
             } else {
                 panic!("no rule for {} and {}", a, b);
             }
         });
-        if sorted != p {
+        if sorted != *p {
             let middle = sorted.len() / 2;
             sum += sorted[middle];
         }
@@ -87,27 +77,46 @@ pub fn part2(input: &str) -> usize {
     sum
 }
 
+pub struct Day;
+
+impl Solution for Day {
+    type Input = (Rules, Rules, Vec<Vec<usize>>);
+
+    fn parse(input: &str) -> Self::Input {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        sum_ordered_middles(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        sum_reordered_middles(input).into()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_core::{read, Part};
 
     #[test]
     fn part1_sample() {
-        assert_eq!(143, part1("sample.txt"));
+        assert_eq!(143, Day::solve(Part::One, &read("sample.txt")));
     }
 
     #[test]
     fn part1_input() {
-        assert_eq!(4905, part1("input.txt"));
+        assert_eq!(4905, Day::solve(Part::One, &read("input.txt")));
     }
 
     #[test]
     fn part2_sample() {
-        assert_eq!(123, part2("sample.txt"));
+        assert_eq!(123, Day::solve(Part::Two, &read("sample.txt")));
     }
 
     #[test]
     fn part2_input() {
-        assert_eq!(6204, part2("input.txt"));
+        assert_eq!(6204, Day::solve(Part::Two, &read("input.txt")));
     }
 }
@@ -1,46 +1,14 @@
-use anyhow::bail;
-use scan_fmt::scan_fmt;
+use aoc_core::{Answer, Solution};
+use aoc_grid::{Direction, Pos};
 use std::collections::HashSet;
-use std::fs::read_to_string;
 
-type Pos = (usize, usize);
-
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
-enum Direction {
-    Up,
-    Down,
-    Left,
-    Right,
-}
-
-impl Direction {
-    fn step(&self, pos: Pos) -> Pos {
-        match self {
-            Direction::Up => (pos.0, pos.1 - 1),
-            Direction::Down => (pos.0, pos.1 + 1),
-            Direction::Left => (pos.0 - 1, pos.1),
-            Direction::Right => (pos.0 + 1, pos.1),
-        }
-    }
-
-    fn bump(&self) -> Direction {
-        match self {
-            Direction::Up => Direction::Right,
-            Direction::Right => Direction::Down,
-            Direction::Down => Direction::Left,
-            Direction::Left => Direction::Up,
-        }
-    }
-}
-
-pub fn part1(input: &str) -> usize {
-    let (mut dir, mut start, cols, rows, obstacles) = parse(input);
+fn patrol(&(mut dir, mut start, cols, rows, ref obstacles): &Lab) -> usize {
     let mut visited = HashSet::new();
-    while (start.0 > 0 && start.1 > 0 && start.0 <= cols && start.1 <= rows) {
+    while start.x > 0 && start.y > 0 && start.x <= cols && start.y <= rows {
         visited.insert(start);
-        let pos = dir.step(start);
+        let pos = start + dir;
         if obstacles.contains(&pos) {
-            dir = dir.bump();
+            dir = dir.turn_right();
         } else {
             start = pos;
         }
@@ -48,24 +16,25 @@ pub fn part1(input: &str) -> usize {
     visited.len()
 }
 
-fn parse(input: &str) -> (Direction, Pos, usize, usize, HashSet<(usize, usize)>) {
-    let input = read_to_string(input).unwrap();
-    let mut dir = Direction::Up;
-    let mut start: Pos = (0, 0);
+pub type Lab = (Direction, Pos, i64, i64, HashSet<Pos>);
+
+fn parse(input: &str) -> Lab {
+    let dir = Direction::Up;
+    let mut start = Pos::default();
     let mut cols = 0;
     let mut rows = 0;
     let mut obstacles = HashSet::new();
 
     for (y, line) in input.trim().lines().enumerate() {
         for (x, c) in line.chars().enumerate() {
-            cols = cols.max(x + 1);
-            rows = rows.max(y + 1);
+            cols = cols.max(x as i64 + 1);
+            rows = rows.max(y as i64 + 1);
             match c {
                 '#' => {
-                    obstacles.insert((x + 1, y + 1));
+                    obstacles.insert(Pos::new(x as i64 + 1, y as i64 + 1));
                 }
                 '^' => {
-                    start = (x + 1, y + 1);
+                    start = Pos::new(x as i64 + 1, y as i64 + 1);
                 }
                 _ => {}
             }
@@ -74,36 +43,35 @@ fn parse(input: &str) -> (Direction, Pos, usize, usize, HashSet<(usize, usize)>)
     (dir, start, cols, rows, obstacles)
 }
 
-pub fn part2(input: &str) -> usize {
-    let (mut dir, start, cols, rows, obstacles) = parse(input);
+fn loop_obstacles(&(mut dir, start, cols, rows, ref obstacles): &Lab) -> usize {
     let mut pos = start;
     let mut new_obstacles = HashSet::new();
     let mut visited = HashSet::new();
-    while (pos.0 > 0 && pos.1 > 0 && pos.0 <= cols && pos.1 <= rows) {
+    while pos.x > 0 && pos.y > 0 && pos.x <= cols && pos.y <= rows {
         visited.insert(pos);
-        let mut check_dir = dir.bump();
+        let mut check_dir = dir.turn_right();
         let mut check_pos = pos;
         let mut checked = HashSet::new();
-        let new_pos = dir.step(pos);
+        let new_pos = pos + dir;
         while !obstacles.contains(&new_pos)
             && !visited.contains(&new_pos)
-            && (new_pos.0 > 0 && new_pos.1 > 0 && new_pos.0 <= cols && new_pos.1 <= rows)
-            && (check_pos.0 > 0 && check_pos.1 > 0 && check_pos.0 <= cols && check_pos.1 <= rows)
+            && (new_pos.x > 0 && new_pos.y > 0 && new_pos.x <= cols && new_pos.y <= rows)
+            && (check_pos.x > 0 && check_pos.y > 0 && check_pos.x <= cols && check_pos.y <= rows)
         {
             if !checked.insert((check_pos, check_dir)) {
                 new_obstacles.insert(new_pos);
                 break;
             }
-            let new_check_pos = check_dir.step(check_pos);
+            let new_check_pos = check_pos + check_dir;
             if obstacles.contains(&new_check_pos) || new_pos == new_check_pos {
-                check_dir = check_dir.bump();
+                check_dir = check_dir.turn_right();
             } else {
                 check_pos = new_check_pos;
             }
         }
 
         if obstacles.contains(&new_pos) {
-            dir = dir.bump();
+            dir = dir.turn_right();
         } else {
             pos = new_pos;
         }
@@ -111,27 +79,46 @@ pub fn part2(input: &str) -> usize {
     new_obstacles.len() - new_obstacles.contains(&start) as usize
 }
 
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Lab;
+
+    fn parse(input: &str) -> Self::Input {
+        parse(input)
+    }
+
+    fn part1(lab: &Self::Input) -> Answer {
+        patrol(lab).into()
+    }
+
+    fn part2(lab: &Self::Input) -> Answer {
+        loop_obstacles(lab).into()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_core::{read, Part};
 
     #[test]
     fn part1_sample() {
-        assert_eq!(41, part1("sample.txt"));
+        assert_eq!(41, Day::solve(Part::One, &read("sample.txt")));
     }
 
     #[test]
     fn part1_input() {
-        assert_eq!(5086, part1("input.txt"));
+        assert_eq!(5086, Day::solve(Part::One, &read("input.txt")));
     }
 
     #[test]
     fn part2_sample() {
-        assert_eq!(6, part2("sample.txt"));
+        assert_eq!(6, Day::solve(Part::Two, &read("sample.txt")));
     }
 
     #[test]
     fn part2_input() {
-        assert_eq!(1770, part2("input.txt"));
+        assert_eq!(1770, Day::solve(Part::Two, &read("input.txt")));
     }
 }
@@ -1,8 +1,7 @@
+use aoc_core::{Answer, Solution};
 use itertools::Itertools;
-use std::fs::read_to_string;
 
 fn parse(input: &str) -> Vec<(usize, Vec<usize>)> {
-    let input = read_to_string(input).unwrap();
     let equations = input
         .trim()
         .lines()
@@ -36,7 +35,7 @@ fn is_result_rec(sum: usize, inputs: &[usize], last_ndx: usize, with_concat: boo
         found = is_result_rec(sum - last, inputs, last_ndx - 1, with_concat)
     }
 
-    if !found && sum % last == 0 {
+    if !found && sum.is_multiple_of(last) {
         found = is_result_rec(sum / last, inputs, last_ndx - 1, with_concat)
     }
 
@@ -50,25 +49,38 @@ fn is_result_rec(sum: usize, inputs: &[usize], last_ndx: usize, with_concat: boo
     found
 }
 
-pub fn part1(input: &str) -> usize {
-    parse(input)
-        .iter()
-        .filter(|(sum, inputs)| is_result(*sum, inputs, false))
-        .map(|(sum, _)| sum)
-        .sum()
-}
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<(usize, Vec<usize>)>;
+
+    fn parse(input: &str) -> Self::Input {
+        parse(input)
+    }
 
-pub fn part2(input: &str) -> usize {
-    parse(input)
-        .iter()
-        .filter(|(sum, inputs)| is_result(*sum, inputs, true))
-        .map(|(sum, _)| sum)
-        .sum()
+    fn part1(equations: &Self::Input) -> Answer {
+        equations
+            .iter()
+            .filter(|(sum, inputs)| is_result(*sum, inputs, false))
+            .map(|(sum, _)| sum)
+            .sum::<usize>()
+            .into()
+    }
+
+    fn part2(equations: &Self::Input) -> Answer {
+        equations
+            .iter()
+            .filter(|(sum, inputs)| is_result(*sum, inputs, true))
+            .map(|(sum, _)| sum)
+            .sum::<usize>()
+            .into()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_core::{read, Part};
     use test_case::test_case;
 
     #[test_case(8, &[2, 4], true => true)]
@@ -88,21 +100,21 @@ mod tests {
 
     #[test]
     fn part1_sample() {
-        assert_eq!(3749, part1("sample.txt"));
+        assert_eq!(3749, Day::solve(Part::One, &read("sample.txt")));
     }
 
     #[test]
     fn part1_input() {
-        assert_eq!(2654749936343, part1("input.txt"));
+        assert_eq!(2654749936343, Day::solve(Part::One, &read("input.txt")));
     }
 
     #[test]
     fn part2_sample() {
-        assert_eq!(11387, part2("sample.txt"));
+        assert_eq!(11387, Day::solve(Part::Two, &read("sample.txt")));
     }
 
     #[test]
     fn part2_input() {
-        assert_eq!(124060392153684, part2("input.txt"));
+        assert_eq!(124060392153684, Day::solve(Part::Two, &read("input.txt")));
     }
 }
@@ -1,10 +1,10 @@
-use anyhow::bail;
+use aoc_core::{Answer, Solution};
 use itertools::Itertools;
-use scan_fmt::scan_fmt;
 use std::collections::{HashMap, HashSet};
-use std::fs::read_to_string;
 
-fn parse(input: String) -> (i32, i32, HashMap<char, Vec<(i32, i32)>>) {
+pub type Antennas = HashMap<char, Vec<(i32, i32)>>;
+
+fn parse(input: &str) -> (i32, i32, Antennas) {
     let cols = input.lines().next().unwrap().len();
     let rows = input.lines().count();
     let antennas = input
@@ -24,10 +24,7 @@ fn parse(input: String) -> (i32, i32, HashMap<char, Vec<(i32, i32)>>) {
     (cols as i32, rows as i32, antennas)
 }
 
-pub fn part1(input: &str) -> usize {
-    let input = read_to_string(input).unwrap();
-    let (cols, rows, antennas) = parse(input);
-
+fn count_antinodes(&(cols, rows, ref antennas): &(i32, i32, Antennas)) -> usize {
     antennas
         .values()
         .flat_map(|antennas| {
@@ -43,10 +40,7 @@ pub fn part1(input: &str) -> usize {
         .len()
 }
 
-pub fn part2(input: &str) -> usize {
-    let input = read_to_string(input).unwrap();
-    let (cols, rows, antennas) = parse(input);
-
+fn count_harmonics(&(cols, rows, ref antennas): &(i32, i32, Antennas)) -> usize {
     antennas
         .values()
         .flat_map(|antennas| {
@@ -87,9 +81,28 @@ fn antinodes(p0: (i32, i32), p1: (i32, i32)) -> ((i32, i32), (i32, i32)) {
     ((p1.0 + dx, p1.1 + dy), (p0.0 - dx, p0.1 - dy))
 }
 
+pub struct Day;
+
+impl Solution for Day {
+    type Input = (i32, i32, Antennas);
+
+    fn parse(input: &str) -> Self::Input {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        count_antinodes(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        count_harmonics(input).into()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_core::{read, Part};
     use test_case::test_case;
 
     #[test_case((4,3), (5,5), (3,1) => true)]
@@ -107,21 +120,21 @@ mod tests {
 
     #[test]
     fn part1_sample() {
-        assert_eq!(14, part1("sample.txt"));
+        assert_eq!(14, Day::solve(Part::One, &read("sample.txt")));
     }
 
     #[test]
     fn part1_input() {
-        assert_eq!(336, part1("input.txt"));
+        assert_eq!(336, Day::solve(Part::One, &read("input.txt")));
     }
 
     #[test]
     fn part2_sample() {
-        assert_eq!(34, part2("sample.txt"));
+        assert_eq!(34, Day::solve(Part::Two, &read("sample.txt")));
     }
 
     #[test]
     fn part2_input() {
-        assert_eq!(1131, part2("input.txt"));
+        assert_eq!(1131, Day::solve(Part::Two, &read("input.txt")));
     }
 }
@@ -1,5 +1,5 @@
+use aoc_core::{Answer, Solution};
 use std::collections::HashSet;
-use std::fs::read_to_string;
 
 enum Block {
     File(i32, usize, usize),
@@ -7,7 +7,6 @@ enum Block {
 }
 
 fn parse(input: &str, small_blocks: bool) -> Vec<Block> {
-    let input = read_to_string(input).unwrap();
     let input = input.trim();
 
     let mut disk = Vec::new();
@@ -41,7 +40,7 @@ fn parse(input: &str, small_blocks: bool) -> Vec<Block> {
     disk
 }
 
-pub fn part1(input: &str) -> usize {
+fn compact_blocks(input: &str) -> usize {
     let disk = parse(input, true);
 
     let mut checksum = 0;
@@ -68,7 +67,7 @@ pub fn part1(input: &str) -> usize {
     checksum
 }
 
-pub fn part2(input: &str) -> usize {
+fn compact_files(input: &str) -> usize {
     let disk = parse(input, false);
 
     let mut moved_files = HashSet::new();
@@ -85,7 +84,6 @@ pub fn part2(input: &str) -> usize {
                             moved_files.insert(id);
                             free_space_index += file_size;
                             free_space_size -= file_size;
-                            assert!(free_space_size >= 0);
                         }
                         _ => {
                             continue;
@@ -118,27 +116,48 @@ pub fn part2(input: &str) -> usize {
     checksum
 }
 
+pub struct Day;
+
+impl Solution for Day {
+    // Part 1 splits the disk map into single blocks and part 2 keeps whole
+    // files together, so each part lays out the disk itself
+    type Input = String;
+
+    fn parse(input: &str) -> Self::Input {
+        input.trim().to_string()
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        compact_blocks(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        compact_files(input).into()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_core::{read, Part};
 
     #[test]
     fn part1_sample() {
-        assert_eq!(1928, part1("sample.txt"));
+        assert_eq!(1928, Day::solve(Part::One, &read("sample.txt")));
     }
 
     #[test]
     fn part1_input() {
-        assert_eq!(6353658451014, part1("input.txt"));
+        assert_eq!(6353658451014, Day::solve(Part::One, &read("input.txt")));
     }
 
     #[test]
     fn part2_sample() {
-        assert_eq!(2858, part2("sample.txt"));
+        assert_eq!(2858, Day::solve(Part::Two, &read("sample.txt")));
     }
 
     #[test]
     fn part2_input() {
-        assert_eq!(6382582136592, part2("input.txt"));
+        assert_eq!(6382582136592, Day::solve(Part::Two, &read("input.txt")));
     }
 }
@@ -0,0 +1,110 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// A repeating stretch of steps: whatever happened at `start` happens again
+/// every `length` steps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    /// The step before the first repetition that ends up like `step`.
+    pub fn equivalent(&self, step: usize) -> usize {
+        if step < self.start {
+            step
+        } else {
+            self.start + (step - self.start) % self.length
+        }
+    }
+}
+
+/// Remembers at which step each key was seen, to spot when one comes around
+/// again. Keys can be whole states or just enough of one to tell it apart.
+#[derive(Debug)]
+pub struct CycleDetector<K> {
+    seen: HashMap<K, usize>,
+}
+
+impl<K: Hash + Eq> Default for CycleDetector<K> {
+    fn default() -> Self {
+        CycleDetector {
+            seen: HashMap::new(),
+        }
+    }
+}
+
+impl<K: Hash + Eq> CycleDetector<K> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records `key` at `step`, and returns the cycle if it was seen before.
+    pub fn check(&mut self, key: K, step: usize) -> Option<Cycle> {
+        self.seen.insert(key, step).map(|start| Cycle {
+            start,
+            length: step - start,
+        })
+    }
+}
+
+/// The state after applying `step` `steps` times, skipping ahead as soon as a
+/// state repeats.
+pub fn state_after<S: Hash + Eq + Clone>(
+    initial: S,
+    steps: usize,
+    mut step: impl FnMut(&S) -> S,
+) -> S {
+    let mut detector = CycleDetector::new();
+    let mut history: Vec<S> = Vec::new();
+    let mut state = initial;
+    for i in 0..steps {
+        if let Some(cycle) = detector.check(state.clone(), i) {
+            return history[cycle.equivalent(steps)].clone();
+        }
+        history.push(state.clone());
+        state = step(&state);
+    }
+    state
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detecting() {
+        let mut detector = CycleDetector::new();
+        assert_eq!(None, detector.check('a', 0));
+        assert_eq!(None, detector.check('b', 1));
+        assert_eq!(None, detector.check('c', 2));
+        assert_eq!(
+            Some(Cycle {
+                start: 1,
+                length: 2
+            }),
+            detector.check('b', 3)
+        );
+    }
+
+    #[test]
+    fn equivalent_steps() {
+        let cycle = Cycle {
+            start: 2,
+            length: 3,
+        };
+        assert_eq!(1, cycle.equivalent(1));
+        assert_eq!(2, cycle.equivalent(5));
+        assert_eq!(4, cycle.equivalent(1_000_000_000));
+    }
+
+    #[test]
+    fn skipping_ahead() {
+        // 3, 10, 5, 16, 8, 4, 2, 1, 4, 2, 1, ...
+        let collatz = |&n: &u64| if n % 2 == 0 { n / 2 } else { 3 * n + 1 };
+        assert_eq!(16, state_after(3, 3, collatz));
+        assert_eq!(4, state_after(3, 8, collatz));
+        assert_eq!(1, state_after(3, 1_000_000_000, collatz));
+    }
+}
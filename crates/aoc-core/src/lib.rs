@@ -0,0 +1,169 @@
+use std::fmt::{self, Display, Formatter};
+use std::fs::read_to_string;
+use std::time::Instant;
+
+mod cycle;
+pub use cycle::{state_after, Cycle, CycleDetector};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+/// What a part evaluates to. `Art` is for answers drawn on a screen that have
+/// to be read by eye, and always spans several lines.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Int(i64),
+    Str(String),
+    Art(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(value) => write!(f, "{value}"),
+            Answer::Str(value) | Answer::Art(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+macro_rules! int_answer {
+    ($($int:ty),*) => {
+        $(
+            impl From<$int> for Answer {
+                fn from(value: $int) -> Self {
+                    Answer::Int(i64::try_from(value).expect("answer fits in an i64"))
+                }
+            }
+        )*
+    };
+}
+
+int_answer!(i32, i64, isize, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Str(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Str(value.to_string())
+    }
+}
+
+impl PartialEq<i64> for Answer {
+    fn eq(&self, other: &i64) -> bool {
+        matches!(self, Answer::Int(value) if value == other)
+    }
+}
+
+impl PartialEq<Answer> for i64 {
+    fn eq(&self, other: &Answer) -> bool {
+        other == self
+    }
+}
+
+impl PartialEq<&str> for Answer {
+    fn eq(&self, other: &&str) -> bool {
+        match self {
+            Answer::Int(_) => false,
+            Answer::Str(value) | Answer::Art(value) => value == other,
+        }
+    }
+}
+
+impl PartialEq<Answer> for &str {
+    fn eq(&self, other: &Answer) -> bool {
+        other == self
+    }
+}
+
+/// A day's puzzle. The input text is parsed once and both parts are solved
+/// from the parsed form.
+pub trait Solution {
+    type Input;
+
+    fn parse(input: &str) -> Self::Input;
+
+    fn part1(input: &Self::Input) -> Answer;
+
+    fn part2(input: &Self::Input) -> Answer;
+
+    fn solve(part: Part, input: &str) -> Answer {
+        let input = Self::parse(input);
+        match part {
+            Part::One => Self::part1(&input),
+            Part::Two => Self::part2(&input),
+        }
+    }
+}
+
+/// Reads a puzzle input, for tests and binaries run from the day's directory.
+pub fn read(path: &str) -> String {
+    read_to_string(path).unwrap_or_else(|e| panic!("{path}: {e}"))
+}
+
+/// The `main` of every day: parses `path` and prints both answers with timings.
+pub fn solve_measure_and_print<S: Solution>(name: &str, path: &str) {
+    println!("{name}:");
+    let text = read(path);
+
+    let start = Instant::now();
+    let input = S::parse(&text);
+    let micros = start.elapsed().as_micros();
+    println!("Parse: ({micros}µs)");
+
+    for part in [Part::One, Part::Two] {
+        let start = Instant::now();
+        let result = match part {
+            Part::One => S::part1(&input),
+            Part::Two => S::part2(&input),
+        };
+        let micros = start.elapsed().as_micros();
+        match result {
+            Answer::Art(art) => println!("Part {part:?}: ({micros}µs)\n{art}"),
+            result => println!("Part {part:?}: {result} ({micros}µs)"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input = Vec<i64>;
+
+        fn parse(input: &str) -> Self::Input {
+            input.lines().map(|line| line.parse().unwrap()).collect()
+        }
+
+        fn part1(input: &Self::Input) -> Answer {
+            input.iter().sum::<i64>().into()
+        }
+
+        fn part2(input: &Self::Input) -> Answer {
+            format!("{} numbers", input.len()).into()
+        }
+    }
+
+    #[test]
+    fn solving_from_text() {
+        assert_eq!(6, Sum::solve(Part::One, "1\n2\n3\n"));
+        assert_eq!("3 numbers", Sum::solve(Part::Two, "1\n2\n3\n"));
+    }
+
+    #[test]
+    fn comparing_answers() {
+        assert_eq!(Answer::Int(42), Answer::from(42usize));
+        assert_ne!(Answer::Str("42".to_string()), 42);
+        assert_eq!(Answer::Art("#.\n.#".to_string()), "#.\n.#");
+        assert_eq!("-1", Answer::from(-1).to_string());
+    }
+}
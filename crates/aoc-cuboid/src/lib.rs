@@ -0,0 +1,252 @@
+/// An axis-aligned box of integer cells in `N` dimensions, spanning
+/// `min[axis]..max[axis]` along each axis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cuboid<const N: usize> {
+    pub min: [i64; N],
+    pub max: [i64; N],
+}
+
+impl<const N: usize> Cuboid<N> {
+    pub fn new(min: [i64; N], max: [i64; N]) -> Self {
+        Cuboid { min, max }
+    }
+
+    /// The cuboid from `min` to `max`, both included, the way puzzles
+    /// write them.
+    pub fn inclusive(min: [i64; N], max: [i64; N]) -> Self {
+        Cuboid {
+            min,
+            max: max.map(|end| end + 1),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        (0..N).any(|axis| self.min[axis] >= self.max[axis])
+    }
+
+    /// The number of cells inside.
+    pub fn volume(&self) -> u64 {
+        (0..N)
+            .map(|axis| self.max[axis].saturating_sub(self.min[axis]).max(0) as u64)
+            .product()
+    }
+
+    pub fn intersection(&self, other: &Cuboid<N>) -> Option<Cuboid<N>> {
+        let overlap = Cuboid {
+            min: std::array::from_fn(|axis| self.min[axis].max(other.min[axis])),
+            max: std::array::from_fn(|axis| self.max[axis].min(other.max[axis])),
+        };
+        (!overlap.is_empty()).then_some(overlap)
+    }
+
+    /// The cells outside `other`, as at most `2 * N` disjoint cuboids: one
+    /// slab is cut off each side of the overlap in turn.
+    pub fn subtract(&self, other: &Cuboid<N>) -> Vec<Cuboid<N>> {
+        let Some(overlap) = self.intersection(other) else {
+            return vec![*self];
+        };
+        let mut pieces = Vec::new();
+        let mut rest = *self;
+        for axis in 0..N {
+            if rest.min[axis] < overlap.min[axis] {
+                let mut below = rest;
+                below.max[axis] = overlap.min[axis];
+                pieces.push(below);
+                rest.min[axis] = overlap.min[axis];
+            }
+            if overlap.max[axis] < rest.max[axis] {
+                let mut above = rest;
+                above.min[axis] = overlap.max[axis];
+                pieces.push(above);
+                rest.max[axis] = overlap.max[axis];
+            }
+        }
+        pieces
+    }
+}
+
+/// A value for every cell in a region of space, kept as disjoint cuboids
+/// that each hold one value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CuboidMap<const N: usize, V> {
+    pieces: Vec<(Cuboid<N>, V)>,
+}
+
+impl<const N: usize, V> Default for CuboidMap<N, V> {
+    fn default() -> Self {
+        CuboidMap { pieces: Vec::new() }
+    }
+}
+
+impl<const N: usize, V: Clone + PartialEq> CuboidMap<N, V> {
+    pub fn new() -> Self {
+        CuboidMap::default()
+    }
+
+    /// Gives every cell inside `cuboid` the value `f` makes of its current
+    /// one, where `None` is a cell outside the map.
+    pub fn update(&mut self, cuboid: Cuboid<N>, f: impl Fn(Option<&V>) -> Option<V>) {
+        let mut overlaps = Vec::new();
+        let mut pieces = Vec::with_capacity(self.pieces.len());
+        for (piece, value) in self.pieces.drain(..) {
+            let Some(overlap) = piece.intersection(&cuboid) else {
+                pieces.push((piece, value));
+                continue;
+            };
+            overlaps.push(overlap);
+            let updated = f(Some(&value));
+            if updated.as_ref() == Some(&value) {
+                // No need to cut up a piece that stays as it is.
+                pieces.push((piece, value));
+                continue;
+            }
+            pieces.extend(
+                piece
+                    .subtract(&overlap)
+                    .into_iter()
+                    .map(|outside| (outside, value.clone())),
+            );
+            if let Some(value) = updated {
+                pieces.push((overlap, value));
+            }
+        }
+        if let Some(value) = f(None) {
+            let uncovered = overlaps.iter().fold(vec![cuboid], |uncovered, overlap| {
+                let mut rest = Vec::with_capacity(uncovered.len());
+                for part in uncovered {
+                    if part.intersection(overlap).is_some() {
+                        rest.extend(part.subtract(overlap));
+                    } else {
+                        rest.push(part);
+                    }
+                }
+                rest
+            });
+            pieces.extend(uncovered.into_iter().map(|rest| (rest, value.clone())));
+        }
+        self.pieces = pieces;
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &(Cuboid<N>, V)> {
+        self.pieces.iter()
+    }
+
+    /// The number of cells in the map.
+    pub fn volume(&self) -> u64 {
+        self.pieces.iter().map(|(piece, _)| piece.volume()).sum()
+    }
+}
+
+/// A set of cells, kept as disjoint cuboids.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CuboidSet<const N: usize> {
+    cells: CuboidMap<N, ()>,
+}
+
+impl<const N: usize> CuboidSet<N> {
+    pub fn new() -> Self {
+        CuboidSet::default()
+    }
+
+    /// Adds every cell of `cuboid`.
+    pub fn union(&mut self, cuboid: Cuboid<N>) {
+        self.cells.update(cuboid, |_| Some(()));
+    }
+
+    /// Removes every cell of `cuboid`.
+    pub fn subtract(&mut self, cuboid: Cuboid<N>) {
+        self.cells.update(cuboid, |_| None);
+    }
+
+    /// Removes the cells of `cuboid` that are in the set and adds the ones
+    /// that are not.
+    pub fn toggle(&mut self, cuboid: Cuboid<N>) {
+        self.cells
+            .update(cuboid, |cell| cell.is_none().then_some(()));
+    }
+
+    pub fn cuboids(&self) -> impl Iterator<Item = &Cuboid<N>> {
+        self.cells.iter().map(|(cuboid, _)| cuboid)
+    }
+
+    /// The number of cells in the set.
+    pub fn volume(&self) -> u64 {
+        self.cells.volume()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cube(min: i64, max: i64) -> Cuboid<3> {
+        Cuboid::inclusive([min; 3], [max; 3])
+    }
+
+    #[test]
+    fn volumes() {
+        assert_eq!(27, cube(10, 12).volume());
+        assert_eq!(1, cube(10, 10).volume());
+        assert_eq!(0, Cuboid::new([1, 5], [3, 5]).volume());
+        assert!(Cuboid::new([1, 5], [3, 5]).is_empty());
+        assert_eq!(6, Cuboid::inclusive([0, 0], [2, 1]).volume());
+    }
+
+    #[test]
+    fn intersections() {
+        assert_eq!(Some(cube(11, 12)), cube(10, 12).intersection(&cube(11, 13)));
+        assert_eq!(None, cube(10, 12).intersection(&cube(13, 14)));
+        assert_eq!(Some(cube(1, 2)), cube(1, 2).intersection(&cube(0, 5)));
+    }
+
+    #[test]
+    fn subtracting_cuboids() {
+        assert_eq!(vec![cube(0, 1)], cube(0, 1).subtract(&cube(5, 6)));
+        assert!(cube(1, 2).subtract(&cube(0, 5)).is_empty());
+
+        let pieces = cube(0, 2).subtract(&cube(1, 1));
+        assert_eq!(6, pieces.len());
+        assert_eq!(26, pieces.iter().map(Cuboid::volume).sum::<u64>());
+        for (i, a) in pieces.iter().enumerate() {
+            assert_eq!(None, a.intersection(&cube(1, 1)));
+            for b in &pieces[i + 1..] {
+                assert_eq!(None, a.intersection(b));
+            }
+        }
+
+        let corner = cube(0, 2).subtract(&cube(2, 5));
+        assert_eq!(3, corner.len());
+        assert_eq!(26, corner.iter().map(Cuboid::volume).sum::<u64>());
+    }
+
+    #[test]
+    fn set_operations() {
+        let mut set = CuboidSet::new();
+        set.union(cube(10, 12));
+        assert_eq!(27, set.volume());
+        set.union(cube(11, 13));
+        assert_eq!(27 + 19, set.volume());
+        set.subtract(cube(9, 11));
+        assert_eq!(27 + 19 - 8, set.volume());
+        set.union(cube(10, 10));
+        assert_eq!(39, set.volume());
+
+        set.toggle(cube(0, 20));
+        assert_eq!(21 * 21 * 21 - 39, set.volume());
+        set.toggle(cube(0, 20));
+        assert_eq!(39, set.volume());
+    }
+
+    #[test]
+    fn values() {
+        let mut brightness: CuboidMap<2, u64> = CuboidMap::new();
+        let square = Cuboid::inclusive([0, 0], [9, 9]);
+        brightness.update(square, |b| Some(b.unwrap_or(&0) + 2));
+        brightness.update(Cuboid::inclusive([5, 5], [14, 14]), |b| {
+            Some(b.unwrap_or(&0) + 1)
+        });
+        assert_eq!(175, brightness.volume());
+        let total: u64 = brightness.iter().map(|(piece, b)| piece.volume() * b).sum();
+        assert_eq!(300, total);
+    }
+}
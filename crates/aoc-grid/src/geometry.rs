@@ -0,0 +1,375 @@
+use std::fmt::{self, Display, Formatter};
+use std::ops::{Add, AddAssign, Mul, Sub};
+use std::str::FromStr;
+
+use crate::Point;
+
+/// One of the four orthogonal directions, clockwise from `Up`. The y axis
+/// points down, as it does when reading a grid from text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub fn turn_right(self) -> Self {
+        Direction::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Direction::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn opposite(self) -> Self {
+        Direction::ALL[(self as usize + 2) % 4]
+    }
+
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Direction::Up | Direction::Down)
+    }
+
+    pub fn offset(self) -> (isize, isize) {
+        match self {
+            Direction::Up => (0, -1),
+            Direction::Right => (1, 0),
+            Direction::Down => (0, 1),
+            Direction::Left => (-1, 0),
+        }
+    }
+
+    /// The neighbouring point, unless that would go below zero.
+    pub fn checked_step(self, point: Point) -> Option<Point> {
+        checked_step(point, self.offset())
+    }
+
+    /// The neighbouring point, if it is still inside `(width, height)`.
+    pub fn bounded_step(self, point: Point, bounds: (usize, usize)) -> Option<Point> {
+        bounded_step(point, self.offset(), bounds)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseDirectionError(String);
+
+impl Display for ParseDirectionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "not a direction: {:?}", self.0)
+    }
+}
+
+impl std::error::Error for ParseDirectionError {}
+
+/// Reads `U`/`R`/`D`/`L`, compass points `N`/`E`/`S`/`W`, or arrows `^`/`>`/`v`/`<`.
+impl FromStr for Direction {
+    type Err = ParseDirectionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "U" | "N" | "^" => Ok(Direction::Up),
+            "R" | "E" | ">" => Ok(Direction::Right),
+            "D" | "S" | "v" => Ok(Direction::Down),
+            "L" | "W" | "<" => Ok(Direction::Left),
+            _ => Err(ParseDirectionError(s.to_string())),
+        }
+    }
+}
+
+impl From<Direction> for (isize, isize) {
+    fn from(direction: Direction) -> Self {
+        direction.offset()
+    }
+}
+
+/// One of the eight compass directions, clockwise from `N`, with `N` pointing
+/// up like [`Direction::Up`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction8 {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl Direction8 {
+    pub const ALL: [Direction8; 8] = [
+        Direction8::N,
+        Direction8::NE,
+        Direction8::E,
+        Direction8::SE,
+        Direction8::S,
+        Direction8::SW,
+        Direction8::W,
+        Direction8::NW,
+    ];
+
+    /// Turns 45 degrees clockwise.
+    pub fn turn_right(self) -> Self {
+        Direction8::ALL[(self as usize + 1) % 8]
+    }
+
+    /// Turns 45 degrees counter-clockwise.
+    pub fn turn_left(self) -> Self {
+        Direction8::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn opposite(self) -> Self {
+        Direction8::ALL[(self as usize + 4) % 8]
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self as usize % 2 == 1
+    }
+
+    pub fn offset(self) -> (isize, isize) {
+        match self {
+            Direction8::N => (0, -1),
+            Direction8::NE => (1, -1),
+            Direction8::E => (1, 0),
+            Direction8::SE => (1, 1),
+            Direction8::S => (0, 1),
+            Direction8::SW => (-1, 1),
+            Direction8::W => (-1, 0),
+            Direction8::NW => (-1, -1),
+        }
+    }
+
+    /// The neighbouring point, unless that would go below zero.
+    pub fn checked_step(self, point: Point) -> Option<Point> {
+        checked_step(point, self.offset())
+    }
+
+    /// The neighbouring point, if it is still inside `(width, height)`.
+    pub fn bounded_step(self, point: Point, bounds: (usize, usize)) -> Option<Point> {
+        bounded_step(point, self.offset(), bounds)
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Self {
+        Direction8::ALL[direction as usize * 2]
+    }
+}
+
+impl From<Direction8> for (isize, isize) {
+    fn from(direction: Direction8) -> Self {
+        direction.offset()
+    }
+}
+
+/// `point` moved by `(dx, dy)`, unless that would go below zero.
+pub fn checked_step((x, y): Point, (dx, dy): (isize, isize)) -> Option<Point> {
+    Some((x.checked_add_signed(dx)?, y.checked_add_signed(dy)?))
+}
+
+/// `point` moved by `(dx, dy)`, if it is still inside `(width, height)`.
+pub fn bounded_step(
+    point: Point,
+    delta: (isize, isize),
+    (width, height): (usize, usize),
+) -> Option<Point> {
+    checked_step(point, delta).filter(|&(x, y)| x < width && y < height)
+}
+
+/// A position on a board without fixed bounds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Pos {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Pos {
+    pub const fn new(x: i64, y: i64) -> Self {
+        Pos { x, y }
+    }
+
+    pub fn manhattan(self, other: Pos) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// The number of king's moves between the two positions.
+    pub fn chebyshev(self, other: Pos) -> u64 {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+
+    pub fn neighbours4(self) -> impl Iterator<Item = Pos> {
+        Direction::ALL.into_iter().map(move |dir| self + dir)
+    }
+
+    pub fn neighbours8(self) -> impl Iterator<Item = Pos> {
+        Direction8::ALL.into_iter().map(move |dir| self + dir)
+    }
+}
+
+impl From<Point> for Pos {
+    fn from((x, y): Point) -> Self {
+        Pos::new(x as i64, y as i64)
+    }
+}
+
+impl From<(isize, isize)> for Pos {
+    fn from((x, y): (isize, isize)) -> Self {
+        Pos::new(x as i64, y as i64)
+    }
+}
+
+impl Display for Pos {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl Add for Pos {
+    type Output = Pos;
+
+    fn add(self, rhs: Pos) -> Pos {
+        Pos::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl Sub for Pos {
+    type Output = Pos;
+
+    fn sub(self, rhs: Pos) -> Pos {
+        Pos::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl Mul<i64> for Pos {
+    type Output = Pos;
+
+    fn mul(self, rhs: i64) -> Pos {
+        Pos::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl Add<Direction> for Pos {
+    type Output = Pos;
+
+    fn add(self, rhs: Direction) -> Pos {
+        self + Pos::from(rhs.offset())
+    }
+}
+
+impl Add<Direction8> for Pos {
+    type Output = Pos;
+
+    fn add(self, rhs: Direction8) -> Pos {
+        self + Pos::from(rhs.offset())
+    }
+}
+
+impl AddAssign<Direction> for Pos {
+    fn add_assign(&mut self, rhs: Direction) {
+        *self = *self + rhs;
+    }
+}
+
+impl AddAssign<Direction8> for Pos {
+    fn add_assign(&mut self, rhs: Direction8) {
+        *self = *self + rhs;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn turning() {
+        for dir in Direction::ALL {
+            assert_eq!(dir, dir.turn_right().turn_left());
+            assert_eq!(dir.opposite(), dir.turn_right().turn_right());
+            assert_eq!(dir.opposite(), dir.turn_left().turn_left());
+            assert_ne!(dir.is_vertical(), dir.turn_right().is_vertical());
+        }
+        assert_eq!(Direction::Right, Direction::Up.turn_right());
+        assert_eq!(Direction::Up, Direction::Right.turn_left());
+        assert_eq!(Direction::Left, Direction::Up.turn_left());
+    }
+
+    #[test]
+    fn turning8() {
+        for dir in Direction8::ALL {
+            assert_eq!(dir, dir.turn_right().turn_left());
+            assert_eq!(
+                dir.opposite(),
+                dir.turn_right().turn_right().turn_right().turn_right()
+            );
+            assert_ne!(dir.is_diagonal(), dir.turn_left().is_diagonal());
+        }
+        assert_eq!(Direction8::NE, Direction8::N.turn_right());
+        assert_eq!(Direction8::NW, Direction8::N.turn_left());
+        assert_eq!(Direction8::SE, Direction8::NW.opposite());
+    }
+
+    #[test]
+    fn offsets_agree() {
+        for dir in Direction::ALL {
+            assert_eq!(dir.offset(), Direction8::from(dir).offset());
+            let (dx, dy) = dir.offset();
+            assert_eq!((-dx, -dy), dir.opposite().offset());
+        }
+        for dir in Direction8::ALL {
+            let (dx, dy) = dir.offset();
+            assert_eq!((-dx, -dy), dir.opposite().offset());
+        }
+    }
+
+    #[test]
+    fn parsing() {
+        assert_eq!(Ok(Direction::Up), "U".parse());
+        assert_eq!(Ok(Direction::Right), "E".parse());
+        assert_eq!(Ok(Direction::Down), "v".parse());
+        assert_eq!(Ok(Direction::Left), "L".parse());
+        assert!("X".parse::<Direction>().is_err());
+    }
+
+    #[test]
+    fn stepping_unsigned() {
+        assert_eq!(Some((1, 0)), Direction::Up.checked_step((1, 1)));
+        assert_eq!(None, Direction::Up.checked_step((1, 0)));
+        assert_eq!(None, Direction8::SW.checked_step((0, 5)));
+        assert_eq!(Some((2, 1)), Direction::Right.bounded_step((1, 1), (3, 3)));
+        assert_eq!(None, Direction::Right.bounded_step((2, 1), (3, 3)));
+        assert_eq!(None, Direction8::SE.bounded_step((1, 2), (3, 3)));
+    }
+
+    #[test]
+    fn moving_and_distances() {
+        let origin = Pos::default();
+        let pos = origin + Direction::Right + Direction::Right + Direction8::NE;
+        assert_eq!(Pos::new(3, -1), pos);
+        assert_eq!(4, origin.manhattan(pos));
+        assert_eq!(3, origin.chebyshev(pos));
+        assert_eq!(Pos::new(6, -2), pos * 2);
+        assert_eq!(Pos::new(-3, 1), origin - pos);
+
+        let mut pos = origin;
+        pos += Direction::Down;
+        pos += Direction8::W;
+        assert_eq!(Pos::new(-1, 1), pos);
+    }
+
+    #[test]
+    fn neighbours() {
+        let pos = Pos::new(5, 5);
+        assert_eq!(4, pos.neighbours4().count());
+        assert!(pos.neighbours4().all(|n| pos.manhattan(n) == 1));
+        assert_eq!(8, pos.neighbours8().count());
+        assert!(pos.neighbours8().all(|n| pos.chebyshev(n) == 1));
+    }
+}
@@ -0,0 +1,455 @@
+mod geometry;
+mod ocr;
+
+pub use geometry::{bounded_step, checked_step, Direction, Direction8, ParseDirectionError, Pos};
+pub use ocr::{read_dots, read_letters};
+
+use std::fmt::{self, Display, Formatter};
+use std::iter::once;
+use std::ops::{Index, IndexMut};
+
+/// A cell's coordinates as `(x, y)`, with `(0, 0)` in the top left corner.
+pub type Point = (usize, usize);
+
+/// A rectangular grid of cells, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Builds a grid from puzzle text, one row per line, converting each
+    /// character with `cell`. Blank lines around the grid are ignored but
+    /// spaces are kept, and all rows must have the same length.
+    pub fn parse_with(input: &str, mut cell: impl FnMut(char) -> T) -> Self {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+        for line in input.trim_matches('\n').lines() {
+            let before = cells.len();
+            cells.extend(line.chars().map(&mut cell));
+            let row = cells.len() - before;
+            assert_eq!(
+                row,
+                *width.get_or_insert(row),
+                "row {height} has a different length"
+            );
+            height += 1;
+        }
+        Grid {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn in_bounds(&self, (x, y): Point) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.in_bounds(point)
+            .then(|| &self.cells[point.1 * self.width + point.0])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.in_bounds(point)
+            .then(|| &mut self.cells[point.1 * self.width + point.0])
+    }
+
+    /// The point `delta` away from `point`, if that is still on the grid.
+    /// `delta` is an `(dx, dy)` offset or a [`Direction`]/[`Direction8`].
+    pub fn step(&self, point: Point, delta: impl Into<(isize, isize)>) -> Option<Point> {
+        bounded_step(point, delta.into(), (self.width, self.height))
+    }
+
+    /// The points above, right of, below and left of `point` that are on the grid.
+    pub fn neighbours4(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |dir| self.step(point, dir))
+    }
+
+    /// The orthogonal and diagonal neighbours of `point` that are on the grid,
+    /// clockwise from the one above.
+    pub fn neighbours8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction8::ALL
+            .into_iter()
+            .filter_map(move |dir| self.step(point, dir))
+    }
+
+    /// The points reached by repeatedly stepping `delta` from `from` (which is
+    /// not included), up to the edge of the grid.
+    pub fn ray(
+        &self,
+        from: Point,
+        delta: impl Into<(isize, isize)>,
+    ) -> impl Iterator<Item = Point> + '_ {
+        let delta = delta.into();
+        let mut point = Some(from);
+        std::iter::from_fn(move || {
+            point = self.step(point?, delta);
+            point
+        })
+    }
+
+    /// All points, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.width * self.height).map(move |i| (i % width, i / width))
+    }
+
+    /// All cells with their points, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    pub fn row(&self, y: usize) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        self.cells[y * self.width..(y + 1) * self.width].iter()
+    }
+
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        assert!(
+            x < self.width,
+            "column {x} is outside a grid {} wide",
+            self.width
+        );
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // a grid without columns has no cells, and so no rows to show
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl DoubleEndedIterator<Item = &T>> {
+        (0..self.width).map(move |x| self.column(x))
+    }
+
+    /// The lines running down and to the right, starting from the bottom left
+    /// corner and ending at the top right one.
+    pub fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let (width, height) = self.nonempty_size();
+        let starts = (0..height)
+            .rev()
+            .map(|y| (0, y))
+            .chain((1..width).map(|x| (x, 0)));
+        starts.map(move |start| self.line(start, (1, 1)))
+    }
+
+    /// The lines running down and to the left, starting from the top left
+    /// corner and ending at the bottom right one.
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let (width, height) = self.nonempty_size();
+        let starts = (0..width)
+            .map(|x| (x, 0))
+            .chain((1..height).map(move |y| (width - 1, y)));
+        starts.map(move |start| self.line(start, (-1, 1)))
+    }
+
+    /// The size, or nothing at all when there are no cells, as a grid can
+    /// be zero wide yet have rows.
+    fn nonempty_size(&self) -> (usize, usize) {
+        if self.cells.is_empty() {
+            (0, 0)
+        } else {
+            (self.width, self.height)
+        }
+    }
+
+    fn line(&self, start: Point, delta: (isize, isize)) -> impl Iterator<Item = &T> {
+        once(start)
+            .chain(self.ray(start, delta))
+            .map(move |point| &self[point])
+    }
+
+    /// The points of all cells matching `predicate`, row by row.
+    pub fn find_all<'a>(
+        &'a self,
+        mut predicate: impl FnMut(&T) -> bool + 'a,
+    ) -> impl Iterator<Item = Point> + 'a {
+        self.iter()
+            .filter_map(move |(point, cell)| predicate(cell).then_some(point))
+    }
+
+    /// The point of the first cell matching `predicate`.
+    pub fn find(&self, predicate: impl FnMut(&T) -> bool) -> Option<Point> {
+        self.find_all(predicate).next()
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Mirrors the grid along the diagonal from the top left corner, so rows
+    /// become columns.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rebuild(self.height, self.width, |(x, y)| (y, x))
+    }
+
+    pub fn rotate_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        let height = self.height;
+        self.rebuild(self.height, self.width, |(x, y)| (y, height - 1 - x))
+    }
+
+    pub fn rotate_counter_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        let width = self.width;
+        self.rebuild(self.height, self.width, |(x, y)| (width - 1 - y, x))
+    }
+
+    /// A `width` by `height` grid where each cell is copied from the point
+    /// `source` gives for it.
+    fn rebuild(&self, width: usize, height: usize, source: impl Fn(Point) -> Point) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..width * height)
+            .map(|i| self[source((i % width, i / width))].clone())
+            .collect();
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+}
+
+impl Grid<char> {
+    pub fn parse(input: &str) -> Self {
+        Grid::parse_with(input, |c| c)
+    }
+}
+
+impl Grid<u8> {
+    /// Parses a grid of single digits, as used for heights and risk levels.
+    pub fn parse_digits(input: &str) -> Self {
+        Grid::parse_with(input, |c| {
+            c.to_digit(10)
+                .unwrap_or_else(|| panic!("{c:?} is not a digit")) as u8
+        })
+    }
+}
+
+impl<T> From<Vec<Vec<T>>> for Grid<T> {
+    fn from(rows: Vec<Vec<T>>) -> Self {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+        assert!(
+            rows.iter().all(|row| row.len() == width),
+            "rows have different lengths"
+        );
+        Grid {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        }
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get(point)
+            .unwrap_or_else(|| panic!("{point:?} is outside the grid"))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{point:?} is outside the grid"))
+    }
+}
+
+/// Renders the grid as text again, one line per row.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Grid<char> {
+        Grid::parse("abc\ndef\n")
+    }
+
+    fn collect<'a>(cells: impl Iterator<Item = &'a char>) -> String {
+        cells.collect()
+    }
+
+    #[test]
+    fn parsing_and_rendering() {
+        let grid = sample();
+        assert_eq!(3, grid.width());
+        assert_eq!(2, grid.height());
+        assert_eq!('f', grid[(2, 1)]);
+        assert_eq!(None, grid.get((3, 0)));
+        assert_eq!("abc\ndef\n", grid.to_string());
+    }
+
+    #[test]
+    fn parsing_digits() {
+        let grid = Grid::parse_digits("\n12\n34\n");
+        assert_eq!(Grid::from(vec![vec![1, 2], vec![3, 4]]), grid);
+        assert_eq!("12\n34\n", grid.to_string());
+    }
+
+    #[test]
+    fn empty_grids() {
+        let grid: Grid<char> = Grid::parse("");
+        assert_eq!(0, grid.rows().count());
+        assert_eq!("", grid.to_string());
+        assert_eq!("", Grid::new(0, 3, '.').to_string());
+
+        for grid in [Grid::new(0, 3, '.'), Grid::new(3, 0, '.')] {
+            assert_eq!(0, grid.diagonals().count());
+            assert_eq!(0, grid.anti_diagonals().count());
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "column 3 is outside a grid 3 wide")]
+    fn column_out_of_range() {
+        sample().column(3).count();
+    }
+
+    #[test]
+    #[should_panic(expected = "row 1 has a different length")]
+    fn parsing_ragged_rows() {
+        Grid::parse("ab\nc\n");
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid = sample();
+        assert_eq!(
+            vec![(1, 0), (0, 1)],
+            grid.neighbours4((0, 0)).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![(1, 0), (2, 1), (0, 1)],
+            grid.neighbours4((1, 1)).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![(1, 0), (2, 0), (2, 1), (0, 1), (0, 0)],
+            grid.neighbours8((1, 1)).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn stepping() {
+        let grid = sample();
+        assert_eq!(Some((2, 1)), grid.step((1, 0), (1, 1)));
+        assert_eq!(None, grid.step((0, 0), (-1, 0)));
+        assert_eq!(None, grid.step((2, 1), (1, 0)));
+        assert_eq!(
+            vec![(1, 0), (2, 0)],
+            grid.ray((0, 0), (1, 0)).collect::<Vec<_>>()
+        );
+        assert_eq!(0, grid.ray((0, 0), (0, -1)).count());
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid = sample();
+        assert_eq!("def", collect(grid.row(1)));
+        assert_eq!("fed", collect(grid.row(1).rev()));
+        assert_eq!("be", collect(grid.column(1)));
+        assert_eq!(
+            vec!["ad", "be", "cf"],
+            grid.columns().map(collect).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec!["abc", "def"],
+            grid.rows()
+                .map(|row| row.iter().collect::<String>())
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn diagonals() {
+        let grid = sample();
+        assert_eq!(
+            vec!["d", "ae", "bf", "c"],
+            grid.diagonals().map(collect).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec!["a", "bd", "ce", "f"],
+            grid.anti_diagonals().map(collect).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn transposing_and_rotating() {
+        let grid = sample();
+        assert_eq!("ad\nbe\ncf\n", grid.transpose().to_string());
+        assert_eq!("da\neb\nfc\n", grid.rotate_clockwise().to_string());
+        assert_eq!("cf\nbe\nad\n", grid.rotate_counter_clockwise().to_string());
+        assert_eq!(grid, grid.rotate_clockwise().rotate_counter_clockwise());
+    }
+
+    #[test]
+    fn finding() {
+        let grid = Grid::parse("#.#\n.#.\n");
+        assert_eq!(
+            vec![(0, 0), (2, 0), (1, 1)],
+            grid.find_all(|&c| c == '#').collect::<Vec<_>>()
+        );
+        assert_eq!(Some((1, 0)), grid.find(|&c| c == '.'));
+        assert_eq!(None, grid.find(|&c| c == 'x'));
+    }
+
+    #[test]
+    fn mapping_and_mutating() {
+        let mut grid = sample().map(|&c| c == 'e');
+        assert_eq!(Some((1, 1)), grid.find(|&lit| lit));
+        grid[(0, 0)] = true;
+        *grid.get_mut((2, 1)).unwrap() = true;
+        assert_eq!(3, grid.find_all(|&lit| lit).count());
+    }
+}
@@ -0,0 +1,268 @@
+use std::collections::HashSet;
+
+use crate::Grid;
+
+/// The 4×6 letters most screens in the puzzles are drawn with. `Y` is the
+/// only one that needs five columns.
+const SMALL: &[(char, &[&str])] = &[
+    ('A', &[".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', &["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', &[".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', &["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', &["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', &[".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', &["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', &["###", ".#.", ".#.", ".#.", ".#.", "###"]),
+    ('J', &["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', &["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', &["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', &[".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', &["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', &["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', &[".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', &["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Y', &["#...#", "#...#", ".#.#.", "..#..", "..#..", "..#.."]),
+    ('Z', &["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+/// The 6×10 letters of the larger screens.
+const LARGE: &[(char, &[&str])] = &[
+    (
+        'A',
+        &[
+            "..##..", ".#..#.", "#....#", "#....#", "#....#", "######", "#....#", "#....#",
+            "#....#", "#....#",
+        ],
+    ),
+    (
+        'B',
+        &[
+            "#####.", "#....#", "#....#", "#....#", "#####.", "#....#", "#....#", "#....#",
+            "#....#", "#####.",
+        ],
+    ),
+    (
+        'C',
+        &[
+            ".####.", "#....#", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....",
+            "#....#", ".####.",
+        ],
+    ),
+    (
+        'E',
+        &[
+            "######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....",
+            "#.....", "######",
+        ],
+    ),
+    (
+        'F',
+        &[
+            "######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....",
+            "#.....", "#.....",
+        ],
+    ),
+    (
+        'G',
+        &[
+            ".####.", "#....#", "#.....", "#.....", "#.....", "#..###", "#....#", "#....#",
+            "#...##", ".###.#",
+        ],
+    ),
+    (
+        'H',
+        &[
+            "#....#", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#",
+            "#....#", "#....#",
+        ],
+    ),
+    (
+        'J',
+        &[
+            "...###", "....#.", "....#.", "....#.", "....#.", "....#.", "....#.", "#...#.",
+            "#...#.", ".###..",
+        ],
+    ),
+    (
+        'K',
+        &[
+            "#....#", "#...#.", "#..#..", "#.#...", "##....", "##....", "#.#...", "#..#..",
+            "#...#.", "#....#",
+        ],
+    ),
+    (
+        'L',
+        &[
+            "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....",
+            "#.....", "######",
+        ],
+    ),
+    (
+        'N',
+        &[
+            "#....#", "##...#", "##...#", "#.#..#", "#.#..#", "#..#.#", "#..#.#", "#...##",
+            "#...##", "#....#",
+        ],
+    ),
+    (
+        'P',
+        &[
+            "#####.", "#....#", "#....#", "#....#", "#####.", "#.....", "#.....", "#.....",
+            "#.....", "#.....",
+        ],
+    ),
+    (
+        'R',
+        &[
+            "#####.", "#....#", "#....#", "#....#", "#####.", "#..#..", "#...#.", "#...#.",
+            "#....#", "#....#",
+        ],
+    ),
+    (
+        'X',
+        &[
+            "#....#", "#....#", ".#..#.", ".#..#.", "..##..", "..##..", ".#..#.", ".#..#.",
+            "#....#", "#....#",
+        ],
+    ),
+    (
+        'Z',
+        &[
+            "######", ".....#", ".....#", "....#.", "...#..", "..#...", ".#....", "#.....",
+            "#.....", "######",
+        ],
+    ),
+];
+
+/// Reads the letters lit up on `screen`, in either font. Letters are told
+/// apart by the blank columns between them. Returns `None` if the screen is
+/// blank, has an unexpected height, or shows a glyph outside the font.
+pub fn read_letters(screen: &Grid<bool>) -> Option<String> {
+    let lit_rows: Vec<usize> = (0..screen.height())
+        .filter(|&y| screen.row(y).any(|&lit| lit))
+        .collect();
+    let (&top, &bottom) = (lit_rows.first()?, lit_rows.last()?);
+    let font = match bottom - top + 1 {
+        6 => SMALL,
+        10 => LARGE,
+        _ => return None,
+    };
+
+    let lit_column = |x: usize| (top..=bottom).any(|y| screen[(x, y)]);
+    let mut letters = String::new();
+    let mut x = 0;
+    while x < screen.width() {
+        if !lit_column(x) {
+            x += 1;
+            continue;
+        }
+        let start = x;
+        while x < screen.width() && lit_column(x) {
+            x += 1;
+        }
+        let glyph: Vec<String> = (top..=bottom)
+            .map(|y| {
+                (start..x)
+                    .map(|x| if screen[(x, y)] { '#' } else { '.' })
+                    .collect()
+            })
+            .collect();
+        let (letter, _) = font.iter().find(|(_, rows)| trim(rows) == glyph)?;
+        letters.push(*letter);
+    }
+    Some(letters)
+}
+
+/// Like [`read_letters`], for a screen given as the positions of its lit
+/// pixels.
+pub fn read_dots(dots: &HashSet<(i32, i32)>) -> Option<String> {
+    let min_x = dots.iter().map(|&(x, _)| x).min()?;
+    let max_x = dots.iter().map(|&(x, _)| x).max()?;
+    let min_y = dots.iter().map(|&(_, y)| y).min()?;
+    let max_y = dots.iter().map(|&(_, y)| y).max()?;
+    let width = (max_x - min_x + 1) as usize;
+    let height = (max_y - min_y + 1) as usize;
+    let mut screen = Grid::new(width, height, false);
+    for &(x, y) in dots {
+        screen[((x - min_x) as usize, (y - min_y) as usize)] = true;
+    }
+    read_letters(&screen)
+}
+
+/// A glyph without the blank columns on either side, the way it is cut out
+/// of a screen.
+fn trim(rows: &[&str]) -> Vec<String> {
+    let lit = |x: usize| rows.iter().any(|row| row.as_bytes()[x] == b'#');
+    let width = rows[0].len();
+    let start = (0..width).find(|&x| lit(x)).unwrap_or(width);
+    let end = (0..width).rfind(|&x| lit(x)).map_or(start, |x| x + 1);
+    rows.iter().map(|row| row[start..end].to_string()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Draws `text` the way a puzzle screen would: one blank column after
+    /// each letter and some margin around it.
+    fn draw(text: &str, font: &[(char, &[&str])]) -> Grid<bool> {
+        let height = font[0].1.len();
+        let mut rows = vec![vec![false; 2]; height + 2];
+        for c in text.chars() {
+            let (_, glyph) = font.iter().find(|(letter, _)| *letter == c).unwrap();
+            for (y, line) in glyph.iter().enumerate() {
+                rows[y + 1].extend(line.chars().map(|pixel| pixel == '#'));
+                rows[y + 1].push(false);
+            }
+            let width = rows[1].len();
+            for y in [0, height + 1] {
+                rows[y].resize(width, false);
+            }
+        }
+        Grid::from(rows)
+    }
+
+    #[test]
+    fn every_small_letter() {
+        let alphabet: String = SMALL.iter().map(|(letter, _)| letter).collect();
+        assert_eq!(
+            Some(alphabet.clone()),
+            read_letters(&draw(&alphabet, SMALL))
+        );
+    }
+
+    #[test]
+    fn every_large_letter() {
+        let alphabet: String = LARGE.iter().map(|(letter, _)| letter).collect();
+        assert_eq!(
+            Some(alphabet.clone()),
+            read_letters(&draw(&alphabet, LARGE))
+        );
+    }
+
+    #[test]
+    fn reading_dots() {
+        let dots = HashSet::from([
+            (10, 5),
+            (11, 5),
+            (12, 5),
+            (11, 6),
+            (11, 7),
+            (11, 8),
+            (11, 9),
+            (10, 10),
+            (11, 10),
+            (12, 10),
+        ]);
+        assert_eq!(Some("I".to_string()), read_dots(&dots));
+    }
+
+    #[test]
+    fn unknown_glyphs() {
+        let screen = Grid::parse_with("#.#.\n.#.#\n#.#.\n.#.#\n#.#.\n.#.#", |c| c == '#');
+        assert_eq!(None, read_letters(&screen));
+        assert_eq!(None, read_letters(&Grid::new(5, 6, false)));
+        assert_eq!(None, read_letters(&Grid::new(5, 7, true)));
+        assert_eq!(None, read_dots(&HashSet::new()));
+    }
+}
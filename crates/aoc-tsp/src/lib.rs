@@ -0,0 +1,237 @@
+/// The weight of going from one place straight to another, for every pair
+/// of `len` places. Pairs without a weight can't follow each other on a
+/// route.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Distances {
+    len: usize,
+    weights: Vec<Option<i64>>,
+}
+
+/// The most places a route can be found through.
+pub const MAX_PLACES: usize = 20;
+
+/// An order to visit every place in, and its total weight.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Route {
+    pub length: i64,
+    pub stops: Vec<usize>,
+}
+
+impl Distances {
+    pub fn new(len: usize) -> Self {
+        Distances {
+            len,
+            weights: vec![None; len * len],
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn get(&self, from: usize, to: usize) -> Option<i64> {
+        self.weights[from * self.len + to]
+    }
+
+    pub fn set(&mut self, from: usize, to: usize, weight: i64) {
+        self.weights[from * self.len + to] = Some(weight);
+    }
+
+    /// Sets the weight both ways between `a` and `b`.
+    pub fn set_both(&mut self, a: usize, b: usize, weight: i64) {
+        self.set(a, b, weight);
+        self.set(b, a, weight);
+    }
+
+    /// The lightest route through every place, starting and ending
+    /// anywhere.
+    pub fn shortest_path(&self) -> Option<Route> {
+        self.held_karp(false, |a, b| a < b)
+    }
+
+    /// The heaviest route through every place, starting and ending
+    /// anywhere.
+    pub fn longest_path(&self) -> Option<Route> {
+        self.held_karp(false, |a, b| a > b)
+    }
+
+    /// The lightest round trip through every place. Its stops start at
+    /// place 0, and the way back there from the last one is included in
+    /// its length.
+    pub fn shortest_tour(&self) -> Option<Route> {
+        self.held_karp(true, |a, b| a < b)
+    }
+
+    /// The heaviest round trip through every place, like `shortest_tour`.
+    pub fn longest_tour(&self) -> Option<Route> {
+        self.held_karp(true, |a, b| a > b)
+    }
+
+    /// Finds the best route by the best path to each place through each
+    /// set of places, in `O(2^n * n^2)` time and `O(2^n * n)` memory: 17
+    /// bytes a table entry, so about 360 MB for the most places allowed.
+    fn held_karp(&self, closed: bool, better: impl Fn(i64, i64) -> bool) -> Option<Route> {
+        let n = self.len;
+        if n == 0 {
+            return None;
+        }
+        assert!(n <= MAX_PLACES, "too many places for Held-Karp: {n}");
+
+        let full = (1 << n) - 1;
+        // Indexed by the set of places visited and the last of them.
+        let mut best: Vec<Option<i64>> = vec![None; (full + 1) * n];
+        let mut previous = vec![0u8; (full + 1) * n];
+        let starts = if closed { 1 } else { n };
+        for start in 0..starts {
+            best[(1 << start) * n + start] = Some(0);
+        }
+
+        for visited in 1..=full {
+            for last in 0..n {
+                let Some(length) = best[visited * n + last] else {
+                    continue;
+                };
+                for next in (0..n).filter(|next| visited & 1 << next == 0) {
+                    let Some(weight) = self.get(last, next) else {
+                        continue;
+                    };
+                    let slot = (visited | 1 << next) * n + next;
+                    if best[slot].is_none_or(|other| better(length + weight, other)) {
+                        best[slot] = Some(length + weight);
+                        previous[slot] = last as u8;
+                    }
+                }
+            }
+        }
+
+        let (length, mut last) = (0..n)
+            .filter_map(|last| {
+                let length = best[full * n + last]?;
+                if closed && n > 1 {
+                    Some((length + self.get(last, 0)?, last))
+                } else {
+                    Some((length, last))
+                }
+            })
+            .reduce(|a, b| if better(b.0, a.0) { b } else { a })?;
+
+        let mut stops = Vec::with_capacity(n);
+        let mut visited = full;
+        loop {
+            stops.push(last);
+            let before = previous[visited * n + last];
+            visited &= !(1 << last);
+            if visited == 0 {
+                break;
+            }
+            last = before as usize;
+        }
+        stops.reverse();
+
+        Some(Route { length, stops })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The sum of the weights along `stops`, back to the start if `closed`.
+    fn length(distances: &Distances, stops: &[usize], closed: bool) -> i64 {
+        let mut legs: Vec<(usize, usize)> = stops.windows(2).map(|w| (w[0], w[1])).collect();
+        if closed && stops.len() > 1 {
+            legs.push((stops[stops.len() - 1], stops[0]));
+        }
+        legs.iter()
+            .map(|&(from, to)| distances.get(from, to).unwrap())
+            .sum()
+    }
+
+    fn assert_visits_all(distances: &Distances, route: &Route, closed: bool) {
+        let mut stops = route.stops.clone();
+        stops.sort();
+        assert_eq!((0..distances.len()).collect::<Vec<_>>(), stops);
+        assert_eq!(route.length, length(distances, &route.stops, closed));
+    }
+
+    /// London, Dublin and Belfast from 2015 day 9.
+    fn cities() -> Distances {
+        let mut distances = Distances::new(3);
+        distances.set_both(0, 1, 464);
+        distances.set_both(0, 2, 518);
+        distances.set_both(1, 2, 141);
+        distances
+    }
+
+    /// Alice, Bob, Carol and David from 2015 day 13, with what both
+    /// neighbours gain from sitting next to each other.
+    fn table() -> Distances {
+        let happiness = [
+            [0, 54, -79, -2],
+            [83, 0, -7, -63],
+            [-62, 60, 0, 55],
+            [46, -7, 41, 0],
+        ];
+        let mut distances = Distances::new(4);
+        for (a, row) in happiness.iter().enumerate() {
+            for (b, gain) in row.iter().enumerate() {
+                if a != b {
+                    distances.set(a, b, gain + happiness[b][a]);
+                }
+            }
+        }
+        distances
+    }
+
+    #[test]
+    fn open_paths() {
+        let distances = cities();
+
+        let shortest = distances.shortest_path().unwrap();
+        assert_eq!(605, shortest.length);
+        assert_visits_all(&distances, &shortest, false);
+
+        let longest = distances.longest_path().unwrap();
+        assert_eq!(982, longest.length);
+        assert_visits_all(&distances, &longest, false);
+    }
+
+    #[test]
+    fn closed_tours() {
+        let distances = table();
+
+        let longest = distances.longest_tour().unwrap();
+        assert_eq!(330, longest.length);
+        assert_eq!(0, longest.stops[0]);
+        assert_visits_all(&distances, &longest, true);
+
+        let shortest = distances.shortest_tour().unwrap();
+        assert_visits_all(&distances, &shortest, true);
+        assert!(shortest.length <= longest.length);
+        assert_eq!(1123, cities().shortest_tour().unwrap().length);
+    }
+
+    #[test]
+    fn one_way_weights() {
+        let mut distances = Distances::new(3);
+        distances.set(0, 1, 1);
+        distances.set(1, 2, 1);
+        distances.set(2, 0, 10);
+
+        assert_eq!(vec![0, 1, 2], distances.shortest_path().unwrap().stops);
+        assert_eq!(12, distances.shortest_tour().unwrap().length);
+        assert_eq!(None, Distances::new(3).shortest_path());
+        assert_eq!(None, Distances::new(0).longest_tour());
+        assert_eq!(0, Distances::new(1).shortest_tour().unwrap().length);
+    }
+
+    #[test]
+    #[should_panic(expected = "too many places")]
+    fn too_many_places() {
+        Distances::new(MAX_PLACES + 1).shortest_path();
+    }
+}
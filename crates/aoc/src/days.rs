@@ -0,0 +1,138 @@
+use aoc_core::{Answer, Part, Solution};
+
+/// How a day is run: in-process through its `Solution` impl, or by executing
+/// the day's own binary for the older `main.rs` layouts.
+pub enum Kind {
+    Solution {
+        solve: fn(Part, &str) -> Answer,
+        input: &'static str,
+    },
+    Binary {
+        package: &'static str,
+    },
+}
+
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    pub dir: &'static str,
+    pub kind: Kind,
+}
+
+macro_rules! solution {
+    ($year:literal, $day:literal, $dir:literal, $solution:ty) => {
+        solution!($year, $day, $dir, $solution, "input.txt")
+    };
+    ($year:literal, $day:literal, $dir:literal, $solution:ty, $input:literal) => {
+        Day {
+            year: $year,
+            day: $day,
+            dir: $dir,
+            kind: Kind::Solution {
+                solve: <$solution as Solution>::solve,
+                input: $input,
+            },
+        }
+    };
+}
+
+macro_rules! binary {
+    ($year:literal, $day:literal, $dir:literal, $package:literal) => {
+        Day {
+            year: $year,
+            day: $day,
+            dir: $dir,
+            kind: Kind::Binary { package: $package },
+        }
+    };
+}
+
+pub fn all() -> Vec<Day> {
+    vec![
+        binary!(2015, 4, "2015/04/part2", "y2015-d04"),
+        binary!(2015, 6, "2015/06", "y2015-d06"),
+        binary!(2015, 9, "2015/09", "y2015-d09"),
+        binary!(2015, 16, "2015/16", "y2015-d16"),
+        binary!(2015, 17, "2015/17", "y2015-d17"),
+        binary!(2020, 25, "2020/25/p1", "y2020-d25"),
+        solution!(2021, 1, "2021/day1", y2021_d01::solution::Day, "input1.txt"),
+        solution!(2021, 2, "2021/day2", y2021_d02::solution::Day, "input1.txt"),
+        solution!(2021, 3, "2021/day3", y2021_d03::solution::Day),
+        solution!(2021, 4, "2021/day4", y2021_d04::solution::Day),
+        solution!(2021, 5, "2021/day5", y2021_d05::solution::Day),
+        solution!(2021, 6, "2021/day6", y2021_d06::solution::Day),
+        solution!(2021, 7, "2021/day7", y2021_d07::solution::Day),
+        solution!(2021, 8, "2021/day8", y2021_d08::solution::Day),
+        solution!(2021, 9, "2021/day9", y2021_d09::solution::Day),
+        solution!(2021, 10, "2021/day10", y2021_d10::solution::Day),
+        solution!(2021, 11, "2021/day11", y2021_d11::solution::Day),
+        solution!(2021, 12, "2021/day12", y2021_d12::solution::Day),
+        solution!(2021, 13, "2021/day13", y2021_d13::solution::Day),
+        solution!(2021, 14, "2021/day14", y2021_d14::solution::Day),
+        solution!(2021, 15, "2021/day15", y2021_d15::solution::Day),
+        solution!(2021, 16, "2021/day16", y2021_d16::solution::Day),
+        solution!(2021, 17, "2021/day17", y2021_d17::solution::Day),
+        solution!(2021, 18, "2021/day18", y2021_d18::solution::Day),
+        solution!(2021, 19, "2021/day19", y2021_d19::solution::Day),
+        solution!(2021, 20, "2021/day20", y2021_d20::solution::Day),
+        solution!(2021, 21, "2021/day21", y2021_d21::solution::Day),
+        solution!(2021, 22, "2021/day22", y2021_d22::solution::Day),
+        solution!(2021, 23, "2021/day23", y2021_d23::solution::Day),
+        solution!(2021, 24, "2021/day24", y2021_d24::solution::Day),
+        solution!(2021, 25, "2021/day25", y2021_d25::solution::Day),
+        solution!(2022, 2, "2022/02", y2022_d02::Day),
+        solution!(2022, 3, "2022/03", y2022_d03::solution::Day),
+        solution!(2022, 4, "2022/04", y2022_d04::solution::Day),
+        solution!(2022, 5, "2022/05", y2022_d05::solution::Day),
+        solution!(2022, 6, "2022/06", y2022_d06::solution::Day),
+        solution!(2022, 7, "2022/07", y2022_d07::solution::Day),
+        solution!(2022, 8, "2022/08", y2022_d08::solution::Day),
+        solution!(2022, 9, "2022/09", y2022_d09::solution::Day),
+        solution!(2022, 10, "2022/10", y2022_d10::solution::Day),
+        solution!(2022, 11, "2022/11", y2022_d11::solution::Day),
+        solution!(2022, 12, "2022/12", y2022_d12::solution::Day),
+        solution!(2022, 13, "2022/13", y2022_d13::solution::Day),
+        solution!(2022, 14, "2022/14", y2022_d14::solution::Day),
+        solution!(2022, 15, "2022/15", y2022_d15::solution::Day),
+        solution!(2022, 16, "2022/16", y2022_d16::solution::Day),
+        solution!(2022, 17, "2022/17", y2022_d17::solution::Day),
+        solution!(2022, 18, "2022/18", y2022_d18::solution::Day),
+        solution!(2022, 19, "2022/19", y2022_d19::solution::Day),
+        solution!(2022, 20, "2022/20", y2022_d20::solution::Day),
+        solution!(2022, 21, "2022/21", y2022_d21::solution::Day),
+        solution!(2022, 22, "2022/22", y2022_d22::solution::Day),
+        solution!(2022, 23, "2022/23", y2022_d23::solution::Day),
+        solution!(2022, 24, "2022/24", y2022_d24::solution::Day),
+        solution!(2022, 25, "2022/25", y2022_d25::solution::Day),
+        solution!(2023, 1, "2023/01", y2023_d01::solution::Day),
+        solution!(2023, 2, "2023/02", y2023_d02::solution::Day),
+        solution!(2023, 3, "2023/03", y2023_d03::solution::Day),
+        solution!(2023, 4, "2023/04", y2023_d04::solution::Day),
+        solution!(2023, 5, "2023/05", y2023_d05::solution::Day),
+        solution!(2023, 6, "2023/06", y2023_d06::solution::Day),
+        solution!(2023, 7, "2023/07", y2023_d07::solution::Day),
+        solution!(2023, 8, "2023/08", y2023_d08::solution::Day),
+        solution!(2023, 9, "2023/09", y2023_d09::solution::Day),
+        solution!(2023, 10, "2023/10", y2023_d10::solution::Day),
+        solution!(2023, 11, "2023/11", y2023_d11::solution::Day),
+        solution!(2023, 12, "2023/12", y2023_d12::solution::Day),
+        solution!(2023, 13, "2023/13", y2023_d13::solution::Day),
+        solution!(2023, 14, "2023/14", y2023_d14::solution::Day),
+        solution!(2023, 15, "2023/15", y2023_d15::solution::Day),
+        solution!(2023, 16, "2023/16", y2023_d16::solution::Day),
+        solution!(2023, 17, "2023/17", y2023_d17::solution::Day),
+        solution!(2023, 18, "2023/18", y2023_d18::solution::Day),
+        solution!(2023, 19, "2023/19", y2023_d19::solution::Day),
+        solution!(2023, 20, "2023/20", y2023_d20::solution::Day),
+        solution!(2023, 21, "2023/21", y2023_d21::solution::Day),
+        solution!(2023, 23, "2023/23", y2023_d23::solution::Day),
+        solution!(2023, 25, "2023/25", y2023_d25::solution::Day),
+        solution!(2024, 4, "2024/04", y2024_d04::solution::Day),
+        solution!(2024, 5, "2024/05", y2024_d05::solution::Day),
+        solution!(2024, 6, "2024/06", y2024_d06::solution::Day),
+        solution!(2024, 7, "2024/07", y2024_d07::solution::Day),
+        solution!(2024, 8, "2024/08", y2024_d08::solution::Day),
+        solution!(2024, 9, "2024/09", y2024_d09::solution::Day),
+        solution!(2024, 10, "2024/10", y2024_d10::solution::Day),
+    ]
+}
@@ -0,0 +1,282 @@
+use crate::days::{Day, Kind};
+use aoc_core::{Answer, Part};
+use std::env;
+use std::fs::read_to_string;
+use std::panic;
+use std::path::{Path, PathBuf};
+use std::process::{exit, Command};
+use std::time::Instant;
+
+mod days;
+
+enum Selection {
+    All,
+    Year(u16),
+    Day(u16, u8),
+}
+
+struct Row {
+    year: u16,
+    day: u8,
+    part: Option<Part>,
+    result: Result<String, String>,
+    micros: u128,
+}
+
+const USAGE: &str = "usage: aoc run (<year> [<day>] | --all) [--part 1|2]";
+
+fn root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("../..")
+}
+
+fn parse_args(args: &[String]) -> Result<(Selection, Option<Part>), String> {
+    let mut args = args.iter();
+    if args.next().map(String::as_str) != Some("run") {
+        return Err(USAGE.to_string());
+    }
+
+    let mut all = false;
+    let mut numbers = vec![];
+    let mut part = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--all" => all = true,
+            "--part" => {
+                part = match args.next().map(String::as_str) {
+                    Some("1") => Some(Part::One),
+                    Some("2") => Some(Part::Two),
+                    _ => return Err("--part takes 1 or 2".to_string()),
+                }
+            }
+            number => numbers.push(
+                number
+                    .parse::<u16>()
+                    .map_err(|_| format!("unexpected argument: {number}"))?,
+            ),
+        }
+    }
+
+    let selection = match (all, numbers.as_slice()) {
+        (true, []) => Selection::All,
+        (false, [year]) => Selection::Year(*year),
+        (false, [year, day @ 1..=25]) => Selection::Day(*year, *day as u8),
+        (false, [_, day]) => return Err(format!("no day {day} in an advent calendar")),
+        _ => return Err(USAGE.to_string()),
+    };
+    Ok((selection, part))
+}
+
+fn solve_and_measure(
+    solve: fn(Part, &str) -> Answer,
+    part: Part,
+    input: &str,
+) -> (Result<String, String>, u128) {
+    let start = Instant::now();
+    let result = panic::catch_unwind(|| solve(part, input).to_string()).map_err(|payload| {
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_default();
+        format!("panicked: {message}")
+    });
+    (result, start.elapsed().as_micros())
+}
+
+/// Days in the old layout only have a `main`, so build it and run it in the
+/// day's directory where it expects its input files.
+fn run_binary(package: &str, dir: &Path) -> (Result<String, String>, u128) {
+    let built = Command::new("cargo")
+        .args(["build", "--release", "--quiet", "-p", package])
+        .current_dir(dir)
+        .output();
+    match built {
+        Ok(built) if built.status.success() => {}
+        Ok(built) => {
+            let stderr = String::from_utf8_lossy(&built.stderr);
+            return (Err(format!("failed to build {package}\n{stderr}")), 0);
+        }
+        Err(e) => return (Err(format!("failed to build {package}: {e}")), 0),
+    }
+
+    let target = env::var_os("CARGO_TARGET_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| root().join("target"));
+    let start = Instant::now();
+    let output = Command::new(target.join("release").join(package))
+        .current_dir(dir)
+        .output();
+    let micros = start.elapsed().as_micros();
+    match output {
+        Ok(output) if output.status.success() => (
+            Ok(String::from_utf8_lossy(&output.stdout)
+                .trim_end()
+                .to_string()),
+            micros,
+        ),
+        Ok(output) => (
+            Err(String::from_utf8_lossy(&output.stderr)
+                .trim_end()
+                .to_string()),
+            micros,
+        ),
+        Err(e) => (Err(e.to_string()), micros),
+    }
+}
+
+fn run(day: &Day, part: Option<Part>) -> Vec<Row> {
+    let dir = root().join(day.dir);
+    match day.kind {
+        Kind::Solution { solve, input } => {
+            let name = input;
+            let input = read_to_string(dir.join(name));
+            [Part::One, Part::Two]
+                .into_iter()
+                .filter(|p| part.is_none() || part == Some(*p))
+                .map(|p| {
+                    let (result, micros) = match &input {
+                        Ok(input) => solve_and_measure(solve, p, input),
+                        Err(e) => (Err(format!("{name}: {e}")), 0),
+                    };
+                    Row {
+                        year: day.year,
+                        day: day.day,
+                        part: Some(p),
+                        result,
+                        micros,
+                    }
+                })
+                .collect()
+        }
+        Kind::Binary { package } => {
+            let (result, micros) = run_binary(package, &dir);
+            vec![Row {
+                year: day.year,
+                day: day.day,
+                part: None,
+                result,
+                micros,
+            }]
+        }
+    }
+}
+
+fn print_table(rows: &[Row]) {
+    let answer = |row: &Row| match &row.result {
+        Ok(answer) => answer.clone(),
+        Err(error) => error.clone(),
+    };
+    let width = rows
+        .iter()
+        .map(|row| answer(row).lines().next().unwrap_or("").chars().count())
+        .max()
+        .unwrap_or(0)
+        .max("Answer".len());
+
+    println!("Year  Day  Part  {:width$}  Time", "Answer");
+    println!("{}", "-".repeat(width + 28));
+    for row in rows {
+        let answer = answer(row);
+        let mut lines = answer.lines();
+        let first = lines.next().unwrap_or("");
+        let part = match row.part {
+            Some(part) => format!("{part:?}"),
+            None => "-".to_string(),
+        };
+        let more = if lines.next().is_some() { " …" } else { "" };
+        println!(
+            "{:4}  {:3}  {:4}  {:width$}  {}µs{more}",
+            row.year, row.day, part, first, row.micros
+        );
+    }
+
+    let total: u128 = rows.iter().map(|row| row.micros).sum();
+    println!("{}", "-".repeat(width + 28));
+    println!("Total: {total}µs");
+
+    for row in rows.iter().filter(|row| answer(row).contains('\n')) {
+        let part = row
+            .part
+            .map(|part| format!(" part {part:?}"))
+            .unwrap_or_default();
+        println!("\n{} {:02}{part}:\n{}", row.year, row.day, answer(row));
+    }
+}
+
+fn main() {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+    let (selection, part) = parse_args(&args).unwrap_or_else(|e| {
+        eprintln!("{e}");
+        exit(2);
+    });
+
+    let days = days::all()
+        .into_iter()
+        .filter(|day| match selection {
+            Selection::All => true,
+            Selection::Year(year) => day.year == year,
+            Selection::Day(year, d) => day.year == year && day.day == d,
+        })
+        .collect::<Vec<_>>();
+    if days.is_empty() {
+        eprintln!("no solutions found");
+        exit(1);
+    }
+
+    // Solvers that panic are reported in the table instead of on stderr
+    panic::set_hook(Box::new(|_| {}));
+
+    let rows = days
+        .iter()
+        .flat_map(|day| run(day, part))
+        .collect::<Vec<_>>();
+    print_table(&rows);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(line: &str) -> Vec<String> {
+        line.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn parsing_day_and_part() {
+        let (selection, part) = parse_args(&args("run 2023 17 --part 2")).unwrap();
+        assert!(matches!(selection, Selection::Day(2023, 17)));
+        assert_eq!(Some(Part::Two), part);
+    }
+
+    #[test]
+    fn parsing_year_and_all() {
+        let (selection, part) = parse_args(&args("run 2022")).unwrap();
+        assert!(matches!(selection, Selection::Year(2022)));
+        assert_eq!(None, part);
+
+        let (selection, _) = parse_args(&args("run --all")).unwrap();
+        assert!(matches!(selection, Selection::All));
+    }
+
+    #[test]
+    fn parsing_errors() {
+        assert!(parse_args(&args("")).is_err());
+        assert!(parse_args(&args("run")).is_err());
+        assert!(parse_args(&args("run --all 2022")).is_err());
+        assert!(parse_args(&args("run 2022 1 --part 3")).is_err());
+        assert!(parse_args(&args("run twenty")).is_err());
+        assert!(parse_args(&args("run 2023 300")).is_err());
+        assert!(parse_args(&args("run 2023 0")).is_err());
+    }
+
+    #[test]
+    fn days_are_unique() {
+        let days = days::all();
+        for (i, a) in days.iter().enumerate() {
+            assert!(days[i + 1..]
+                .iter()
+                .all(|b| (a.year, a.day) != (b.year, b.day)));
+            assert!(root().join(a.dir).join("Cargo.toml").exists(), "{}", a.dir);
+        }
+    }
+}
@@ -1,27 +1,38 @@
+use aoc_core::{Answer, Solution};
 use scan_fmt::scan_fmt;
-use std::fs::read_to_string;
 
-pub fn part1(input: &str) -> usize {
-    //todo!()
-    0
-}
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Self::Input {
+        input.lines().map(String::from).collect()
+    }
 
-pub fn part2(input: &str) -> usize {
-    //todo!()
-    0
+    fn part1(input: &Self::Input) -> Answer {
+        //todo!()
+        0.into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        //todo!()
+        0.into()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_core::{read, Part};
 
     #[test]
     fn part1_sample() {
-        assert_eq!(0, part1("sample.txt"));
+        assert_eq!(0, Day::solve(Part::One, &read("sample.txt")));
     }
 
     #[test]
     fn part1_input() {
-        assert_eq!(0, part1("input.txt"));
+        assert_eq!(0, Day::solve(Part::One, &read("input.txt")));
     }
 }